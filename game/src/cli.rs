@@ -0,0 +1,189 @@
+use std::{env, path::PathBuf};
+
+use clap::Parser;
+
+use crate::{
+    high_scores::default_high_scores_path,
+    replay::{MAX_REPLAY_SPEED, MIN_REPLAY_SPEED},
+    script::OutputFormat,
+    state::{Difficulty, GameState, Rules, RulesError},
+};
+
+#[derive(Parser, Debug)]
+#[command(version, about)]
+pub struct Args {
+    /// Seed for the game's random events, so that a game can be shared and replayed.
+    /// A random seed is chosen when omitted.
+    #[arg(long)]
+    pub seed: Option<u64>,
+
+    /// Resume the saved game in the given file. Saving will write back to the same file.
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["seed", "years", "rules"])]
+    pub load: Option<PathBuf>,
+
+    /// How many years the game lasts. Overrides the length set by --rules [default: 3]
+    #[arg(long, value_parser = clap::value_parser!(u16).range(1..=100))]
+    pub years: Option<u16>,
+
+    /// Play with the balance constants in the given .toml or .json file,
+    /// e.g. starting_gold or cannon_cost. Constants left out keep their defaults.
+    #[arg(long, value_name = "FILE")]
+    pub rules: Option<PathBuf>,
+
+    /// Print the rules the game would be played with as TOML, then exit.
+    /// A good starting point for a --rules file.
+    #[arg(long, conflicts_with_all = ["load", "script", "record", "replay"])]
+    pub print_rules: bool,
+
+    /// Keep the high score table in the given file instead of the user's data directory.
+    #[arg(long, value_name = "FILE")]
+    pub high_scores: Option<PathBuf>,
+
+    /// The name recorded with your high scores. Defaults to your user name.
+    #[arg(long)]
+    pub name: Option<String>,
+
+    /// Log filter directives (e.g. "info" or "merchant=debug") written to ./merchant.log.
+    /// Takes precedence over the MERCHANT_LOG environment variable.
+    #[arg(long, value_name = "FILTER")]
+    pub log: Option<String>,
+
+    /// Play without a terminal UI, running the commands in the given file ("-" for stdin),
+    /// e.g. "buy tea 10", "sail lisbon" or "deposit 500", then print the resulting state.
+    #[arg(long, value_name = "FILE")]
+    pub script: Option<PathBuf>,
+
+    /// Difficulty of a scripted game. Games in the terminal UI ask on the introduction screen.
+    #[arg(long, value_enum, requires = "script", conflicts_with = "load")]
+    pub difficulty: Option<Difficulty>,
+
+    /// How the state is printed in script mode.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text, requires = "script")]
+    pub output: OutputFormat,
+
+    /// Record every key press to the given file, so the session can be played back with --replay.
+    #[arg(long, value_name = "FILE", conflicts_with = "script")]
+    pub record: Option<PathBuf>,
+
+    /// Play back a session recorded with --record.
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["seed", "load", "years", "rules", "script", "record"]
+    )]
+    pub replay: Option<PathBuf>,
+
+    /// Key presses per second when playing back a replay. Press + or - to change it while watching.
+    #[arg(long, value_name = "SPEED", default_value_t = 4.0, value_parser = parse_replay_speed, requires = "replay")]
+    pub replay_speed: f64,
+}
+
+fn parse_replay_speed(s: &str) -> Result<f64, String> {
+    let speed: f64 = s.parse().map_err(|_| format!("'{}' is not a number", s))?;
+    if (MIN_REPLAY_SPEED..=MAX_REPLAY_SPEED).contains(&speed) {
+        Ok(speed)
+    } else {
+        Err(format!(
+            "must be between {} and {}",
+            MIN_REPLAY_SPEED, MAX_REPLAY_SPEED
+        ))
+    }
+}
+
+impl Args {
+    /// the rules new games are played with, read from the --rules file if given
+    pub fn rules(&self) -> Result<Rules, RulesError> {
+        let mut rules = match &self.rules {
+            Some(path) => Rules::from_file(path)?,
+            None => Rules::default(),
+        };
+        if let Some(years) = self.years {
+            rules.game_length_years = years;
+        }
+        rules.validate()?;
+        Ok(rules)
+    }
+
+    pub fn high_scores_path(&self) -> Option<PathBuf> {
+        self.high_scores.clone().or_else(default_high_scores_path)
+    }
+
+    pub fn player_name(&self) -> String {
+        self.name
+            .clone()
+            .or_else(|| env::var("USER").ok())
+            .or_else(|| env::var("USERNAME").ok())
+            .filter(|name| !name.trim().is_empty())
+            .unwrap_or_else(|| "Captain".to_owned())
+    }
+
+    /// creates a new game according to the given arguments.
+    /// games started from the same seed and rules play out identically.
+    pub fn new_game(&self, rules: Rules) -> GameState {
+        let seed = self.seed.unwrap_or_else(rand::random);
+        GameState::from_seed(seed, rules)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::{error::ErrorKind, CommandFactory};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn verify_args() {
+        Args::command().debug_assert();
+    }
+
+    #[test]
+    fn defaults() {
+        let args = Args::try_parse_from(["merchant"]).unwrap();
+        assert_eq!(args.seed, None);
+        assert_eq!(args.load, None);
+        assert_eq!(args.years, None);
+        assert_eq!(args.rules, None);
+        assert_eq!(args.rules().unwrap(), Rules::default());
+        assert_eq!(args.log, None);
+        assert_eq!(args.script, None);
+        assert_eq!(args.output, OutputFormat::Text);
+        assert_eq!(args.difficulty, None);
+        assert_eq!(args.high_scores, None);
+    }
+
+    #[test]
+    fn same_seed_same_game() {
+        let args = Args::try_parse_from(["merchant", "--seed", "42", "--years", "5"]).unwrap();
+        let a = args.new_game(args.rules().unwrap());
+        let b = args.new_game(args.rules().unwrap());
+        assert_eq!(a.seed, Some(42));
+        assert_eq!(a.rules.game_length_years, 5);
+        assert_eq!(
+            serde_json::to_value(&a).unwrap(),
+            serde_json::to_value(&b).unwrap()
+        );
+    }
+
+    #[test]
+    fn invalid_args() {
+        let err = Args::try_parse_from(["merchant", "--years", "0"]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ValueValidation);
+        let err =
+            Args::try_parse_from(["merchant", "--load", "save.json", "--seed", "1"]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+        let err = Args::try_parse_from(["merchant", "--output", "json"]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+        let err = Args::try_parse_from(["merchant", "--difficulty", "hard"]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+        let err = Args::try_parse_from(["merchant", "--replay", "r.jsonl", "--replay-speed", "0"])
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ValueValidation);
+        let err =
+            Args::try_parse_from(["merchant", "--replay", "r.jsonl", "--seed", "1"]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+        let err = Args::try_parse_from(["merchant", "--load", "save.json", "--rules", "r.toml"])
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+    }
+}
@@ -0,0 +1,219 @@
+use std::fmt::{self};
+
+use crossterm::{
+    cursor::{Hide, MoveTo},
+    style::{style, Attribute, Print, Stylize},
+    terminal::Clear,
+    Command,
+};
+
+use crate::{
+    components::{Frame, FrameType, ScreenCenteredText},
+    state::{LedgerEntry, LEDGER_EXPORT_PATH},
+};
+
+pub struct CaptainsLog<'a> {
+    pub entries: &'a [LedgerEntry],
+    /// how many of the most recent entries have been scrolled past
+    pub scroll: usize,
+    /// how exporting the log went, if it has been
+    pub export: Option<&'a str>,
+}
+
+impl<'a> CaptainsLog<'a> {
+    /// how many entries fit on the screen at once
+    pub const PAGE_SIZE: usize = 20;
+}
+
+const OFFSET_X: u16 = 6;
+const OFFSET_Y: u16 = 6;
+
+fn row(date: &str, location: &str, event: &str, values: [&str; 5]) -> String {
+    let [good, quantity, price, gold_change, gold] = values;
+    format!("{date:<8}  {location:<10}  {event:<18}  {good:<7}  {quantity:>6}  {price:>5}  {gold_change:>9}  {gold:>8}")
+}
+
+impl<'a> Command for CaptainsLog<'a> {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        comp!(
+            f,
+            Clear(crossterm::terminal::ClearType::All),
+            Hide,
+            Frame(FrameType::SimpleEmptyInside),
+            ScreenCenteredText::new_styleds(
+                &[style("Captain's Log").attribute(Attribute::Bold)],
+                2
+            ),
+            ScreenCenteredText::new(
+                &[format!(
+                    "(Up/Down) Scroll, (e) Export to {}, (b) Back",
+                    LEDGER_EXPORT_PATH
+                )],
+                29
+            ),
+        );
+        if let Some(export) = self.export {
+            comp!(f, ScreenCenteredText::new(&[export.to_owned()], 30));
+        }
+        if self.entries.is_empty() {
+            comp!(
+                f,
+                ScreenCenteredText::new(&["Nothing has been recorded yet.".to_owned()], 12),
+            );
+            return Ok(());
+        }
+        comp!(
+            f,
+            MoveTo(OFFSET_X, OFFSET_Y - 2),
+            Print(row(
+                "Date",
+                "Location",
+                "Event",
+                ["Good", "Amount", "Price", "Gold +/-", "Gold"]
+            )),
+        );
+        // the most recent page is shown first, scrolling back in time
+        let end = self.entries.len().saturating_sub(self.scroll);
+        let start = end.saturating_sub(Self::PAGE_SIZE);
+        for (i, entry) in self.entries[start..end].iter().enumerate() {
+            let optional = |value: Option<u32>| value.map(|x| x.to_string()).unwrap_or_default();
+            let gold_change = match entry.event.gold_change() {
+                0 => String::new(),
+                x if x > 0 => format!("+{}", x),
+                x => x.to_string(),
+            };
+            comp!(
+                f,
+                MoveTo(OFFSET_X, OFFSET_Y + i as u16),
+                Print(row(
+                    &format!("{} {}", &entry.date.1.name()[..3], entry.date.0),
+                    &entry.location.to_string(),
+                    &entry.event.to_string(),
+                    [
+                        &entry
+                            .event
+                            .good()
+                            .map(|good| good.to_string())
+                            .unwrap_or_default(),
+                        &optional(entry.event.quantity()),
+                        &optional(entry.event.unit_price()),
+                        &gold_change,
+                        &entry.gold.to_string(),
+                    ]
+                )),
+            );
+        }
+        comp!(
+            f,
+            ScreenCenteredText::new(
+                &[format!(
+                    "Entries {}-{} of {}",
+                    start + 1,
+                    end,
+                    self.entries.len()
+                )],
+                27
+            ),
+        );
+        Ok(())
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> std::io::Result<()> {
+        todo!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Month;
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        state::{Good, LedgerEvent, Location},
+        test::render_component,
+    };
+
+    use super::*;
+
+    fn entry(month: Month, event: LedgerEvent, gold: u32) -> LedgerEntry {
+        LedgerEntry {
+            date: (1782, month),
+            location: Location::London,
+            event,
+            gold,
+        }
+    }
+
+    #[test]
+    fn scrolled() {
+        let mut entries = vec![
+            entry(
+                Month::March,
+                LedgerEvent::Bought {
+                    good: Good::Tea,
+                    amount: 10,
+                    price: 20,
+                },
+                300,
+            ),
+            entry(Month::April, LedgerEvent::BankWithdrawal(1000), 1300),
+        ];
+        for _ in 0..20 {
+            entries.push(entry(Month::May, LedgerEvent::EscapedPirates, 1300));
+        }
+        assert_eq!(
+            render_component(CaptainsLog {
+                entries: &entries,
+                scroll: 2,
+                export: Some("Exported to merchant_ledger.csv."),
+            }),
+            r#"
+---------------------------------------------------------------------------------------------------
+|                                                                                                 |
+|                                          Captain's Log                                          |
+|                                                                                                 |
+|     Date      Location    Event               Good     Amount  Price   Gold +/-      Gold       |
+|                                                                                                 |
+|     Mar 1782  London      Bought              Tea          10     20       -200       300       |
+|     Apr 1782  London      Withdrew from bank                              +1000      1300       |
+|     May 1782  London      Escaped pirates                                            1300       |
+|     May 1782  London      Escaped pirates                                            1300       |
+|     May 1782  London      Escaped pirates                                            1300       |
+|     May 1782  London      Escaped pirates                                            1300       |
+|     May 1782  London      Escaped pirates                                            1300       |
+|     May 1782  London      Escaped pirates                                            1300       |
+|     May 1782  London      Escaped pirates                                            1300       |
+|     May 1782  London      Escaped pirates                                            1300       |
+|     May 1782  London      Escaped pirates                                            1300       |
+|     May 1782  London      Escaped pirates                                            1300       |
+|     May 1782  London      Escaped pirates                                            1300       |
+|     May 1782  London      Escaped pirates                                            1300       |
+|     May 1782  London      Escaped pirates                                            1300       |
+|     May 1782  London      Escaped pirates                                            1300       |
+|     May 1782  London      Escaped pirates                                            1300       |
+|     May 1782  London      Escaped pirates                                            1300       |
+|     May 1782  London      Escaped pirates                                            1300       |
+|     May 1782  London      Escaped pirates                                            1300       |
+|                                                                                                 |
+|                                        Entries 1-20 of 22                                       |
+|                                                                                                 |
+|                  (Up/Down) Scroll, (e) Export to merchant_ledger.csv, (b) Back                  |
+|                                 Exported to merchant_ledger.csv.                                |
+|                                                                                                 |
+---------------------------------------------------------------------------------------------------
+"#
+            .trim()
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let formatted = render_component(CaptainsLog {
+            entries: &[],
+            scroll: 0,
+            export: None,
+        });
+        assert!(formatted.contains("Nothing has been recorded yet."));
+    }
+}
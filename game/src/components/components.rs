@@ -1,6 +1,6 @@
 use std::{
+    cmp::Ordering,
     fmt::{self},
-    num::Saturating,
 };
 
 use chrono::Month;
@@ -12,19 +12,26 @@ use crossterm::{
 };
 
 use crate::{
-    comp,
     components::{Frame, FrameType},
     state::{
-        GameState, Good, GoodsStolenResult, Inventory, Location, NoEffectEvent,
-        PirateEncounterState, Transaction, CANNON_COST,
+        Aging, Bounty, Contract, ContractOutcome, CostBasis, Crew, CustomsInspection,
+        DebtCollection, GameState, Good, GoodsMap, GoodsStolenResult, Inventory, Lender, Loans,
+        Location, NoEffectEvent, PirateBribe, PirateCaptain, PirateEncounterState, PirateRecord,
+        ShipClass, Transaction, WarehouseLoss,
     },
 };
 
-pub struct BankWithdrawInput<'a>(pub &'a Option<u32>);
+pub struct BankWithdrawInput<'a> {
+    pub amount: &'a Option<u32>,
+    /// how much the deposit grows each month
+    pub interest_rate: f64,
+    /// when withdrawals are frozen until, after a run on the bank
+    pub frozen_until: Option<(u16, Month)>,
+}
 
 impl<'a> Command for BankWithdrawInput<'a> {
     fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
-        let amount = self.0;
+        let amount = self.amount;
         const OFFSET_X: u16 = PROMPT_OFFSET_X;
         const OFFSET_Y: u16 = PROMPT_OFFSET_Y;
         let prompt = format!(
@@ -38,11 +45,26 @@ impl<'a> Command for BankWithdrawInput<'a> {
             Print("How much gold do you"),
             MoveTo(OFFSET_X, OFFSET_Y + 1),
             Print(prompt),
+            MoveTo(OFFSET_X, OFFSET_Y + 3),
+            Print(format!(
+                "Deposits earn {}% a month.",
+                (self.interest_rate * 100.0).round()
+            )),
             MoveTo(OFFSET_X, OFFSET_Y + 5),
             Print("(b) <- back".to_string()),
-            MoveTo(OFFSET_X + prompt_len, OFFSET_Y + 1),
-            Show
         );
+        if let Some((year, month)) = self.frozen_until {
+            comp!(
+                f,
+                MoveTo(OFFSET_X, OFFSET_Y + 4),
+                Print(format!(
+                    "Withdrawals are frozen until {} {}.",
+                    &month.name()[..3],
+                    year
+                )),
+            );
+        }
+        comp!(f, MoveTo(OFFSET_X + prompt_len, OFFSET_Y + 1), Show);
         Ok(())
     }
 
@@ -76,28 +98,90 @@ impl std::fmt::Display for Numeric7Digits {
     }
 }
 
-pub struct InventoryList<'a>(pub &'a Inventory, pub u16, pub u16);
+/// a count of years written out the way a person would say it, e.g. "three years"
+pub struct YearsInWords(pub u16);
+
+impl std::fmt::Display for YearsInWords {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const WORDS: [&str; 10] = [
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+        ];
+        let years = self.0;
+        let count = match WORDS.get((years as usize).wrapping_sub(1)) {
+            Some(word) => word.to_string(),
+            None => years.to_string(),
+        };
+        write!(f, "{} year{}", count, if years == 1 { "" } else { "s" })
+    }
+}
+
+/// how many goods fit in the lists of the home base, the warehouse and the ship's hold
+const GOODS_SLOTS: usize = 6;
+
+/// the goods to list where there's only room for `slots` of them, and how many more are left off.
+/// every good is listed if the catalog fits, otherwise only those there are some of
+fn listed_goods(inventory: &Inventory, slots: usize) -> (Vec<Good>, usize) {
+    if Good::COUNT <= slots {
+        return (Good::variants().to_vec(), 0);
+    }
+    let held = inventory
+        .iter()
+        .filter(|(_, amount)| **amount > 0)
+        .map(|(good, _)| good)
+        .collect::<Vec<Good>>();
+    if held.len() <= slots {
+        (held, 0)
+    } else {
+        // the last slot says how many more there are
+        let more = held.len() - (slots - 1);
+        (held[..slots - 1].to_vec(), more)
+    }
+}
+
+/// a good and its amount, lined up with the rest of the catalog
+fn good_amount(good: &Good, amount: u32, aging: Aging) -> String {
+    format!(
+        "{:>width$}: {}{}",
+        good.to_string(),
+        Numeric4Digits(amount),
+        aging.marker(),
+        width = Good::longest_name()
+    )
+}
+
+/// a column of goods and their amounts, with how each is aging if given
+pub struct InventoryList<'a>(
+    pub &'a Inventory,
+    pub u16,
+    pub u16,
+    pub Option<GoodsMap<Aging>>,
+);
 
 impl<'a> Command for InventoryList<'a> {
     fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
         let inventory = self.0;
         let offset_x = self.1;
         let offset_y = self.2;
-        comp!(
-            f,
-            MoveTo(offset_x + 4, offset_y),
-            Print(format!("Tea: {}", Numeric4Digits(inventory.tea))),
-            MoveTo(offset_x + 1, offset_y + 1),
-            Print(format!("Coffee: {}", Numeric4Digits(inventory.coffee))),
-            MoveTo(offset_x + 2, offset_y + 2),
-            Print(format!("Sugar: {}", Numeric4Digits(inventory.sugar))),
-            MoveTo(offset_x, offset_y + 3),
-            Print(format!("Tobacco: {}", Numeric4Digits(inventory.tobacco))),
-            MoveTo(offset_x + 4, offset_y + 4),
-            Print(format!("Rum: {}", Numeric4Digits(inventory.rum))),
-            MoveTo(offset_x + 1, offset_y + 5),
-            Print(format!("Cotton: {}", Numeric4Digits(inventory.cotton))),
-        );
+        let aging = self.3.clone().unwrap_or_default();
+        let (goods, more) = listed_goods(inventory, GOODS_SLOTS);
+        for (i, good) in goods.iter().enumerate() {
+            comp!(
+                f,
+                MoveTo(offset_x, offset_y + i as u16),
+                Print(good_amount(good, inventory[*good], aging[*good])),
+            );
+        }
+        if more > 0 {
+            comp!(
+                f,
+                MoveTo(offset_x, offset_y + goods.len() as u16),
+                Print(format!(
+                    "{:>width$}",
+                    format!("and {} more", more),
+                    width = Good::longest_name() + 6
+                )),
+            );
+        }
         Ok(())
     }
 
@@ -107,19 +191,58 @@ impl<'a> Command for InventoryList<'a> {
     }
 }
 
+/// the most goods the screens listing every good have room for,
+/// eg. two columns of six prices beside the ship
+const MAX_GOODS: usize = 12;
+const _: () = assert!(
+    Good::COUNT <= MAX_GOODS,
+    "the catalog has more goods than the screens have room for"
+);
+
+const PRICES_OFFSET_X: u16 = 52;
+const PRICES_OFFSET_Y: u16 = 24;
+/// the most prices listed in a column before starting another
+const PRICES_ROWS: usize = 6;
+/// how wide a column of prices is, with the profits beside them
+const PRICES_COLUMN_WIDTH: u16 = 23;
+/// how wide a price is, with the name of the good
+const PRICE_WIDTH: u16 = 15;
+
+/// how many rows the prices of every good are listed in
+fn price_rows() -> usize {
+    let columns = Good::COUNT.div_ceil(PRICES_ROWS);
+    Good::COUNT.div_ceil(columns)
+}
+
+/// where the price of a good is listed, in as many columns as it takes
+fn price_position(good: &Good) -> (u16, u16) {
+    let rows = price_rows();
+    let column = (good.index() / rows) as u16;
+    let row = (good.index() % rows) as u16;
+    (
+        PRICES_OFFSET_X + column * PRICES_COLUMN_WIDTH,
+        PRICES_OFFSET_Y + row,
+    )
+}
+
 pub struct CurrentPrices<'a>(pub &'a Inventory);
 
 impl<'a> Command for CurrentPrices<'a> {
     fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
         let prices = self.0;
-        const OFFSET_X: u16 = 53;
-        const OFFSET_Y: u16 = 23;
         comp!(
             f,
-            MoveTo(OFFSET_X, OFFSET_Y),
+            MoveTo(PRICES_OFFSET_X, PRICES_OFFSET_Y - 1),
             Print("Captain, the prices of goods here are:"),
-            InventoryList(prices, OFFSET_X + 11, OFFSET_Y + 1),
         );
+        for (good, price) in prices {
+            let (x, y) = price_position(&good);
+            comp!(
+                f,
+                MoveTo(x, y),
+                Print(good_amount(&good, *price, Aging::Fresh)),
+            );
+        }
         Ok(())
     }
 
@@ -130,7 +253,8 @@ impl<'a> Command for CurrentPrices<'a> {
 }
 
 pub struct KeyInputAction {
-    num_key: u8,
+    /// actions beyond the number keys are only chosen by their letter
+    num_key: Option<u8>,
     char_key: char,
     text: String,
 }
@@ -149,7 +273,10 @@ impl Command for KeyInputAction {
         let segment_after = &self.text[(char_index + 1)..];
         comp!(
             f,
-            Print(format!("({}) ", self.num_key)),
+            Print(match self.num_key {
+                Some(num_key) => format!("({}) ", num_key),
+                None => format!("({}) ", self.char_key),
+            }),
             Print(segment_before),
             Print(style(char_segment).attribute(Attribute::Underlined)),
             Print(segment_after),
@@ -163,10 +290,83 @@ impl Command for KeyInputAction {
     }
 }
 
+/// the shipwright's offer to mend the hull, for the given price
+struct MendHull(u32);
+
+impl Command for MendHull {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        comp!(
+            f,
+            KeyInputAction {
+                num_key: None,
+                char_key: 'm',
+                text: "Mend hull".to_owned()
+            },
+            Print(format!(" ({})", self.0)),
+        );
+        Ok(())
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> std::io::Result<()> {
+        todo!()
+    }
+}
+
+struct ShipyardAction;
+
+impl Command for ShipyardAction {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        comp!(
+            f,
+            KeyInputAction {
+                num_key: None,
+                char_key: 'y',
+                text: "Shipyard".to_owned()
+            },
+        );
+        Ok(())
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> std::io::Result<()> {
+        todo!()
+    }
+}
+
+struct SignOnCrew;
+
+impl Command for SignOnCrew {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        comp!(
+            f,
+            KeyInputAction {
+                num_key: None,
+                char_key: 'n',
+                text: "Sign on crew".to_owned()
+            },
+        );
+        Ok(())
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> std::io::Result<()> {
+        todo!()
+    }
+}
+
 pub struct ViewingInventoryActions<'a> {
     pub location: &'a Location,
     pub home_port: &'a Location,
+    /// what is owed to every lender, any of which can be repaid from here
     pub debt: u32,
+    /// the goods in the warehouse rented in this port, if any
+    pub warehouse: Option<&'a Inventory>,
+    pub warehouse_rent: u32,
+    /// what a shipwright would charge to mend the hull, if it's damaged
+    pub repair_cost: Option<u32>,
+    /// whether there's a shipyard in this port
+    pub shipyard: bool,
 }
 
 impl<'a> Command for ViewingInventoryActions<'a> {
@@ -174,6 +374,7 @@ impl<'a> Command for ViewingInventoryActions<'a> {
         let location = self.location;
         let home_port = self.home_port;
         let debt = self.debt;
+        let warehouse = self.warehouse;
         const OFFSET_X: u16 = PROMPT_OFFSET_X;
         const OFFSET_Y: u16 = PROMPT_OFFSET_Y;
         comp!(
@@ -181,63 +382,161 @@ impl<'a> Command for ViewingInventoryActions<'a> {
             // actions
             MoveTo(OFFSET_X, OFFSET_Y),
             KeyInputAction {
-                num_key: 1,
+                num_key: Some(1),
                 char_key: 'b',
                 text: "Buy".to_owned()
             },
             MoveTo(OFFSET_X, OFFSET_Y + 1),
             KeyInputAction {
-                num_key: 2,
+                num_key: Some(2),
                 char_key: 's',
                 text: "Sell".to_owned()
             },
             MoveTo(OFFSET_X, OFFSET_Y + 2),
             KeyInputAction {
-                num_key: 3,
+                num_key: Some(3),
                 char_key: 'a',
                 text: "Sail".to_owned()
             },
+            MoveTo(OFFSET_X + 22, OFFSET_Y),
+            KeyInputAction {
+                num_key: Some(9),
+                char_key: 'l',
+                text: "Captain's log".to_owned()
+            },
+            MoveTo(OFFSET_X + 22, OFFSET_Y + 1),
+            KeyInputAction {
+                num_key: Some(0),
+                char_key: 'h',
+                text: "Price history".to_owned()
+            },
+            MoveTo(OFFSET_X + 22, OFFSET_Y + 2),
+            KeyInputAction {
+                num_key: None,
+                char_key: 'r',
+                text: "Market rumors".to_owned()
+            },
+            MoveTo(OFFSET_X + 22, OFFSET_Y + 3),
+            KeyInputAction {
+                num_key: None,
+                char_key: 'o',
+                text: "Borrow".to_owned()
+            },
+            MoveTo(OFFSET_X + 22, OFFSET_Y + 4),
+            KeyInputAction {
+                num_key: None,
+                char_key: 'c',
+                text: "Contracts".to_owned()
+            },
         );
+        // the home port's actions push paying down debt further down
+        let mut pay_debt_row = OFFSET_Y + 3;
         if location == home_port {
             comp!(
                 f,
                 MoveTo(OFFSET_X, OFFSET_Y + 3),
                 KeyInputAction {
-                    num_key: 4,
+                    num_key: Some(4),
                     char_key: 'd',
                     text: "Stash deposit".to_owned()
                 },
                 MoveTo(OFFSET_X, OFFSET_Y + 4),
                 KeyInputAction {
-                    num_key: 5,
+                    num_key: Some(5),
                     char_key: 'w',
                     text: "Stash withdraw".to_owned()
                 },
                 MoveTo(OFFSET_X, OFFSET_Y + 5),
                 KeyInputAction {
-                    num_key: 6,
+                    num_key: Some(6),
                     char_key: 'e',
                     text: "Bank deposit".to_owned()
                 },
                 MoveTo(OFFSET_X, OFFSET_Y + 6),
                 KeyInputAction {
-                    num_key: 7,
+                    num_key: Some(7),
                     char_key: 'i',
                     text: "Bank withdraw".to_owned()
                 },
             );
-            if debt > 0 {
+            pay_debt_row = OFFSET_Y + 7;
+            // the left column is full, and there's no warehouse to rent at home
+            if let Some(cost) = self.repair_cost {
+                comp!(f, MoveTo(OFFSET_X + 22, OFFSET_Y + 5), MendHull(cost));
+            }
+            if self.shipyard {
+                comp!(f, MoveTo(OFFSET_X + 22, OFFSET_Y + 6), ShipyardAction);
+            }
+        } else if let Some(warehouse) = warehouse {
+            comp!(
+                f,
+                MoveTo(OFFSET_X, OFFSET_Y + 3),
+                KeyInputAction {
+                    num_key: Some(4),
+                    char_key: 'd',
+                    text: "Stash deposit".to_owned()
+                },
+                MoveTo(OFFSET_X, OFFSET_Y + 4),
+                KeyInputAction {
+                    num_key: Some(5),
+                    char_key: 'w',
+                    text: "Stash withdraw".to_owned()
+                },
+            );
+            if warehouse.total_amount() == 0 {
                 comp!(
                     f,
-                    MoveTo(OFFSET_X, OFFSET_Y + 7),
+                    MoveTo(OFFSET_X + 22, OFFSET_Y + 5),
                     KeyInputAction {
-                        num_key: 8,
-                        char_key: 'p',
-                        text: "Pay down debt".to_owned()
+                        num_key: None,
+                        char_key: 'u',
+                        text: "Give up warehouse".to_owned()
                     },
                 );
             }
+            pay_debt_row = OFFSET_Y + 5;
+        } else {
+            comp!(
+                f,
+                MoveTo(OFFSET_X + 22, OFFSET_Y + 5),
+                KeyInputAction {
+                    num_key: None,
+                    char_key: 'u',
+                    text: "Rent warehouse".to_owned()
+                },
+                // underneath, to keep clear of the prices
+                MoveTo(OFFSET_X + 26, OFFSET_Y + 6),
+                Print(format!("{} a month", self.warehouse_rent)),
+            );
+        }
+        if debt > 0 {
+            comp!(
+                f,
+                MoveTo(OFFSET_X, pay_debt_row),
+                KeyInputAction {
+                    num_key: Some(8),
+                    char_key: 'p',
+                    text: "Pay down debt".to_owned()
+                },
+            );
+        }
+        if location != home_port {
+            // away from home, the shipwright and shipyard follow on down the left column
+            let mut row = if debt > 0 {
+                pay_debt_row + 1
+            } else {
+                pay_debt_row
+            };
+            if let Some(cost) = self.repair_cost {
+                comp!(f, MoveTo(OFFSET_X, row), MendHull(cost));
+                row += 1;
+            }
+            if self.shipyard {
+                comp!(f, MoveTo(OFFSET_X, row), ShipyardAction);
+            }
         }
+        // every port has sailors looking for a berth
+        comp!(f, MoveTo(OFFSET_X + 22, OFFSET_Y + 7), SignOnCrew);
         Ok(())
     }
 
@@ -247,11 +546,17 @@ impl<'a> Command for ViewingInventoryActions<'a> {
     }
 }
 
-pub struct BankDepositInput<'a>(pub &'a Option<u32>);
+pub struct BankDepositInput<'a> {
+    pub amount: &'a Option<u32>,
+    /// how much the deposit grows each month
+    pub interest_rate: f64,
+    /// the chance each month of a run on the bank
+    pub run_chance: f64,
+}
 
 impl<'a> Command for BankDepositInput<'a> {
     fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
-        let amount = self.0;
+        let amount = self.amount;
         const OFFSET_X: u16 = PROMPT_OFFSET_X;
         const OFFSET_Y: u16 = PROMPT_OFFSET_Y;
         let prompt = format!(
@@ -265,6 +570,16 @@ impl<'a> Command for BankDepositInput<'a> {
             Print("How much gold do you want"),
             MoveTo(OFFSET_X, OFFSET_Y + 1),
             Print(prompt),
+            MoveTo(OFFSET_X, OFFSET_Y + 3),
+            Print(format!(
+                "Deposits earn {}% a month, but there is",
+                (self.interest_rate * 100.0).round()
+            )),
+            MoveTo(OFFSET_X, OFFSET_Y + 4),
+            Print(format!(
+                "a {}% chance a month of a run on the bank.",
+                (self.run_chance * 100.0).round()
+            )),
             MoveTo(OFFSET_X, OFFSET_Y + 5),
             Print("(b) <- back".to_string()),
             MoveTo(OFFSET_X + prompt_len, OFFSET_Y + 1),
@@ -333,6 +648,7 @@ impl<'a> From<&'a GameState> for Date<'a> {
 
 pub struct HomeBase<'a> {
     stash: &'a Inventory,
+    stash_aging: GoodsMap<Aging>,
     bank: u32,
     debt: u32,
     location: &'a Location,
@@ -343,8 +659,11 @@ impl<'a> From<&'a GameState> for HomeBase<'a> {
     fn from(value: &'a GameState) -> Self {
         HomeBase {
             stash: &value.stash,
+            stash_aging: value
+                .stash_lots
+                .aging(&value.stash, value.date, &value.rules),
             bank: value.bank.0,
-            debt: value.debt.0,
+            debt: value.debt(),
             location: &value.location,
             home_port: &value.location_config.home_port,
         }
@@ -354,22 +673,22 @@ impl<'a> From<&'a GameState> for HomeBase<'a> {
 impl<'a> Command for HomeBase<'a> {
     fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
         const HOME: &str = r###"
-  _____[LLL]______[LLL]____
- /     [LLL]      [LLL]    \
-/___________________________\
- )=========================(
- '|I .--. I               I|
-  |I | +| I               I|
-  |I_|_+|_I               I|
- /_I______I               I_\
-  )========               =(
-  |I .--. I               I|
-  |I |<>| I               I|
-  |I |~ | I               I|
-  |I |  | I               I|
-  |I_|__|_I_______________I|
-###(______)##################
- ##(________)   ~"^"^~   ## 
+  _____[LLL]______[LLL]______
+ /     [LLL]      [LLL]      \
+/_____________________________\
+ )===========================(
+ '|I .--. I                 I|
+  |I | +| I                 I|
+  |I_|_+|_I                 I|
+ /_I______I                 I_\
+  )========                 =(
+  |I .--. I                 I|
+  |I |<>| I                 I|
+  |I |~ | I                 I|
+  |I |  | I                 I|
+  |I_|__|_I_________________I|
+###(______)####################
+ ##(________)   ~"^"^~     ## 
 "###;
         const OFFSET_X: u16 = 4;
         const OFFSET_Y: u16 = 3;
@@ -382,10 +701,15 @@ impl<'a> Command for HomeBase<'a> {
         }
         comp!(
             f,
-            InventoryList(self.stash, OFFSET_X + 12, OFFSET_Y + 4),
-            MoveTo(OFFSET_X + 12, OFFSET_Y + 11),
+            InventoryList(
+                self.stash,
+                OFFSET_X + 11,
+                OFFSET_Y + 4,
+                Some(self.stash_aging.clone())
+            ),
+            MoveTo(OFFSET_X + 13, OFFSET_Y + 11),
             Print(format!("Bank: {}", Numeric7Digits(self.bank))),
-            MoveTo(OFFSET_X + 12, OFFSET_Y + 12),
+            MoveTo(OFFSET_X + 13, OFFSET_Y + 12),
             Print(format!("Debt: {}", Numeric7Digits(self.debt))),
         );
         const PATH_CONTINUATION: &str = r###"
@@ -462,6 +786,7 @@ pub struct ViewingInventoryBase<'a>(pub &'a GameState);
 impl<'a> Command for ViewingInventoryBase<'a> {
     fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
         let state = self.0;
+        let sale_prices = state.hold_sale_prices();
         comp!(
             f,
             Clear(crossterm::terminal::ClearType::All), // clear the terminal
@@ -470,8 +795,15 @@ impl<'a> Command for ViewingInventoryBase<'a> {
             Date::from(state),
             HomeBase::from(state),
             Ship::from(state),
+            WarehouseGoods::from(state),
+            ActiveContracts(&state.contracts),
             CurrentLocation::from(state),
-            CurrentPrices(&state.locations.location_info(&state.location).prices)
+            CurrentPrices(&state.locations.location_info(&state.location).prices),
+            UnrealizedGains {
+                inventory: &state.inventory,
+                cost_basis: &state.inventory_cost,
+                prices: &sale_prices,
+            }
         );
         Ok(())
     }
@@ -482,27 +814,143 @@ impl<'a> Command for ViewingInventoryBase<'a> {
     }
 }
 
-pub struct Ship<'a> {
-    inventory: &'a Inventory,
-    gold: u32,
-    hold_size: u32,
-    cannons: u8,
+/// the contracts taken on, listed in the corner beside the date
+pub struct ActiveContracts<'a>(pub &'a [Contract]);
+
+impl<'a> Command for ActiveContracts<'a> {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        const OFFSET_X: u16 = 61;
+        const OFFSET_Y: u16 = 1;
+        // there's room for two, the rest are on the contract board
+        for (i, contract) in self.0.iter().take(2).enumerate() {
+            comp!(
+                f,
+                MoveTo(OFFSET_X, OFFSET_Y + i as u16),
+                Print(contract.to_string()),
+            );
+        }
+        Ok(())
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> std::io::Result<()> {
+        todo!()
+    }
 }
 
-impl<'a> From<&'a GameState> for Ship<'a> {
+/// the goods in the warehouse rented in the current port, drawn beside the home base
+pub struct WarehouseGoods<'a>(pub Option<(&'a Inventory, GoodsMap<Aging>)>);
+
+impl<'a> From<&'a GameState> for WarehouseGoods<'a> {
     fn from(value: &'a GameState) -> Self {
-        Ship {
-            inventory: &value.inventory,
-            gold: value.gold.0,
-            hold_size: value.hold_size.0,
-            cannons: value.cannons.0,
+        WarehouseGoods(
+            value
+                .warehouses
+                .get(&value.location)
+                .as_ref()
+                .map(|warehouse| {
+                    (
+                        &warehouse.goods,
+                        warehouse
+                            .lots
+                            .aging(&warehouse.goods, value.date, &value.rules),
+                    )
+                }),
+        )
+    }
+}
+
+impl<'a> Command for WarehouseGoods<'a> {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        const OFFSET_X: u16 = 36;
+        const OFFSET_Y: u16 = 3;
+        if let Some((goods, aging)) = &self.0 {
+            comp!(
+                f,
+                MoveTo(OFFSET_X + 2, OFFSET_Y),
+                Print("Warehouse"),
+                InventoryList(goods, OFFSET_X, OFFSET_Y + 1, Some(aging.clone())),
+            );
         }
+        Ok(())
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> std::io::Result<()> {
+        todo!()
     }
 }
 
-impl<'a> Command for Ship<'a> {
+/// next to each price, how much more (or less) the goods in the hold would sell for
+/// than was paid for them
+pub struct UnrealizedGains<'a> {
+    pub inventory: &'a Inventory,
+    pub cost_basis: &'a CostBasis,
+    /// what the goods in the hold would sell for, given how well they've aged
+    pub prices: &'a Inventory,
+}
+
+impl<'a> Command for UnrealizedGains<'a> {
     fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
-        const SHIP: &str = r###"
+        if self.inventory.total_amount() == 0 {
+            return Ok(());
+        }
+        for (good, amount) in self.inventory.iter() {
+            if *amount > 0 {
+                let gain = self
+                    .cost_basis
+                    .unrealized_gain(&good, self.inventory, self.prices);
+                let (x, y) = price_position(&good);
+                comp!(
+                    f,
+                    MoveTo(x + PRICE_WIDTH, y),
+                    Print(format!("{:>+7}", gain)),
+                );
+            }
+        }
+        // beneath the last column of profits
+        const LABEL: &str = "Profit if sold";
+        let columns = Good::COUNT.div_ceil(price_rows()) as u16;
+        comp!(
+            f,
+            MoveTo(
+                // less the gap between columns
+                PRICES_OFFSET_X + columns * PRICES_COLUMN_WIDTH - 1 - LABEL.len() as u16,
+                PRICES_OFFSET_Y + price_rows() as u16
+            ),
+            Print(LABEL),
+        );
+        Ok(())
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> std::io::Result<()> {
+        todo!()
+    }
+}
+
+/// the masts and sails of each class of ship, down to the deck
+const SLOOP_SAILS: &str = r###"
+                             |                            
+                            )_)\                          
+                           )___) \                        
+                           )____)  \                      
+                          /)_____)   \                    
+                         /)______)\    \                  
+                       //)_______) \\    \\               
+       ______________///_____|______\\\_____\\\_____=====
+"###;
+const BRIG_SAILS: &str = r###"
+                             |                            
+                            )_)                           
+                           )___)         |                
+                           )____)       )_)\              
+                          /)_____)      )__)\             
+                         /)______)\    )___) \            
+                       //)_______) \\ )_____) \\          
+       ______________///_____|______\\\__|_____\\\__=====
+"###;
+const FRIGATE_SAILS: &str = r###"
                              |                            
                  |          )_)                           
                 )_)        )___)         |                
@@ -511,6 +959,9 @@ impl<'a> Command for Ship<'a> {
               )_____)    /)______)\    )___) \            
              )______)  //)_______) \\ )_____) \\          
        _____//___|___///_____|______\\\__|_____\\\__=====
+"###;
+/// the hull below the deck, the same for every ship, and the dock it's moored at
+const HULL: &str = r###"
        \                                           /     
         \                                         /      
          \                                       /____   
@@ -520,36 +971,95 @@ impl<'a> Command for Ship<'a> {
                   ^^^      ^^^^             /...../      
                                           /....../       
 "###;
+
+pub struct Ship<'a> {
+    inventory: &'a Inventory,
+    aging: GoodsMap<Aging>,
+    gold: u32,
+    hold_size: u32,
+    cannons: u8,
+    ship_class: ShipClass,
+    hull: u8,
+    crew: Crew,
+}
+
+impl<'a> From<&'a GameState> for Ship<'a> {
+    fn from(value: &'a GameState) -> Self {
+        Ship {
+            inventory: &value.inventory,
+            aging: value.hold_aging(),
+            gold: value.gold.0,
+            hold_size: value.hold_size.0,
+            cannons: value.cannons.0,
+            ship_class: value.ship_class,
+            hull: value.hull(),
+            crew: value.crew,
+        }
+    }
+}
+
+impl<'a> Command for Ship<'a> {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
         const OFFSET_X: u16 = 39;
         const OFFSET_Y: u16 = 3;
-        for (i, line) in SHIP.trim_matches('\n').lines().enumerate() {
+        let sails = match self.ship_class {
+            ShipClass::Sloop => SLOOP_SAILS,
+            ShipClass::Brig => BRIG_SAILS,
+            ShipClass::Frigate => FRIGATE_SAILS,
+        };
+        for (i, line) in sails
+            .trim_matches('\n')
+            .lines()
+            .chain(HULL.trim_matches('\n').lines())
+            .enumerate()
+        {
             comp!(
                 f,
                 MoveTo(OFFSET_X, OFFSET_Y + (i as u16)),
                 Print(line.to_string()),
             );
         }
-        let inventory = self.inventory;
+        // the hold is listed in two columns down the hull
+        let rows = GOODS_SLOTS.div_ceil(2);
+        let (goods, more) = listed_goods(self.inventory, GOODS_SLOTS);
+        for (i, good) in goods.iter().enumerate() {
+            comp!(
+                f,
+                MoveTo(
+                    OFFSET_X + 13 + 19 * (i / rows) as u16,
+                    OFFSET_Y + 8 + (i % rows) as u16
+                ),
+                Print(good_amount(good, self.inventory[*good], self.aging[*good])),
+            );
+        }
+        if more > 0 {
+            comp!(
+                f,
+                MoveTo(OFFSET_X + 32, OFFSET_Y + 8 + (rows - 1) as u16),
+                Print(format!("and {} more", more)),
+            );
+        }
         comp!(
             f,
-            MoveTo(OFFSET_X + 14, OFFSET_Y + 8),
-            Print(format!("Tea: {}", Numeric4Digits(inventory.tea))),
-            MoveTo(OFFSET_X + 24, OFFSET_Y + 8),
-            Print(format!("Coffee: {}", Numeric4Digits(inventory.coffee))),
-            MoveTo(OFFSET_X + 38, OFFSET_Y + 8),
-            Print(format!("Sugar: {}", Numeric4Digits(inventory.sugar))),
-            MoveTo(OFFSET_X + 10, OFFSET_Y + 9),
-            Print(format!("Tobacco: {}", Numeric4Digits(inventory.tobacco))),
-            MoveTo(OFFSET_X + 27, OFFSET_Y + 9),
-            Print(format!("Rum: {}", Numeric4Digits(inventory.rum))),
-            MoveTo(OFFSET_X + 37, OFFSET_Y + 9),
-            Print(format!("Cotton: {}", Numeric4Digits(inventory.cotton))),
             MoveTo(OFFSET_X + 12, OFFSET_Y + 11),
             Print(format!("Gold: {}", Numeric7Digits(self.gold))),
             MoveTo(OFFSET_X + 26, OFFSET_Y + 11),
             Print(format!("Hold: {}", Numeric4Digits(self.hold_size))),
             MoveTo(OFFSET_X + 37, OFFSET_Y + 11),
             Print(format!("Cannons: {}", self.cannons)),
+            // at the waterline
+            MoveTo(OFFSET_X + 11, OFFSET_Y + 12),
+            Print(format!(
+                " Hull: {}/{} ",
+                self.hull,
+                self.ship_class.info().hull
+            )),
+            MoveTo(OFFSET_X + 11, OFFSET_Y + 13),
+            Print(format!(
+                " Crew: {}, {} ",
+                self.crew.sailors,
+                self.crew.mood()
+            )),
         );
         const DOCK_CONTINUATION_1: &str = r###"
 /......../
@@ -592,7 +1102,6 @@ impl<'a> Command for BuyInput<'a> {
         const OFFSET_X: u16 = PROMPT_OFFSET_X;
         const OFFSET_Y: u16 = PROMPT_OFFSET_Y;
         // user has indicated which good they want to buy
-        let gold = state.gold;
         let good = &info.good;
         let good_price = state
             .locations
@@ -606,7 +1115,7 @@ impl<'a> Command for BuyInput<'a> {
                 .map_or("".to_owned(), |amount| amount.to_string())
         );
         let prompt_len: u16 = prompt.len().try_into().unwrap();
-        let can_afford = gold / Saturating(*good_price);
+        let can_afford = state.can_afford(good);
         comp!(
             f,
             // prompt what to buy
@@ -618,13 +1127,28 @@ impl<'a> Command for BuyInput<'a> {
             Print("(b) <- back".to_string()),
         );
         let remaining_hold = state.remaining_hold();
-        if remaining_hold < can_afford.0 {
+        if remaining_hold < can_afford {
             comp!(
                 f,
                 MoveTo(OFFSET_X, OFFSET_Y + 2),
                 Print(format!("You have space for ({})", remaining_hold)),
             )
         }
+        let rate = state.customs().export_rate(good);
+        let duty = state.export_duty(good, info.amount.unwrap_or(0));
+        if let Some(line) = customs_line(state, good, "Export", rate, duty) {
+            comp!(f, MoveTo(OFFSET_X, OFFSET_Y + 3), Print(line));
+        }
+        if let Some(amount) = info.amount.filter(|amount| *amount > 0) {
+            comp!(
+                f,
+                MoveTo(OFFSET_X, OFFSET_Y + 4),
+                Print(format!(
+                    "Price here {}",
+                    price_movement(*good_price, state.price_after_trade(good, amount as i64))
+                )),
+            );
+        }
         comp!(f, MoveTo(OFFSET_X + prompt_len, OFFSET_Y), Show);
         Ok(())
     }
@@ -635,10 +1159,36 @@ impl<'a> Command for BuyInput<'a> {
     }
 }
 
-const PROMPT_OFFSET_X: u16 = 10;
-const PROMPT_OFFSET_Y: u16 = 23;
+/// a warning if a good is contraband here, otherwise the duty on the amount being traded, if any
+fn customs_line(
+    state: &GameState,
+    good: &Good,
+    kind: &str,
+    rate: f64,
+    duty: u32,
+) -> Option<String> {
+    if state.customs().is_contraband(good) {
+        Some(format!("{} is contraband here!", good))
+    } else if rate > 0.0 {
+        Some(format!("{} duty {:.0}%: {} gold", kind, rate * 100.0, duty))
+    } else {
+        None
+    }
+}
 
-pub struct BuyPrompt;
+/// describes how a trade moves the local price of a good
+fn price_movement(before: u32, after: u32) -> String {
+    match after.cmp(&before) {
+        Ordering::Greater => format!("rises to {}", after),
+        Ordering::Less => format!("falls to {}", after),
+        Ordering::Equal => format!("holds at {}", after),
+    }
+}
+
+const PROMPT_OFFSET_X: u16 = 10;
+const PROMPT_OFFSET_Y: u16 = 23;
+
+pub struct BuyPrompt;
 
 impl Command for BuyPrompt {
     fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
@@ -665,45 +1215,28 @@ impl Command for GoodOptions {
     fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
         let offset_x = self.0;
         let offset_y = self.1;
+        // as many columns as it takes to fit the catalog above the way back
+        const MAX_ROWS: usize = 6;
+        const COLUMN_WIDTH: u16 = 20;
+        let columns = Good::COUNT.div_ceil(MAX_ROWS);
+        let rows = Good::COUNT.div_ceil(columns);
+        for (i, good) in Good::variants_iter().enumerate() {
+            comp!(
+                f,
+                MoveTo(
+                    offset_x + COLUMN_WIDTH * (i / rows) as u16,
+                    offset_y + (i % rows) as u16
+                ),
+                KeyInputAction {
+                    num_key: good.number_key(),
+                    char_key: good.key(),
+                    text: good.to_string()
+                },
+            );
+        }
         comp!(
             f,
-            MoveTo(offset_x, offset_y),
-            KeyInputAction {
-                num_key: 1,
-                char_key: 't',
-                text: "Tea".to_owned()
-            },
-            MoveTo(offset_x, offset_y + 1),
-            KeyInputAction {
-                num_key: 2,
-                char_key: 'c',
-                text: "Coffee".to_owned()
-            },
-            MoveTo(offset_x, offset_y + 2),
-            KeyInputAction {
-                num_key: 3,
-                char_key: 's',
-                text: "Sugar".to_owned()
-            },
-            MoveTo(offset_x, offset_y + 3),
-            KeyInputAction {
-                num_key: 4,
-                char_key: 'a',
-                text: "Tobacco".to_owned()
-            },
-            MoveTo(offset_x, offset_y + 4),
-            KeyInputAction {
-                num_key: 5,
-                char_key: 'r',
-                text: "Rum".to_owned()
-            },
-            MoveTo(offset_x, offset_y + 5),
-            KeyInputAction {
-                num_key: 6,
-                char_key: 'o',
-                text: "Cotton".to_owned()
-            },
-            MoveTo(offset_x, offset_y + 6),
+            MoveTo(offset_x, offset_y + rows as u16),
             Print("(b) <- back"),
         );
         Ok(())
@@ -715,12 +1248,21 @@ impl Command for GoodOptions {
     }
 }
 
-pub struct SellInput<'a>(pub &'a Transaction, pub &'a u32);
+pub struct SellInput<'a> {
+    pub info: &'a Transaction,
+    pub state: &'a GameState,
+}
 
 impl<'a> Command for SellInput<'a> {
     fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
-        let info = self.0;
-        let current_amount = self.1;
+        let info = self.info;
+        let state = self.state;
+        let current_amount = state.inventory.get_good(&info.good);
+        let current_price = state
+            .locations
+            .location_info(&state.location)
+            .prices
+            .get_good(&info.good);
         const OFFSET_X: u16 = PROMPT_OFFSET_X;
         const OFFSET_Y: u16 = PROMPT_OFFSET_Y;
         let good = &info.good;
@@ -738,8 +1280,55 @@ impl<'a> Command for SellInput<'a> {
             Print(prompt),
             MoveTo(OFFSET_X, OFFSET_Y + 2),
             Print(format!("You have ({})", current_amount)),
-            MoveTo(OFFSET_X, OFFSET_Y + 5),
+        );
+        // the way back moves down to make room for the duty
+        let rate = state.customs().import_rate(good);
+        let duty = state.import_duty(good, info.amount.unwrap_or(0));
+        let back_row = match customs_line(state, good, "Import", rate, duty) {
+            Some(line) => {
+                comp!(f, MoveTo(OFFSET_X, OFFSET_Y + 5), Print(line));
+                OFFSET_Y + 6
+            }
+            None => OFFSET_Y + 5,
+        };
+        comp!(
+            f,
+            MoveTo(OFFSET_X, back_row),
             Print("(b) <- back".to_string()),
+        );
+        // the oldest go first, so this is the quality of what would be sold
+        let quality = state.quality(good, info.amount.unwrap_or(*current_amount));
+        if quality != 1.0 {
+            let have_len = format!("You have ({})", current_amount).len() as u16;
+            comp!(
+                f,
+                MoveTo(OFFSET_X + have_len, OFFSET_Y + 2),
+                Print(format!(", quality {:.0}%", quality * 100.0)),
+            );
+        }
+        if let Some(average) = state.inventory_cost.average(good, *current_amount) {
+            comp!(
+                f,
+                MoveTo(OFFSET_X, OFFSET_Y + 3),
+                Print(format!("Bought for {} each on average", average)),
+            );
+        }
+        if let Some(amount) = info.amount.filter(|amount| *amount > 0) {
+            comp!(
+                f,
+                MoveTo(OFFSET_X, OFFSET_Y + 4),
+                Print(format!(
+                    "Profit: {:+}, price {}",
+                    state.profit_from_selling(good, amount),
+                    price_movement(
+                        *current_price,
+                        state.price_after_trade(good, -(amount as i64))
+                    )
+                )),
+            );
+        }
+        comp!(
+            f,
             // position cursor for input
             MoveTo(OFFSET_X + prompt_len, OFFSET_Y + 1),
             Show
@@ -774,55 +1363,56 @@ impl Command for SellPrompt {
     }
 }
 
-pub struct SailPrompt;
+/// the ports to sail to, with how many months away each is
+pub struct SailPrompt<'a>(pub &'a GameState);
 
-impl Command for SailPrompt {
+impl<'a> Command for SailPrompt<'a> {
     fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
         const OFFSET_X: u16 = PROMPT_OFFSET_X;
         const OFFSET_Y: u16 = PROMPT_OFFSET_Y;
+        const ROWS: usize = 6;
+        const COLUMN_WIDTH: u16 = 21;
+        // past the keys and the longest name
+        let travel_x = 4 + Location::longest_name() as u16 + 1;
         comp!(
             f,
             MoveTo(OFFSET_X, OFFSET_Y),
             Print("Where do you want to sail?"),
-            MoveTo(OFFSET_X, OFFSET_Y + 1),
-            KeyInputAction {
-                num_key: 1,
-                char_key: 'l',
-                text: "London".to_owned()
-            },
-            MoveTo(OFFSET_X, OFFSET_Y + 2),
-            KeyInputAction {
-                num_key: 2,
-                char_key: 's',
-                text: "Savannah".to_owned()
-            },
-            MoveTo(OFFSET_X, OFFSET_Y + 3),
-            KeyInputAction {
-                num_key: 3,
-                char_key: 'i',
-                text: "Lisbon".to_owned()
-            },
-            MoveTo(OFFSET_X, OFFSET_Y + 4),
-            KeyInputAction {
-                num_key: 4,
-                char_key: 'a',
-                text: "Amsterdam".to_owned()
-            },
-            MoveTo(OFFSET_X, OFFSET_Y + 5),
-            KeyInputAction {
-                num_key: 5,
-                char_key: 'c',
-                text: "Cape Town".to_owned()
-            },
-            MoveTo(OFFSET_X, OFFSET_Y + 6),
-            KeyInputAction {
-                num_key: 6,
-                char_key: 'v',
-                text: "Venice".to_owned()
-            },
-            MoveTo(OFFSET_X, OFFSET_Y + 7),
+        );
+        for (i, location) in Location::variants_iter().enumerate() {
+            let info = location.info();
+            let x = OFFSET_X + (i / ROWS) as u16 * COLUMN_WIDTH;
+            let y = OFFSET_Y + 1 + (i % ROWS) as u16;
+            let travel = if location == &self.0.location {
+                "here".to_owned()
+            } else {
+                format!("{} mo", self.0.voyage_months(location))
+            };
+            comp!(
+                f,
+                MoveTo(x, y),
+                KeyInputAction {
+                    num_key: location.number_key(),
+                    char_key: info.key,
+                    text: info.name.to_owned()
+                },
+                MoveTo(x + travel_x, y),
+                Print(travel),
+            );
+        }
+        let rows = Location::COUNT.min(ROWS) as u16;
+        comp!(
+            f,
+            MoveTo(OFFSET_X, OFFSET_Y + 1 + rows),
             Print("(b) <- back"),
         );
+        if self.0.hull_slows_ship() {
+            comp!(
+                f,
+                MoveTo(OFFSET_X + 15, OFFSET_Y + 1 + rows),
+                Print("The damaged hull slows every voyage."),
+            );
+        }
         Ok(())
     }
 
@@ -950,11 +1540,30 @@ impl Command for StashWithdrawPrompt {
     }
 }
 
-pub struct PayDebtInput<'a>(pub &'a Option<u32>);
+/// pays down the loans, the soonest due first, wherever they were borrowed
+pub struct PayDebtInput<'a>(pub &'a Option<u32>, pub &'a Loans);
 
 impl<'a> Command for PayDebtInput<'a> {
     fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
         let amount = self.0;
+        let loans = self.1;
+        let owed = format!("You owe {} in all.", loans.total());
+        // where the loan that comes due next was borrowed, since it's paid first
+        let next_due = loans
+            .owed_by_due_date()
+            .first()
+            .and_then(|location| {
+                let loan = loans.get(location);
+                let (year, month) = loan.due?;
+                Some(format!(
+                    "{} to {} by {} {}.",
+                    loan.balance,
+                    location,
+                    &month.name()[..3],
+                    year
+                ))
+            })
+            .unwrap_or_default();
         const OFFSET_X: u16 = PROMPT_OFFSET_X;
         const OFFSET_Y: u16 = PROMPT_OFFSET_Y;
         let prompt = format!(
@@ -968,6 +1577,108 @@ impl<'a> Command for PayDebtInput<'a> {
             Print("How much debt do you"),
             MoveTo(OFFSET_X, OFFSET_Y + 1),
             Print(prompt),
+            MoveTo(OFFSET_X, OFFSET_Y + 3),
+            Print(owed),
+            MoveTo(OFFSET_X, OFFSET_Y + 4),
+            Print(next_due),
+            MoveTo(OFFSET_X, OFFSET_Y + 5),
+            Print("(b) <- back".to_string()),
+            MoveTo(OFFSET_X + prompt_len, OFFSET_Y + 1),
+            Show
+        );
+        Ok(())
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> std::io::Result<()> {
+        todo!()
+    }
+}
+
+/// borrows from the lender of the current port
+pub struct BorrowInput<'a> {
+    pub amount: &'a Option<u32>,
+    pub lender: Lender,
+    /// how much more the lender will lend
+    pub available: u32,
+    /// when what's already borrowed here is due, which borrowing more doesn't put off
+    pub due: Option<(u16, Month)>,
+}
+
+impl<'a> Command for BorrowInput<'a> {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        const OFFSET_X: u16 = PROMPT_OFFSET_X;
+        const OFFSET_Y: u16 = PROMPT_OFFSET_Y;
+        let prompt = format!(
+            "want to borrow? {}",
+            self.amount
+                .map_or("".to_owned(), |amount| amount.to_string())
+        );
+        let prompt_len: u16 = prompt.len().try_into().unwrap();
+        comp!(
+            f,
+            MoveTo(OFFSET_X, OFFSET_Y),
+            Print("How much gold do you"),
+            MoveTo(OFFSET_X, OFFSET_Y + 1),
+            Print(prompt),
+            MoveTo(OFFSET_X, OFFSET_Y + 3),
+            Print(format!(
+                "Up to {} at {}% a month,",
+                self.available,
+                (self.lender.interest_rate * 100.0).round()
+            )),
+            MoveTo(OFFSET_X, OFFSET_Y + 4),
+            Print(match self.due {
+                Some((year, month)) => format!("to be repaid by {} {}.", &month.name()[..3], year),
+                None => format!("to be repaid within {} months.", self.lender.term_months),
+            }),
+            MoveTo(OFFSET_X, OFFSET_Y + 5),
+            Print("(b) <- back".to_string()),
+            MoveTo(OFFSET_X + prompt_len, OFFSET_Y + 1),
+            Show
+        );
+        Ok(())
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> std::io::Result<()> {
+        todo!()
+    }
+}
+
+/// signs on sailors in the current port
+pub struct HireCrewInput<'a> {
+    pub amount: &'a Option<u32>,
+    /// what each sailor asks to sign on
+    pub fee: u32,
+    /// what each sailor is paid for a month at sea
+    pub wage: u32,
+    pub free_berths: u32,
+}
+
+impl<'a> Command for HireCrewInput<'a> {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        const OFFSET_X: u16 = PROMPT_OFFSET_X;
+        const OFFSET_Y: u16 = PROMPT_OFFSET_Y;
+        let prompt = format!(
+            "want to sign on? {}",
+            self.amount
+                .map_or("".to_owned(), |amount| amount.to_string())
+        );
+        let prompt_len: u16 = prompt.len().try_into().unwrap();
+        comp!(
+            f,
+            MoveTo(OFFSET_X, OFFSET_Y),
+            Print("How many sailors do you"),
+            MoveTo(OFFSET_X, OFFSET_Y + 1),
+            Print(prompt),
+            MoveTo(OFFSET_X, OFFSET_Y + 3),
+            Print(format!(
+                "{} gold each, and {} a month in wages.",
+                self.fee, self.wage
+            )),
+            MoveTo(OFFSET_X, OFFSET_Y + 4),
+            Print(format!("Berths for {} more.", self.free_berths)),
             MoveTo(OFFSET_X, OFFSET_Y + 5),
             Print("(b) <- back".to_string()),
             MoveTo(OFFSET_X + prompt_len, OFFSET_Y + 1),
@@ -1099,7 +1810,11 @@ impl Command for GoodsStolenDialog {
     }
 }
 
-pub struct CanBuyCannon;
+pub struct CanBuyCannon {
+    pub cost: u32,
+    /// the player's ship, if it already carries as many cannons as it can
+    pub full: Option<ShipClass>,
+}
 
 impl Command for CanBuyCannon {
     fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
@@ -1110,9 +1825,283 @@ impl Command for CanBuyCannon {
             MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y + 1),
             Print("offers to outfit your ship with an"),
             MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y + 2),
-            Print(format!("additional cannon for {CANNON_COST} gold.")),
-            MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y + 4),
-            Print("Accept? y/n"),
+            Print(format!("additional cannon for {} gold.", self.cost)),
+        );
+        if let Some(class) = self.full {
+            comp!(
+                f,
+                MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y + 3),
+                Print(format!("But your {} carries no more.", class)),
+                MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y + 5),
+                Print("(press any key to continue)"),
+            );
+        } else {
+            comp!(
+                f,
+                MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y + 4),
+                Print("Accept? y/n"),
+            );
+        }
+        Ok(())
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> std::io::Result<()> {
+        todo!()
+    }
+}
+
+pub struct DebtCollectorsDialog {
+    pub lender: Location,
+    pub months_overdue: u32,
+    pub collection: DebtCollection,
+}
+
+impl Command for DebtCollectorsDialog {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        let months = if self.months_overdue == 1 {
+            "a month".to_owned()
+        } else {
+            format!("{} months", self.months_overdue)
+        };
+        comp!(
+            f,
+            MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y),
+            Print(format!("Collectors for your {} lender", self.lender)),
+            MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y + 1),
+            Print("are waiting on the docks. Your loan is"),
+            MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y + 2),
+            Print(format!("{} overdue, and they take", months)),
+            MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y + 3),
+        );
+        if self.collection.seizes_cargo {
+            comp!(
+                f,
+                Print(format!(
+                    "{} gold and everything in your hold!",
+                    self.collection.gold
+                )),
+            );
+        } else {
+            comp!(
+                f,
+                Print(format!(
+                    "{} gold towards what you owe.",
+                    self.collection.gold
+                )),
+            );
+        }
+        comp!(
+            f,
+            MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y + 5),
+            Print("(press any key to continue)".to_string())
+        );
+        Ok(())
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> std::io::Result<()> {
+        todo!()
+    }
+}
+
+pub struct BankRunDialog {
+    pub lost: u32,
+    pub frozen_until: (u16, Month),
+}
+
+impl Command for BankRunDialog {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        let (year, month) = self.frozen_until;
+        comp!(
+            f,
+            MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y),
+            Print("Word reaches you of a run on your bank!"),
+            MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y + 1),
+            Print(format!("{} gold of your deposit is lost, and", self.lost)),
+            MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y + 2),
+            Print("the rest can't be withdrawn until"),
+            MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y + 3),
+            Print(format!("{} {}.", month.name(), year)),
+            MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y + 5),
+            Print("(press any key to continue)".to_string())
+        );
+        Ok(())
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> std::io::Result<()> {
+        todo!()
+    }
+}
+
+pub struct DesertionDialog {
+    pub sailors: u32,
+}
+
+impl Command for DesertionDialog {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        comp!(
+            f,
+            MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y),
+            Print("Your crew has had enough of you!"),
+            MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y + 1),
+            Print(format!(
+                "{} of them slipped ashore and won't be back.",
+                self.sailors
+            )),
+            MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y + 5),
+            Print("(press any key to continue)".to_string())
+        );
+        Ok(())
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> std::io::Result<()> {
+        todo!()
+    }
+}
+
+pub struct MutinyDialog {
+    pub sailors: u32,
+    pub gold: u32,
+}
+
+impl Command for MutinyDialog {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        comp!(
+            f,
+            MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y),
+            Print("Mutiny! Your crew rose up on the way into port."),
+            MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y + 1),
+            Print(format!(
+                "{} of them made off with {} of your gold.",
+                self.sailors, self.gold
+            )),
+            MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y + 2),
+            Print("Those who stayed are loyal, for now."),
+            MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y + 5),
+            Print("(press any key to continue)".to_string())
+        );
+        Ok(())
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> std::io::Result<()> {
+        todo!()
+    }
+}
+
+pub struct CustomsInspectionDialog {
+    pub inspection: CustomsInspection,
+    pub gold: u32,
+}
+
+impl Command for CustomsInspectionDialog {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        let inspection = &self.inspection;
+        comp!(
+            f,
+            MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y),
+            Print("Customs officers search your hold"),
+        );
+        if !inspection.found_contraband() {
+            comp!(
+                f,
+                MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y + 1),
+                Print("and find nothing amiss."),
+                MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y + 3),
+                Print("(press any key to continue)".to_string())
+            );
+            return Ok(());
+        }
+        let found = inspection
+            .contraband
+            .iter()
+            .filter(|(_, amount)| **amount > 0)
+            .map(|(good, amount)| format!("{} {}", amount, good))
+            .collect::<Vec<String>>()
+            .join(", ");
+        comp!(
+            f,
+            MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y + 1),
+            Print(format!("and find {}, forbidden here.", found)),
+            MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y + 2),
+            Print(format!(
+                "They'll seize it and fine you {} gold,",
+                inspection.fine
+            )),
+            MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y + 3),
+            Print(format!("unless you slip them {} gold.", inspection.bribe)),
+            MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y + 5),
+        );
+        if self.gold >= inspection.bribe {
+            comp!(f, Print("Pay the bribe? y/n"));
+        } else {
+            comp!(f, Print("Pay the bribe? y/n (you can't afford it)"));
+        }
+        Ok(())
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> std::io::Result<()> {
+        todo!()
+    }
+}
+
+pub struct WarehouseLossesDialog<'a>(pub &'a [WarehouseLoss]);
+
+impl<'a> Command for WarehouseLossesDialog<'a> {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        comp!(
+            f,
+            MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y),
+            Print("News from your warehouses:"),
+        );
+        for (i, loss) in self.0.iter().enumerate() {
+            comp!(
+                f,
+                MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y + 1 + i as u16),
+                Print(loss.to_string()),
+            );
+        }
+        let continue_row = PROMPT_OFFSET_Y + 2 + self.0.len().min(4) as u16;
+        comp!(
+            f,
+            MoveTo(PROMPT_OFFSET_X, continue_row),
+            Print("(press any key to continue)".to_string())
+        );
+        Ok(())
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> std::io::Result<()> {
+        todo!()
+    }
+}
+
+/// contracts delivered on arriving in port, or whose deadlines passed
+pub struct ContractsSettledDialog<'a>(pub &'a [ContractOutcome]);
+
+impl<'a> Command for ContractsSettledDialog<'a> {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        comp!(
+            f,
+            MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y),
+            Print("News of your contracts:"),
+        );
+        for (i, outcome) in self.0.iter().enumerate() {
+            comp!(
+                f,
+                MoveTo(PROMPT_OFFSET_X, PROMPT_OFFSET_Y + 1 + i as u16),
+                Print(outcome.to_string()),
+            );
+        }
+        let continue_row = PROMPT_OFFSET_Y + 2 + self.0.len().min(4) as u16;
+        comp!(
+            f,
+            MoveTo(PROMPT_OFFSET_X, continue_row),
+            Print("(press any key to continue)".to_string())
         );
         Ok(())
     }
@@ -1155,18 +2144,84 @@ impl Command for CanBuyHoldSpace {
     }
 }
 
+/// the named captain leading the pirates, and what there is to know of them
+pub struct CaptainSighting {
+    pub captain: PirateCaptain,
+    pub record: PirateRecord,
+    /// what the port authorities would pay for sinking them
+    pub bounty: u32,
+}
+
 pub struct PirateEncounter {
     pub pirate_encounter_state: PirateEncounterState,
+    /// the port the ship is bound for, whose authorities pay any bounty
+    pub location: Location,
+    pub captain: Option<CaptainSighting>,
     pub cannons: u8,
+    /// how many of the cannons there are sailors enough to fire
+    pub manned: u8,
     pub date: (u16, Month),
+    /// the gold the pirates ask to leave, if the player has it
+    pub bribe: Option<u32>,
+    /// whether there's anything in the hold to offer, surrender or dump
+    pub has_cargo: bool,
 }
 
 impl From<(PirateEncounterState, &mut GameState)> for PirateEncounter {
     fn from(value: (PirateEncounterState, &mut GameState)) -> Self {
+        let bribe = match value.0 {
+            PirateEncounterState::Prompt { info } => Some(value.1.pirate_bribe(info.cur_pirates)),
+            _ => None,
+        }
+        .filter(|bribe| *bribe <= value.1.gold.0);
+        let captain = match value.0 {
+            PirateEncounterState::Prompt { info } => info.captain,
+            _ => None,
+        }
+        .map(|captain| CaptainSighting {
+            captain,
+            record: value.1.pirates.get(captain),
+            bounty: value.1.bounty(captain),
+        });
         PirateEncounter {
             pirate_encounter_state: value.0,
+            location: value.1.location,
+            captain,
             cannons: value.1.cannons.0,
+            manned: value.1.cannons_manned(),
             date: value.1.date,
+            bribe,
+            has_cargo: value.1.inventory.total_amount() > 0,
+        }
+    }
+}
+
+impl PirateEncounter {
+    /// the ship's cannons, and how many are manned if not all of them are
+    fn cannons_text(&self) -> String {
+        if self.manned < self.cannons {
+            format!("{} ({} manned)", self.cannons, self.manned)
+        } else {
+            self.cannons.to_string()
+        }
+    }
+
+    /// the ways out of the fight the player can afford, as one sentence
+    fn other_options(&self) -> Option<String> {
+        let mut options = vec![];
+        if let Some(bribe) = self.bribe {
+            options.push(format!("(g)ive them {} gold", bribe));
+        }
+        if self.has_cargo {
+            options.push("offer them (c)argo".to_owned());
+            options.push("(s)urrender cargo".to_owned());
+            options.push("(d)ump cargo and run".to_owned());
+        }
+        let last = options.pop()?;
+        if options.is_empty() {
+            Some(format!("Or {} ?", last))
+        } else {
+            Some(format!("Or {} or {} ?", options.join(", "), last))
         }
     }
 }
@@ -1202,16 +2257,54 @@ impl Command for PirateEncounter {
                     Print(CenteredText(
                         format!(
                             "Health {}, Pirates {}, Cannons {}.",
-                            info.health, info.cur_pirates, self.cannons
+                            info.health,
+                            info.cur_pirates,
+                            self.cannons_text()
                         ),
                         (FRAME_WIDTH - 2).into()
                     )),
-                    MoveTo(1, 15),
+                    MoveTo(1, 13),
                     Print(CenteredText(
-                        "Will you (r)un or (f)ight ?".to_owned(),
+                        "Will you (r)un, (f)ight, fire a (b)roadside or b(o)ard them ?".to_owned(),
                         (FRAME_WIDTH - 2).into()
                     )),
                 );
+                if let Some(other_options) = self.other_options() {
+                    comp!(
+                        f,
+                        MoveTo(1, 15),
+                        Print(CenteredText(other_options, (FRAME_WIDTH - 2).into())),
+                    );
+                }
+                if let Some(sighting) = &self.captain {
+                    // this meeting is already counted
+                    let met_before = match sighting.record.encounters.saturating_sub(1) {
+                        0 => "".to_owned(),
+                        1 => " who has met you once before".to_owned(),
+                        n => format!(" who has met you {} times before", n),
+                    };
+                    comp!(
+                        f,
+                        MoveTo(1, 10),
+                        Print(CenteredText(
+                            format!(
+                                "They sail under {}, a {} captain{}.",
+                                sighting.captain,
+                                sighting.record.reputation(),
+                                met_before
+                            ),
+                            (FRAME_WIDTH - 2).into()
+                        )),
+                        MoveTo(1, 11),
+                        Print(CenteredText(
+                            format!(
+                                "There's a bounty of {} gold on their head.",
+                                sighting.bounty
+                            ),
+                            (FRAME_WIDTH - 2).into()
+                        )),
+                    );
+                }
             }
             PirateEncounterState::RunSuccess => {
                 comp!(
@@ -1235,7 +2328,9 @@ impl Command for PirateEncounter {
                     Print(CenteredText(
                         format!(
                             "Health {}, Pirates {}, Cannons {}.",
-                            info.health, info.cur_pirates, self.cannons
+                            info.health,
+                            info.cur_pirates,
+                            self.cannons_text()
                         ),
                         (FRAME_WIDTH - 2).into()
                     )),
@@ -1261,7 +2356,7 @@ impl Command for PirateEncounter {
                     Print(CenteredText(
                         format!(
                             "Health {}, Pirates {}, Cannons {}.",
-                            info.health, info.cur_pirates, self.cannons
+                            info.health, info.cur_pirates, self.cannons_text()
                         ),
                         (FRAME_WIDTH - 2).into()
                     )),
@@ -1307,7 +2402,9 @@ impl Command for PirateEncounter {
                     Print(CenteredText(
                         format!(
                             "Health {}, Pirates {}, Cannons {}.",
-                            info.health, info.cur_pirates, self.cannons
+                            info.health,
+                            info.cur_pirates,
+                            self.cannons_text()
                         ),
                         (FRAME_WIDTH - 2).into()
                     )),
@@ -1330,7 +2427,10 @@ impl Command for PirateEncounter {
                     )),
                 )
             }
-            PirateEncounterState::Victory { gold_recovered } => {
+            PirateEncounterState::Victory {
+                gold_recovered,
+                bounty,
+            } => {
                 comp!(
                     f,
                     MoveTo(1, 8),
@@ -1338,9 +2438,201 @@ impl Command for PirateEncounter {
                         "You have sank all of the pirates!".to_string(),
                         (FRAME_WIDTH - 2).into()
                     )),
+                );
+                // ships taken by boarding were plundered already and leave no wreckage
+                if gold_recovered > 0 {
+                    comp!(
+                        f,
+                        MoveTo(1, 12),
+                        Print(CenteredText(
+                            format!("You recover {} gold from the wreckage!", gold_recovered),
+                            (FRAME_WIDTH - 2).into()
+                        )),
+                    );
+                }
+                if let Some(Bounty { captain, gold }) = bounty {
+                    comp!(
+                        f,
+                        MoveTo(1, 13),
+                        Print(CenteredText(
+                            format!(
+                                "The authorities of {} pay a bounty of {} gold for {}.",
+                                self.location, gold, captain
+                            ),
+                            (FRAME_WIDTH - 2).into()
+                        )),
+                    );
+                }
+                comp!(
+                    f,
+                    MoveTo(1, 16),
+                    Print(CenteredText(
+                        "(press any key to continue)".to_string(),
+                        (FRAME_WIDTH - 2).into()
+                    )),
+                )
+            }
+            PirateEncounterState::BroadsideResult { info, sunk } => {
+                comp!(
+                    f,
+                    MoveTo(1, 8),
+                    Print(CenteredText(
+                        format!(
+                            "Health {}, Pirates {}, Cannons {}.",
+                            info.health,
+                            info.cur_pirates,
+                            self.cannons_text()
+                        ),
+                        (FRAME_WIDTH - 2).into()
+                    )),
                     MoveTo(1, 12),
                     Print(CenteredText(
-                        format!("You recover {} gold from the wreckage!", gold_recovered),
+                        format!(
+                            "You fire a broadside at the pirates, {}",
+                            match sunk {
+                                0 => "but every shot falls short!",
+                                1 => "and you sink one of them!",
+                                _ => "and you sink two of them!",
+                            }
+                        ),
+                        (FRAME_WIDTH - 2).into()
+                    )),
+                    MoveTo(1, 16),
+                    Print(CenteredText(
+                        "(press any key to continue)".to_string(),
+                        (FRAME_WIDTH - 2).into()
+                    )),
+                )
+            }
+            PirateEncounterState::BoardResult { info, plunder } => {
+                comp!(
+                    f,
+                    MoveTo(1, 8),
+                    Print(CenteredText(
+                        format!(
+                            "Health {}, Pirates {}, Cannons {}.",
+                            info.health,
+                            info.cur_pirates,
+                            self.cannons_text()
+                        ),
+                        (FRAME_WIDTH - 2).into()
+                    )),
+                    MoveTo(1, 12),
+                    Print(CenteredText(
+                        match plunder {
+                            Some(plunder) => format!(
+                                "You board a pirate ship and take it, with {} gold aboard!",
+                                plunder
+                            ),
+                            None => "Your boarding party is driven back to your ship!".to_owned(),
+                        },
+                        (FRAME_WIDTH - 2).into()
+                    )),
+                    MoveTo(1, 16),
+                    Print(CenteredText(
+                        "(press any key to continue)".to_string(),
+                        (FRAME_WIDTH - 2).into()
+                    )),
+                )
+            }
+            PirateEncounterState::BribeResult {
+                info,
+                offer,
+                accepted,
+            } => {
+                let offer = match offer {
+                    PirateBribe::Gold(gold) => format!("{} gold", gold),
+                    PirateBribe::Cargo(goods) => format!("{} of your goods", goods),
+                };
+                if accepted {
+                    comp!(
+                        f,
+                        MoveTo(1, 8),
+                        Print(CenteredText(
+                            format!("The pirates take {} and sail away.", offer),
+                            (FRAME_WIDTH - 2).into()
+                        )),
+                    );
+                } else {
+                    comp!(
+                        f,
+                        MoveTo(1, 8),
+                        Print(CenteredText(
+                            format!(
+                                "Health {}, Pirates {}, Cannons {}.",
+                                info.health,
+                                info.cur_pirates,
+                                self.cannons_text()
+                            ),
+                            (FRAME_WIDTH - 2).into()
+                        )),
+                        MoveTo(1, 12),
+                        Print(CenteredText(
+                            format!("The pirates scoff at {}!", offer),
+                            (FRAME_WIDTH - 2).into()
+                        )),
+                    );
+                }
+                comp!(
+                    f,
+                    MoveTo(1, 16),
+                    Print(CenteredText(
+                        "(press any key to continue)".to_string(),
+                        (FRAME_WIDTH - 2).into()
+                    )),
+                )
+            }
+            PirateEncounterState::Surrendered { goods } => {
+                comp!(
+                    f,
+                    MoveTo(1, 8),
+                    Print(CenteredText(
+                        "You strike your colours.".to_owned(),
+                        (FRAME_WIDTH - 2).into()
+                    )),
+                    MoveTo(1, 12),
+                    Print(CenteredText(
+                        format!(
+                            "The pirates help themselves to {} of your goods and leave you be.",
+                            goods
+                        ),
+                        (FRAME_WIDTH - 2).into()
+                    )),
+                    MoveTo(1, 16),
+                    Print(CenteredText(
+                        "(press any key to continue)".to_string(),
+                        (FRAME_WIDTH - 2).into()
+                    )),
+                )
+            }
+            PirateEncounterState::DumpResult {
+                info,
+                dumped,
+                escaped,
+            } => {
+                comp!(
+                    f,
+                    MoveTo(1, 8),
+                    Print(CenteredText(
+                        format!(
+                            "Health {}, Pirates {}, Cannons {}.",
+                            info.health,
+                            info.cur_pirates,
+                            self.cannons_text()
+                        ),
+                        (FRAME_WIDTH - 2).into()
+                    )),
+                    MoveTo(1, 12),
+                    Print(CenteredText(
+                        format!(
+                            "You throw {} goods overboard, {}",
+                            dumped,
+                            if escaped {
+                                "and your lighter ship outruns the pirates!"
+                            } else {
+                                "but the pirates keep pace with you!"
+                            }
+                        ),
                         (FRAME_WIDTH - 2).into()
                     )),
                     MoveTo(1, 16),
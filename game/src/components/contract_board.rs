@@ -0,0 +1,193 @@
+use std::fmt::{self};
+
+use chrono::Month;
+use crossterm::{
+    cursor::{Hide, MoveTo},
+    style::{style, Attribute, Print, Stylize},
+    terminal::Clear,
+    Command,
+};
+
+use crate::{
+    components::{Frame, FrameType, ScreenCenteredText},
+    state::{Contract, ContractOffer, Location},
+};
+
+/// the deliveries the merchants of the current port will pay for, and those already taken on
+pub struct ContractBoard<'a> {
+    pub location: Location,
+    pub offers: &'a [ContractOffer],
+    pub contracts: &'a [Contract],
+    pub date: (u16, Month),
+    /// how many contracts can be taken on at once
+    pub max_contracts: u8,
+    /// the share of the reward paid for missing a deadline
+    pub penalty: f64,
+}
+
+const OFFSET_X: u16 = 14;
+const OFFSET_Y: u16 = 5;
+
+impl<'a> Command for ContractBoard<'a> {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        comp!(
+            f,
+            Clear(crossterm::terminal::ClearType::All),
+            Hide,
+            Frame(FrameType::SimpleEmptyInside),
+            ScreenCenteredText::new_styleds(
+                &[style("Contract Board").attribute(Attribute::Bold)],
+                2
+            ),
+            MoveTo(OFFSET_X, OFFSET_Y),
+            Print(format!(
+                "The merchants of {} will pay for these deliveries:",
+                self.location
+            )),
+            MoveTo(OFFSET_X, OFFSET_Y + 10),
+            Print("Your contracts:"),
+            ScreenCenteredText::new(
+                &[format!(
+                    "Up to {} contracts can be taken on at once. Goods are delivered from the hold",
+                    self.max_contracts
+                )],
+                OFFSET_Y + 18
+            ),
+            ScreenCenteredText::new(
+                &[format!(
+                    "on arriving in port, and missing a deadline costs {:.0}% of the reward.",
+                    self.penalty * 100.0
+                )],
+                OFFSET_Y + 19
+            ),
+            ScreenCenteredText::new(&["(b) Back".to_owned()], 29),
+        );
+        if self.offers.is_empty() {
+            comp!(
+                f,
+                MoveTo(OFFSET_X + 2, OFFSET_Y + 2),
+                Print("Nothing on offer, come back another month."),
+            );
+        }
+        for (i, offer) in self.offers.iter().enumerate() {
+            // the deadline is counted from when the contract would be accepted
+            let contract = offer.accept(self.location, self.date);
+            comp!(
+                f,
+                MoveTo(OFFSET_X + 2, OFFSET_Y + 2 + 2 * i as u16),
+                Print(format!(
+                    "({}) Deliver {} for {} gold",
+                    i + 1,
+                    contract,
+                    contract.reward
+                )),
+            );
+        }
+        if self.contracts.is_empty() {
+            comp!(
+                f,
+                MoveTo(OFFSET_X + 2, OFFSET_Y + 12),
+                Print(style("none yet").attribute(Attribute::Dim)),
+            );
+        }
+        for (i, contract) in self.contracts.iter().enumerate() {
+            comp!(
+                f,
+                MoveTo(OFFSET_X + 2, OFFSET_Y + 12 + i as u16),
+                Print(format!(
+                    "{} for {} gold, from {}",
+                    contract, contract.reward, contract.issuer
+                )),
+            );
+        }
+        Ok(())
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> std::io::Result<()> {
+        todo!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::{state::Good, test::render_component};
+
+    use super::*;
+
+    #[test]
+    fn board() {
+        let offers = [
+            ContractOffer {
+                good: Good::Sugar,
+                amount: 40,
+                destination: Location::Venice,
+                months: 8,
+                reward: 9000,
+            },
+            ContractOffer {
+                good: Good::Tea,
+                amount: 20,
+                destination: Location::CapeTown,
+                months: 3,
+                reward: 150000,
+            },
+        ];
+        let contracts = [Contract {
+            good: Good::Rum,
+            amount: 60,
+            destination: Location::London,
+            issuer: Location::Savannah,
+            due: (1783, Month::January),
+            reward: 4000,
+        }];
+        assert_eq!(
+            render_component(ContractBoard {
+                location: Location::Lisbon,
+                offers: &offers,
+                contracts: &contracts,
+                date: (1782, Month::March),
+                max_contracts: 2,
+                penalty: 0.25,
+            }),
+            r#"
+---------------------------------------------------------------------------------------------------
+|                                                                                                 |
+|                                          Contract Board                                         |
+|                                                                                                 |
+|                                                                                                 |
+|             The merchants of Lisbon will pay for these deliveries:                              |
+|                                                                                                 |
+|               (1) Deliver 40 Sugar to Venice by Nov 1782 for 9000 gold                          |
+|                                                                                                 |
+|               (2) Deliver 20 Tea to Cape Town by Jun 1782 for 150000 gold                       |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|             Your contracts:                                                                     |
+|                                                                                                 |
+|               60 Rum to London by Jan 1783 for 4000 gold, from Savannah                         |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|           Up to 2 contracts can be taken on at once. Goods are delivered from the hold          |
+|               on arriving in port, and missing a deadline costs 25% of the reward.              |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                             (b) Back                                            |
+|                                                                                                 |
+|                                                                                                 |
+---------------------------------------------------------------------------------------------------
+"#
+            .trim()
+        );
+    }
+}
@@ -7,7 +7,6 @@ use crossterm::{
 };
 
 use crate::{
-    comp,
     components::{FRAME_HEIGHT, FRAME_WIDTH},
     state::Location,
 };
@@ -71,6 +70,11 @@ pub const VENICE_HORIZONTAL_TOP: &str = "╱╳╲╳╱╳╲╳╱╳╲╳╱
 pub const VENICE_HORIZONTAL_BOT: &str = "╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱";
 pub const VENICE_HORIZONTAL_MID: &str = "╲╱╱╲╲╱╱╲╲╱╱╲╲╱╱╲╲╱╱╲╲╱╱╲╲╱╱╲╲╱╱╲╲╱╱╲╲╱╱╲╲╱╱╲╲╱╱╲╲╱╱╲╲╱╱╲╲╱╱╲╲╱╱╲╲╱╱╲╲╱╱╲╲╱╱╲╲╱╱╲╲╱╱╲╲╱╱╲╲╱╱╲╲╱╱╲╲";
 
+/// for ports without a style of their own
+pub const PORT_HORIZONTAL_TOP: &str = "~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~";
+pub const PORT_HORIZONTAL_BOT: &str = "^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^";
+pub const PORT_HORIZONTAL_MID: &str = "~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~";
+
 impl Command for Frame {
     fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
         let (top, bot, mid, left_char_seq, right_char_seq) = match self.0 {
@@ -124,6 +128,13 @@ impl Command for Frame {
                     vec!['╳'],
                     vec!['╳'],
                 ),
+                _ => (
+                    PORT_HORIZONTAL_TOP,
+                    PORT_HORIZONTAL_BOT,
+                    Some(PORT_HORIZONTAL_MID),
+                    vec!['}', '{'],
+                    vec!['{', '}'],
+                ),
             },
         };
         comp!(
@@ -459,6 +470,49 @@ mod tests {
 ╳                                                                                                 ╳
 ╳                                                                                                 ╳
 ╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱
+"#
+            .trim()
+        );
+    }
+
+    #[test]
+    fn location_without_its_own_border() {
+        assert_eq!(
+            render_component(Frame(FrameType::Location(Location::Kingston))),
+            r#"
+~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~
+}                                                                                                 {
+{                                                                                                 }
+}                                                                                                 {
+{                                                                                                 }
+}                                                                                                 {
+{                                                                                                 }
+}                                                                                                 {
+{                                                                                                 }
+}                                                                                                 {
+{                                                                                                 }
+}                                                                                                 {
+{                                                                                                 }
+}                                                                                                 {
+{                                                                                                 }
+}                                                                                                 {
+{                                                                                                 }
+}                                                                                                 {
+{                                                                                                 }
+}~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~~-~{
+{                                                                                                 }
+}                                                                                                 {
+{                                                                                                 }
+}                                                                                                 {
+{                                                                                                 }
+}                                                                                                 {
+{                                                                                                 }
+}                                                                                                 {
+{                                                                                                 }
+}                                                                                                 {
+{                                                                                                 }
+}                                                                                                 {
+^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^~^
 "#
             .trim()
         );
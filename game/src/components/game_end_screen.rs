@@ -1,4 +1,4 @@
-use std::fmt::{self};
+use std::fmt::{self, Display};
 
 use crossterm::{
     cursor::{Hide, MoveTo},
@@ -6,11 +6,11 @@ use crossterm::{
     terminal::Clear,
     Command,
 };
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    comp,
-    components::{Frame, FrameType, ScreenCenteredText},
-    state::{GameState, PriceRanges},
+    components::{Frame, FrameType, ScreenCenteredText, YearsInWords},
+    state::{Difficulty, GameState, Good, PriceRanges},
 };
 
 const GAME_OVER: &str = r"
@@ -22,8 +22,13 @@ const GAME_OVER: &str = r"
  \_____|\__,_|_| |_| |_|\___|  \____/  \_/ \___|_|   
 ";
 
-pub struct GameEndScreen<'a>(pub &'a GameState);
+pub struct GameEndScreen<'a> {
+    pub state: &'a GameState,
+    /// the game's position in the high score table, if it made it in
+    pub high_score_rank: Option<usize>,
+}
 
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum AchievementTier {
     InDebt,
     Poor,
@@ -32,16 +37,35 @@ pub enum AchievementTier {
     Great,
 }
 
+impl Display for AchievementTier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let string = match self {
+            AchievementTier::InDebt => "In debt",
+            AchievementTier::Poor => "Poor",
+            AchievementTier::Ok => "Ok",
+            AchievementTier::Good => "Good",
+            AchievementTier::Great => "Great",
+        };
+        write!(f, "{}", string)
+    }
+}
+
 impl AchievementTier {
-    pub fn from_net_worth(net_worth: i32, overall_price_ranges: &PriceRanges) -> Self {
+    pub fn from_net_worth(
+        net_worth: i32,
+        overall_price_ranges: &PriceRanges,
+        difficulty: Difficulty,
+    ) -> Self {
         let net_worth = net_worth as i64;
+        let multiple = difficulty.achievement_multiple();
         if net_worth <= 0 {
             AchievementTier::InDebt
-        } else if net_worth <= (overall_price_ranges.rum.1 as i64).saturating_mul(50) {
+        } else if net_worth <= (overall_price_ranges[Good::Rum].1 as i64).saturating_mul(multiple) {
             AchievementTier::Poor
-        } else if net_worth <= (overall_price_ranges.sugar.1 as i64).saturating_mul(50) {
+        } else if net_worth <= (overall_price_ranges[Good::Sugar].1 as i64).saturating_mul(multiple)
+        {
             AchievementTier::Ok
-        } else if net_worth <= (overall_price_ranges.tea.1 as i64).saturating_mul(50) {
+        } else if net_worth <= (overall_price_ranges[Good::Tea].1 as i64).saturating_mul(multiple) {
             AchievementTier::Good
         } else {
             AchievementTier::Great
@@ -51,7 +75,7 @@ impl AchievementTier {
 
 impl<'a> Command for GameEndScreen<'a> {
     fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
-        let state = self.0;
+        let state = self.state;
         let starting_net_worth = state.starting_debt.0 as i64 - state.starting_gold.0 as i64;
         // let starting_gold = state.starting_gold.0;
         // let starting_debt = state.starting_debt.0;
@@ -59,6 +83,7 @@ impl<'a> Command for GameEndScreen<'a> {
         let achievement_tier = AchievementTier::from_net_worth(
             final_net_worth,
             &state.location_config.overall_price_ranges,
+            state.difficulty,
         );
         // results
         comp!(
@@ -66,7 +91,13 @@ impl<'a> Command for GameEndScreen<'a> {
             Clear(crossterm::terminal::ClearType::All),
             Hide,
             Frame(FrameType::SimpleEmptyInside),
-            ScreenCenteredText::new(&["After three years, you went from being".to_owned()], 13),
+            ScreenCenteredText::new(
+                &[format!(
+                    "After {}, you went from being",
+                    YearsInWords(state.rules.game_length_years)
+                )],
+                13
+            ),
             ScreenCenteredText::new_styleds(
                 &[style(format!("{starting_net_worth} gold in debt").as_str())
                     .attribute(Attribute::Bold)],
@@ -95,6 +126,8 @@ impl<'a> Command for GameEndScreen<'a> {
                 .attribute(Attribute::Bold)],
                 19
             ),
+            // scores from different difficulties aren't comparable
+            ScreenCenteredText::new(&[format!("on {} difficulty", state.difficulty)], 21),
         );
         match achievement_tier {
             AchievementTier::InDebt => comp!(
@@ -155,8 +188,30 @@ impl<'a> Command for GameEndScreen<'a> {
         }
         comp!(
             f,
-            ScreenCenteredText::new(&["(q) to quit, (Enter) to play again".to_owned()], 29),
+            ScreenCenteredText::new(
+                &["(q) to quit, (Enter) to play again, (s) to see the high scores".to_owned()],
+                29
+            ),
         );
+        if let Some(rank) = self.high_score_rank {
+            comp!(
+                f,
+                ScreenCenteredText::new_styleds(
+                    &[
+                        style(format!("A new high score! You placed #{}.", rank + 1).as_str())
+                            .attribute(Attribute::Bold)
+                    ],
+                    2
+                ),
+            );
+        }
+        if let Some(seed) = state.seed {
+            // so that a good run can be shared with others
+            comp!(
+                f,
+                ScreenCenteredText::new(&[format!("This voyage was seed {seed}")], 27),
+            );
+        }
         // "game over" ascii art terxt
         const OFFSET_X: u16 = 23;
         const OFFSET_Y: u16 = 4;
@@ -0,0 +1,167 @@
+use std::fmt::{self};
+
+use crossterm::{
+    cursor::{Hide, MoveTo},
+    style::{style, Attribute, Print, Stylize},
+    terminal::Clear,
+    Command,
+};
+
+use crate::{
+    components::{Frame, FrameType, ScreenCenteredText},
+    high_scores::HighScore,
+};
+
+pub struct HighScoresScreen<'a> {
+    pub entries: &'a [HighScore],
+    /// the entry to draw attention to, eg. the game just finished
+    pub highlight: Option<usize>,
+}
+
+const OFFSET_X: u16 = 7;
+const OFFSET_Y: u16 = 7;
+
+fn row(marker: &str, rank: &str, name: &str, values: [&str; 5]) -> String {
+    let [net_worth, tier, difficulty, date, seed] = values;
+    format!("{marker:1}{rank:>3} {name:<16} {net_worth:>11}  {tier:<8} {difficulty:<10} {date:<10}  {seed}")
+}
+
+impl<'a> Command for HighScoresScreen<'a> {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        comp!(
+            f,
+            Clear(crossterm::terminal::ClearType::All),
+            Hide,
+            Frame(FrameType::SimpleEmptyInside),
+            ScreenCenteredText::new_styleds(&[style("High Scores").attribute(Attribute::Bold)], 3),
+            ScreenCenteredText::new(&["Press any key to go back".to_owned()], 29),
+        );
+        if self.entries.is_empty() {
+            comp!(
+                f,
+                ScreenCenteredText::new(&["No voyages have been recorded yet.".to_owned()], 12),
+            );
+            return Ok(());
+        }
+        comp!(
+            f,
+            MoveTo(OFFSET_X, OFFSET_Y - 2),
+            Print(row(
+                "",
+                "#",
+                "Captain",
+                ["Net worth", "Result", "Difficulty", "Played", "Seed"]
+            )),
+        );
+        for (i, entry) in self.entries.iter().enumerate() {
+            let name: String = entry.name.chars().take(16).collect();
+            let line = row(
+                if self.highlight == Some(i) { ">" } else { "" },
+                &format!("{}.", i + 1),
+                &name,
+                [
+                    &entry.net_worth.to_string(),
+                    &entry.tier.to_string(),
+                    &entry.difficulty.to_string(),
+                    &entry.date.format("%Y-%m-%d").to_string(),
+                    &entry.seed.map(|seed| seed.to_string()).unwrap_or_default(),
+                ],
+            );
+            comp!(f, MoveTo(OFFSET_X, OFFSET_Y + (i as u16) * 2));
+            if self.highlight == Some(i) {
+                comp!(f, Print(style(line).attribute(Attribute::Bold)));
+            } else {
+                comp!(f, Print(line));
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> std::io::Result<()> {
+        todo!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use pretty_assertions::assert_eq;
+
+    use crate::{components::AchievementTier, state::Difficulty, test::render_component};
+
+    use super::*;
+
+    #[test]
+    fn highlighted_entry() {
+        let entries = [
+            HighScore {
+                name: "Anne Bonny".to_owned(),
+                net_worth: 1234567,
+                tier: AchievementTier::Great,
+                difficulty: Difficulty::Hard,
+                date: NaiveDate::from_ymd_opt(2024, 11, 5).unwrap(),
+                seed: Some(9713269763989775522),
+            },
+            HighScore {
+                name: "A captain with a very long name".to_owned(),
+                net_worth: -500,
+                tier: AchievementTier::InDebt,
+                difficulty: Difficulty::Easy,
+                date: NaiveDate::from_ymd_opt(2024, 12, 25).unwrap(),
+                seed: None,
+            },
+        ];
+        assert_eq!(
+            render_component(HighScoresScreen {
+                entries: &entries,
+                highlight: Some(1)
+            }),
+            r#"
+---------------------------------------------------------------------------------------------------
+|                                                                                                 |
+|                                                                                                 |
+|                                           High Scores                                           |
+|                                                                                                 |
+|         # Captain            Net worth  Result   Difficulty Played      Seed                    |
+|                                                                                                 |
+|        1. Anne Bonny           1234567  Great    Hard       2024-11-05  9713269763989775522     |
+|                                                                                                 |
+|      > 2. A captain with a        -500  In debt  Easy       2024-12-25                          |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                     Press any key to go back                                    |
+|                                                                                                 |
+|                                                                                                 |
+---------------------------------------------------------------------------------------------------
+"#
+            .trim()
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let formatted = render_component(HighScoresScreen {
+            entries: &[],
+            highlight: None,
+        });
+        assert!(formatted.contains("No voyages have been recorded yet."));
+    }
+}
@@ -8,14 +8,14 @@ use crossterm::{
 };
 
 use crate::{
-    comp,
-    components::{Frame, FrameType, ScreenCenteredText},
+    components::{Frame, FrameType, ScreenCenteredText, YearsInWords},
     state::Location,
 };
 
 pub struct IntroductionScreen {
     pub home: Location,
     pub starting_year: u16,
+    pub game_length_years: u16,
 }
 
 impl Command for IntroductionScreen {
@@ -23,6 +23,7 @@ impl Command for IntroductionScreen {
         let IntroductionScreen {
             home,
             starting_year,
+            game_length_years,
         } = self;
         comp!(
             f,
@@ -65,7 +66,8 @@ impl Command for IntroductionScreen {
             ScreenCenteredText::new_styleds(
                 &[
                     style("You have "),
-                    style("three years").attribute(Attribute::Bold),
+                    style(YearsInWords(*game_length_years).to_string().as_str())
+                        .attribute(Attribute::Bold),
                     style(" to make as much money as possible."),
                 ],
                 14
@@ -75,7 +77,8 @@ impl Command for IntroductionScreen {
                 24
             ),
             ScreenCenteredText::new_styleds(
-                &[style("Press any key to continue").attribute(Attribute::Bold)],
+                &[style("Choose your difficulty: (e)asy, (n)ormal or (h)ard")
+                    .attribute(Attribute::Bold)],
                 27
             ),
             Hide
@@ -102,7 +105,8 @@ mod tests {
         assert_eq!(
             render_component(IntroductionScreen {
                 home: Location::London,
-                starting_year: 1782
+                starting_year: 1782,
+                game_length_years: 3
             }),
             r#"
 '~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.
@@ -132,7 +136,7 @@ mod tests {
 |                             Fair winds and following seas, captain.                             |
 )                                                                                                 (
 |                                                                                                 |
-(                                    Press any key to continue                                    )
+(                        Choose your difficulty: (e)asy, (n)ormal or (h)ard                       )
 |                                                                                                 |
 )                                                                                                 (
 |                                                                                                 |
@@ -148,7 +152,8 @@ mod tests {
         assert_eq!(
             render_component(IntroductionScreen {
                 home: Location::Venice,
-                starting_year: 1785
+                starting_year: 1785,
+                game_length_years: 3
             }),
             r#"
 ╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲╳╱╳╲
@@ -178,7 +183,7 @@ mod tests {
 ╳                             Fair winds and following seas, captain.                             ╳
 ╳                                                                                                 ╳
 ╳                                                                                                 ╳
-╳                                    Press any key to continue                                    ╳
+╳                        Choose your difficulty: (e)asy, (n)ormal or (h)ard                       ╳
 ╳                                                                                                 ╳
 ╳                                                                                                 ╳
 ╳                                                                                                 ╳
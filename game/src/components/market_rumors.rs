@@ -0,0 +1,232 @@
+use std::fmt::{self};
+
+use chrono::Month;
+use crossterm::{
+    cursor::{Hide, MoveTo},
+    style::{style, Attribute, Print, Stylize},
+    terminal::Clear,
+    Command,
+};
+
+use crate::{
+    components::{Frame, FrameType, ScreenCenteredText, FRAME_WIDTH},
+    state::{months_between, Good, Inventory, Location, PriceHistory},
+};
+
+/// the last prices known for every port, which grow stale the longer ago they were seen
+pub struct MarketRumors<'a> {
+    pub history: &'a PriceHistory,
+    /// the port the player is in, whose prices are known for certain
+    pub location: Location,
+    pub prices: &'a Inventory,
+    pub date: (u16, Month),
+}
+
+const OFFSET_Y: u16 = 4;
+const SEEN_WIDTH: usize = 14;
+
+fn labels_width() -> usize {
+    Location::longest_name() + 2 + SEEN_WIDTH
+}
+
+/// how wide each good's column of prices is: roomy if the catalog allows,
+/// otherwise just wide enough for the highest price
+fn price_width(goods: usize) -> usize {
+    if labels_width() + goods * 6 <= FRAME_WIDTH as usize - 4 {
+        6
+    } else {
+        5
+    }
+}
+
+fn row(port: &str, seen: &str, prices: &str) -> String {
+    format!(
+        "{port:<width$}  {seen:<SEEN_WIDTH$}{prices}",
+        width = Location::longest_name()
+    )
+}
+
+fn prices_row(prices: impl Fn(&Good) -> String) -> String {
+    let width = price_width(Good::COUNT);
+    Good::variants_iter()
+        .map(|good| format!("{:>width$}", prices(good)))
+        .collect()
+}
+
+/// the names of the goods, every other one on the row below,
+/// so that the longer names can reach over the price before them
+fn header_rows() -> [String; 2] {
+    let width = price_width(Good::COUNT);
+    let mut rows = [
+        vec![' '; Good::COUNT * width],
+        vec![' '; Good::COUNT * width],
+    ];
+    for (i, good) in Good::variants_iter().enumerate() {
+        let name = good.to_string();
+        let start = ((i + 1) * width).saturating_sub(name.len());
+        for (j, c) in name.chars().enumerate() {
+            rows[i % 2][start + j] = c;
+        }
+    }
+    rows.map(|row| row.into_iter().collect::<String>().trim_end().to_owned())
+}
+
+fn age(months: i32) -> String {
+    match months {
+        0 => "this month".to_owned(),
+        1 => "1 month ago".to_owned(),
+        months => format!("{} months ago", months),
+    }
+}
+
+impl<'a> Command for MarketRumors<'a> {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        let x =
+            (FRAME_WIDTH - (labels_width() + Good::COUNT * price_width(Good::COUNT)) as u16) / 2;
+        let [upper, lower] = header_rows();
+        comp!(
+            f,
+            Clear(crossterm::terminal::ClearType::All),
+            Hide,
+            Frame(FrameType::SimpleEmptyInside),
+            ScreenCenteredText::new_styleds(
+                &[style("Market Rumors").attribute(Attribute::Bold)],
+                2
+            ),
+            ScreenCenteredText::new(&["(b) Back".to_owned()], 29),
+            MoveTo(x, OFFSET_Y),
+            Print(row("", "", &upper)),
+            MoveTo(x, OFFSET_Y + 1),
+            Print(row("Port", "Last seen", &lower)),
+        );
+        let mut y = OFFSET_Y + 3;
+        for location in Location::variants_iter() {
+            let name = location.to_string();
+            comp!(f, MoveTo(x, y));
+            if location == &self.location {
+                comp!(
+                    f,
+                    Print(
+                        style(row(
+                            &name,
+                            "here now",
+                            &prices_row(|good| self.prices.get_good(good).to_string())
+                        ))
+                        .attribute(Attribute::Bold)
+                    ),
+                );
+            } else if let Some(sighting) = self.history.sightings(location).last() {
+                comp!(
+                    f,
+                    Print(
+                        style(row(
+                            &name,
+                            &age(months_between(sighting.date, self.date)),
+                            &prices_row(|good| sighting.prices.get_good(good).to_string())
+                        ))
+                        .attribute(Attribute::Dim)
+                    ),
+                );
+            } else {
+                comp!(
+                    f,
+                    Print(row(&name, "never visited", &prices_row(|_| "?".to_owned()))),
+                );
+            }
+            y += 1;
+        }
+        comp!(
+            f,
+            ScreenCenteredText::new(
+                &["Prices elsewhere are as they were when last seen, and will have changed since."
+                    .to_owned()],
+                y + 1
+            ),
+        );
+        Ok(())
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> std::io::Result<()> {
+        todo!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::test::render_component;
+
+    use super::*;
+
+    #[test]
+    fn room_for_more_goods() {
+        assert_eq!(price_width(Good::COUNT), 6);
+        // a catalog of twelve still fits, with narrower columns
+        assert_eq!(price_width(12), 5);
+        assert!(labels_width() + 12 * price_width(12) <= FRAME_WIDTH as usize - 4);
+    }
+
+    #[test]
+    fn rumors() {
+        let mut history = PriceHistory::default();
+        let prices = |tea| {
+            Inventory::from([
+                (Good::Tea, tea),
+                (Good::Coffee, 2000),
+                (Good::Sugar, 700),
+                (Good::Tobacco, 200),
+                (Good::Rum, 40),
+                (Good::Cotton, 6),
+            ])
+        };
+        history.record((1782, Month::March), &Location::London, &prices(5000));
+        history.record((1782, Month::April), &Location::Venice, &prices(6000));
+        history.record((1782, Month::July), &Location::London, &prices(5500));
+        assert_eq!(
+            render_component(MarketRumors {
+                history: &history,
+                location: Location::London,
+                prices: &prices(5400),
+                date: (1782, Month::July),
+            }),
+            r#"
+---------------------------------------------------------------------------------------------------
+|                                                                                                 |
+|                                          Market Rumors                                          |
+|                                                                                                 |
+|                                  Tea       Sugar         Rum      Spices      Indigo            |
+|      Port       Last seen           Coffee     Tobacco      Cotton        Silk   Porcelain      |
+|                                                                                                 |
+|      London     here now        5400  2000   700   200    40     6     0     0     0     0      |
+|      Savannah   never visited      ?     ?     ?     ?     ?     ?     ?     ?     ?     ?      |
+|      Lisbon     never visited      ?     ?     ?     ?     ?     ?     ?     ?     ?     ?      |
+|      Amsterdam  never visited      ?     ?     ?     ?     ?     ?     ?     ?     ?     ?      |
+|      Cape Town  never visited      ?     ?     ?     ?     ?     ?     ?     ?     ?     ?      |
+|      Venice     3 months ago    6000  2000   700   200    40     6     0     0     0     0      |
+|      Kingston   never visited      ?     ?     ?     ?     ?     ?     ?     ?     ?     ?      |
+|      Havana     never visited      ?     ?     ?     ?     ?     ?     ?     ?     ?     ?      |
+|      Elmina     never visited      ?     ?     ?     ?     ?     ?     ?     ?     ?     ?      |
+|      Luanda     never visited      ?     ?     ?     ?     ?     ?     ?     ?     ?     ?      |
+|      Batavia    never visited      ?     ?     ?     ?     ?     ?     ?     ?     ?     ?      |
+|      Malacca    never visited      ?     ?     ?     ?     ?     ?     ?     ?     ?     ?      |
+|                                                                                                 |
+|          Prices elsewhere are as they were when last seen, and will have changed since.         |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                             (b) Back                                            |
+|                                                                                                 |
+|                                                                                                 |
+---------------------------------------------------------------------------------------------------
+"#
+            .trim()
+        );
+    }
+}
@@ -1,3 +1,4 @@
+#[allow(clippy::module_inception)]
 mod components;
 pub use components::*;
 mod frame;
@@ -10,5 +11,17 @@ mod screen_centered_text;
 pub use screen_centered_text::*;
 mod game_end_screen;
 pub use game_end_screen::*;
+mod high_scores_screen;
+pub use high_scores_screen::*;
+mod captains_log;
+pub use captains_log::*;
+mod contract_board;
+pub use contract_board::*;
+mod market_rumors;
+pub use market_rumors::*;
+mod price_chart;
+pub use price_chart::*;
+mod shipyard;
+pub use shipyard::*;
 mod require_resize;
 pub use require_resize::*;
@@ -0,0 +1,223 @@
+use std::fmt::{self};
+
+use chrono::Month;
+use crossterm::{
+    cursor::{Hide, MoveTo},
+    style::{style, Attribute, Print, Stylize},
+    terminal::Clear,
+    Command,
+};
+
+use crate::{
+    components::{Frame, FrameType, ScreenCenteredText, FRAME_WIDTH},
+    state::{months_between, Good, Location, PriceHistory},
+};
+
+/// a bar chart of the prices of a good seen in each port, one column per month
+/// and one row per port
+pub struct PriceChart<'a> {
+    pub history: &'a PriceHistory,
+    pub good: Good,
+    pub starting_date: (u16, Month),
+    pub date: (u16, Month),
+}
+
+const LABEL_X: u16 = 3;
+const BARS_X: u16 = 15;
+/// how many months fit in the chart, older months are left off
+const CHART_WIDTH: u16 = 60;
+const STATS_X: u16 = BARS_X + CHART_WIDTH + 3;
+const OFFSET_Y: u16 = 4;
+/// the row of bars for a port, and the blank row after it
+const PORT_HEIGHT: u16 = 2;
+/// the bars from the lowest price seen to the highest
+const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// the bar for a price, as tall as the price is high among those seen
+fn bar(price: u32, (low, high): (u32, u32)) -> char {
+    if high == low {
+        return BARS[BARS.len() / 2 - 1];
+    }
+    let steps = (BARS.len() - 1) as u32;
+    BARS[((price - low) * steps + (high - low) / 2).div_euclid(high - low) as usize]
+}
+
+/// the keys for each good and the way back, over as many lines as it takes to fit them all
+fn good_options() -> Vec<String> {
+    const MAX_WIDTH: usize = FRAME_WIDTH as usize - 6;
+    let options = Good::variants_iter()
+        .map(|good| match good.number_key() {
+            Some(number) => format!("({}) {}", number, good),
+            None => format!("({}) {}", good.key(), good),
+        })
+        .collect::<Vec<String>>();
+    let back = "   (b) Back";
+    let width = options.iter().map(|option| option.len() + 1).sum::<usize>() + back.len();
+    let lines = width.div_ceil(MAX_WIDTH);
+    let mut lines = options
+        .chunks(options.len().div_ceil(lines))
+        .map(|options| options.join(" "))
+        .collect::<Vec<String>>();
+    if let Some(last) = lines.last_mut() {
+        last.push_str(back);
+    }
+    lines
+}
+
+impl<'a> Command for PriceChart<'a> {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        let good = self.good;
+        let title = format!("Prices of {} seen in each port", good);
+        comp!(
+            f,
+            Clear(crossterm::terminal::ClearType::All),
+            Hide,
+            Frame(FrameType::SimpleEmptyInside),
+            ScreenCenteredText::new_styleds(&[style(title.as_str()).attribute(Attribute::Bold)], 2),
+        );
+        let options = good_options();
+        for (i, line) in options.iter().enumerate() {
+            comp!(
+                f,
+                ScreenCenteredText::new(
+                    std::slice::from_ref(line),
+                    30 - (options.len() - i) as u16
+                )
+            );
+        }
+        let months = months_between(self.starting_date, self.date).max(0) as u16 + 1;
+        let first_month = months.saturating_sub(CHART_WIDTH);
+        let range = self.history.range_of(&good);
+        for (i, location) in Location::variants().iter().enumerate() {
+            let y = OFFSET_Y + i as u16 * PORT_HEIGHT;
+            comp!(f, MoveTo(LABEL_X, y), Print(location.to_string()),);
+            let mut last_seen = None;
+            for (date, price) in self.history.prices_of(&good, location) {
+                last_seen = Some(price);
+                let month = months_between(self.starting_date, date);
+                let Some(range) = range.filter(|_| month >= first_month as i32) else {
+                    continue;
+                };
+                let column = BARS_X + month as u16 - first_month;
+                comp!(f, MoveTo(column, y), Print(bar(price, range)),);
+            }
+            if let Some(price) = last_seen {
+                comp!(f, MoveTo(STATS_X, y), Print(format!("last {}", price)),);
+            } else {
+                comp!(f, MoveTo(BARS_X, y), Print("not visited yet"));
+            }
+        }
+        // along the bottom, in the blank row after the last port
+        let axis_y = OFFSET_Y + Location::COUNT as u16 * PORT_HEIGHT - 1;
+        if let Some((low, high)) = range {
+            comp!(
+                f,
+                MoveTo(STATS_X, axis_y),
+                Print(format!("range {}-{}", low, high)),
+            );
+        }
+        // label the start of the chart and the start of each year
+        let mut free_from = BARS_X;
+        for month in first_month..months {
+            let months_into_year = self.starting_date.1.number_from_month() as u16 - 1 + month;
+            let year = self.starting_date.0 + months_into_year / 12;
+            let column = BARS_X + month - first_month;
+            if (month == first_month || months_into_year.is_multiple_of(12)) && column >= free_from
+            {
+                let label = format!("|{}", year);
+                free_from = column + label.len() as u16 + 1;
+                comp!(f, MoveTo(column, axis_y), Print(label));
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> std::io::Result<()> {
+        todo!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::{state::Inventory, test::render_component};
+
+    use super::*;
+
+    fn prices(tea: u32) -> Inventory {
+        Inventory::from([(Good::Tea, tea)])
+    }
+
+    #[test]
+    fn bars() {
+        assert_eq!(bar(10, (10, 40)), '▁');
+        assert_eq!(bar(25, (10, 40)), '▅');
+        assert_eq!(bar(40, (10, 40)), '█');
+        assert_eq!(bar(7, (7, 7)), '▄');
+    }
+
+    #[test]
+    fn chart() {
+        let mut history = PriceHistory::default();
+        let mut date = (1782, Month::October);
+        for (location, tea) in [
+            (Location::London, 10),
+            (Location::Venice, 40),
+            (Location::London, 15),
+            (Location::Lisbon, 30),
+            (Location::London, 25),
+        ] {
+            history.record(date, &location, &prices(tea));
+            date.1 = date.1.succ();
+            if date.1 == Month::January {
+                date.0 += 1;
+            }
+        }
+        assert_eq!(
+            render_component(PriceChart {
+                history: &history,
+                good: Good::Tea,
+                starting_date: (1782, Month::October),
+                date: (1783, Month::February),
+            }),
+            r#"
+---------------------------------------------------------------------------------------------------
+|                                                                                                 |
+|                                 Prices of Tea seen in each port                                 |
+|                                                                                                 |
+|  London      ▁ ▂ ▅                                                          last 25             |
+|                                                                                                 |
+|  Savannah    not visited yet                                                                    |
+|                                                                                                 |
+|  Lisbon         ▆                                                           last 30             |
+|                                                                                                 |
+|  Amsterdam   not visited yet                                                                    |
+|                                                                                                 |
+|  Cape Town   not visited yet                                                                    |
+|                                                                                                 |
+|  Venice       █                                                             last 40             |
+|                                                                                                 |
+|  Kingston    not visited yet                                                                    |
+|                                                                                                 |
+|  Havana      not visited yet                                                                    |
+|                                                                                                 |
+|  Elmina      not visited yet                                                                    |
+|                                                                                                 |
+|  Luanda      not visited yet                                                                    |
+|                                                                                                 |
+|  Batavia     not visited yet                                                                    |
+|                                                                                                 |
+|  Malacca     not visited yet                                                                    |
+|              |1782                                                          range 10-40         |
+|                         (1) Tea (2) Coffee (3) Sugar (4) Tobacco (5) Rum                        |
+|                (6) Cotton (7) Spices (8) Silk (9) Indigo (0) Porcelain   (b) Back               |
+|                                                                                                 |
+|                                                                                                 |
+---------------------------------------------------------------------------------------------------
+"#
+            .trim()
+        );
+    }
+}
@@ -7,10 +7,7 @@ use crossterm::{
     Command,
 };
 
-use crate::{
-    comp,
-    components::{FRAME_HEIGHT, FRAME_WIDTH},
-};
+use crate::components::{FRAME_HEIGHT, FRAME_WIDTH};
 
 pub struct RequireResize {
     pub current_x_cols: u16,
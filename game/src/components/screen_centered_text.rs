@@ -6,7 +6,7 @@ use crossterm::{
     Command,
 };
 
-use crate::{comp, components::FRAME_WIDTH};
+use crate::components::FRAME_WIDTH;
 
 pub struct ScreenCenteredText<'a, T: Display> {
     content: &'a [T],
@@ -66,28 +66,28 @@ mod tests {
     fn basic() {
         let s = "012345678901234567890123456789012345678901234567890123456789012345678901234567890123456789012345678".to_owned();
         assert_eq!(s.len(), FRAME_WIDTH as usize);
-        assert_eq!(render_component(ScreenCenteredText::new(&[s.clone()], 0)), "012345678901234567890123456789012345678901234567890123456789012345678901234567890123456789012345678");
+        assert_eq!(render_component(ScreenCenteredText::new(std::slice::from_ref(&s), 0)), "012345678901234567890123456789012345678901234567890123456789012345678901234567890123456789012345678");
         let s = s[..s.len().saturating_sub(1)].to_owned();
-        assert_eq!(render_component(ScreenCenteredText::new(&[s.clone()], 0)), " 01234567890123456789012345678901234567890123456789012345678901234567890123456789012345678901234567");
+        assert_eq!(render_component(ScreenCenteredText::new(std::slice::from_ref(&s), 0)), " 01234567890123456789012345678901234567890123456789012345678901234567890123456789012345678901234567");
         let s = s[..s.len().saturating_sub(1)].to_owned();
-        assert_eq!(render_component(ScreenCenteredText::new(&[s.clone()], 0)), " 0123456789012345678901234567890123456789012345678901234567890123456789012345678901234567890123456");
+        assert_eq!(render_component(ScreenCenteredText::new(std::slice::from_ref(&s), 0)), " 0123456789012345678901234567890123456789012345678901234567890123456789012345678901234567890123456");
         let s = s[..s.len().saturating_sub(1)].to_owned();
-        assert_eq!(render_component(ScreenCenteredText::new(&[s.clone()], 0)), "  012345678901234567890123456789012345678901234567890123456789012345678901234567890123456789012345");
+        assert_eq!(render_component(ScreenCenteredText::new(std::slice::from_ref(&s), 0)), "  012345678901234567890123456789012345678901234567890123456789012345678901234567890123456789012345");
         let s = s[..s.len().saturating_sub(1)].to_owned();
-        assert_eq!(render_component(ScreenCenteredText::new(&[s.clone()], 0)), "  01234567890123456789012345678901234567890123456789012345678901234567890123456789012345678901234");
+        assert_eq!(render_component(ScreenCenteredText::new(std::slice::from_ref(&s), 0)), "  01234567890123456789012345678901234567890123456789012345678901234567890123456789012345678901234");
         let s = s[..s.len().saturating_sub(1)].to_owned();
-        assert_eq!(render_component(ScreenCenteredText::new(&[s.clone()], 0)), "   0123456789012345678901234567890123456789012345678901234567890123456789012345678901234567890123");
+        assert_eq!(render_component(ScreenCenteredText::new(std::slice::from_ref(&s), 0)), "   0123456789012345678901234567890123456789012345678901234567890123456789012345678901234567890123");
         let s = s[..s.len().saturating_sub(1)].to_owned();
-        assert_eq!(render_component(ScreenCenteredText::new(&[s.clone()], 0)), "   012345678901234567890123456789012345678901234567890123456789012345678901234567890123456789012");
+        assert_eq!(render_component(ScreenCenteredText::new(std::slice::from_ref(&s), 0)), "   012345678901234567890123456789012345678901234567890123456789012345678901234567890123456789012");
         let s = s[..s.len().saturating_sub(1)].to_owned();
-        assert_eq!(render_component(ScreenCenteredText::new(&[s.clone()], 0)), "    01234567890123456789012345678901234567890123456789012345678901234567890123456789012345678901");
+        assert_eq!(render_component(ScreenCenteredText::new(std::slice::from_ref(&s), 0)), "    01234567890123456789012345678901234567890123456789012345678901234567890123456789012345678901");
         let s = s[..s.len().saturating_sub(1)].to_owned();
-        assert_eq!(render_component(ScreenCenteredText::new(&[s.clone()], 0)), "    0123456789012345678901234567890123456789012345678901234567890123456789012345678901234567890");
+        assert_eq!(render_component(ScreenCenteredText::new(std::slice::from_ref(&s), 0)), "    0123456789012345678901234567890123456789012345678901234567890123456789012345678901234567890");
         let s = s[..s.len().saturating_sub(1)].to_owned();
-        assert_eq!(render_component(ScreenCenteredText::new(&[s.clone()], 0)), "     012345678901234567890123456789012345678901234567890123456789012345678901234567890123456789");
+        assert_eq!(render_component(ScreenCenteredText::new(std::slice::from_ref(&s), 0)), "     012345678901234567890123456789012345678901234567890123456789012345678901234567890123456789");
         let s = s[..s.len().saturating_sub(1)].to_owned();
-        assert_eq!(render_component(ScreenCenteredText::new(&[s.clone()], 0)), "     01234567890123456789012345678901234567890123456789012345678901234567890123456789012345678");
+        assert_eq!(render_component(ScreenCenteredText::new(std::slice::from_ref(&s), 0)), "     01234567890123456789012345678901234567890123456789012345678901234567890123456789012345678");
         let s = s[..s.len().saturating_sub(1)].to_owned();
-        assert_eq!(render_component(ScreenCenteredText::new(&[s.clone()], 0)), "      0123456789012345678901234567890123456789012345678901234567890123456789012345678901234567");
+        assert_eq!(render_component(ScreenCenteredText::new(std::slice::from_ref(&s), 0)), "      0123456789012345678901234567890123456789012345678901234567890123456789012345678901234567");
     }
 }
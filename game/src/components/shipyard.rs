@@ -0,0 +1,179 @@
+use std::fmt::{self};
+
+use crossterm::{
+    cursor::{Hide, MoveTo},
+    style::{style, Attribute, Print, Stylize},
+    terminal::Clear,
+    Command,
+};
+
+use crate::{
+    components::{Frame, FrameType, ScreenCenteredText},
+    state::{Location, ShipClass},
+};
+
+/// the ships for sale in a port's shipyard, and what each would cost after trading in the player's
+pub struct Shipyard {
+    pub location: Location,
+    /// the class of the player's ship
+    pub current: ShipClass,
+    /// what the shipyard gives for the player's ship
+    pub trade_in: u32,
+}
+
+const OFFSET_X: u16 = 12;
+const OFFSET_Y: u16 = 5;
+
+fn row(
+    ship: &str,
+    hold: &str,
+    cannons: &str,
+    crew: &str,
+    hull: &str,
+    speed: &str,
+    upkeep: &str,
+) -> String {
+    format!(
+        "{:<13}{:>6}{:>9}{:>9}{:>6}{:>7}{:>8}",
+        ship, hold, cannons, crew, hull, speed, upkeep
+    )
+}
+
+impl Command for Shipyard {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        let title = format!("The Shipyard of {}", self.location);
+        comp!(
+            f,
+            Clear(crossterm::terminal::ClearType::All),
+            Hide,
+            Frame(FrameType::SimpleEmptyInside),
+            ScreenCenteredText::new_styleds(&[style(title.as_str()).attribute(Attribute::Bold)], 2),
+            MoveTo(OFFSET_X, OFFSET_Y),
+            Print(format!(
+                "{}{:>15}",
+                row("", "Hold", "Cannons", "Crew", "Hull", "Speed", "Upkeep"),
+                "Price"
+            )),
+            MoveTo(OFFSET_X, OFFSET_Y + 1),
+            Print(format!(
+                "{}{:>15}",
+                row("", "", "at most", "at most", "", "", "a month"),
+                "after trade-in"
+            )),
+        );
+        for (i, class) in ShipClass::variants_iter().enumerate() {
+            let info = class.info();
+            let price = if class == self.current {
+                "yours".to_owned()
+            } else if info.price >= self.trade_in {
+                (info.price - self.trade_in).to_string()
+            } else {
+                format!("they pay {}", self.trade_in - info.price)
+            };
+            let line = format!(
+                "{}{:>15}",
+                row(
+                    &format!("({}) {}", info.key, info.name),
+                    &info.hold.to_string(),
+                    &info.max_cannons.to_string(),
+                    &info.berths.to_string(),
+                    &info.hull.to_string(),
+                    &format!("{:.0}%", info.speed * 100.0),
+                    &info.upkeep.to_string(),
+                ),
+                price
+            );
+            comp!(f, MoveTo(OFFSET_X, OFFSET_Y + 3 + i as u16));
+            if class == self.current {
+                comp!(f, Print(style(line).attribute(Attribute::Dim)));
+            } else {
+                comp!(f, Print(line));
+            }
+        }
+        comp!(
+            f,
+            ScreenCenteredText::new(
+                &[format!(
+                    "They'll give {} gold for your {}, allowing for any damage to its hull.",
+                    self.trade_in, self.current
+                )],
+                OFFSET_Y + 8
+            ),
+            ScreenCenteredText::new(
+                &["Your goods and as many cannons as the new ship carries move over,".to_owned()],
+                OFFSET_Y + 10
+            ),
+            ScreenCenteredText::new(
+                &["but hold space bought along the way stays with the old ship.".to_owned()],
+                OFFSET_Y + 11
+            ),
+            ScreenCenteredText::new(
+                &["Speed is how much sea the ship covers in a month, next to a sloop.".to_owned()],
+                OFFSET_Y + 13
+            ),
+            ScreenCenteredText::new(&["(b) Back".to_owned()], 29),
+        );
+        Ok(())
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> std::io::Result<()> {
+        todo!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::test::render_component;
+
+    use super::*;
+
+    #[test]
+    fn shipyard() {
+        assert_eq!(
+            render_component(Shipyard {
+                location: Location::Amsterdam,
+                current: ShipClass::Brig,
+                trade_in: 4000,
+            }),
+            r#"
+---------------------------------------------------------------------------------------------------
+|                                                                                                 |
+|                                    The Shipyard of Amsterdam                                    |
+|                                                                                                 |
+|                                                                                                 |
+|                          Hold  Cannons     Crew  Hull  Speed  Upkeep          Price             |
+|                                at most  at most              a month after trade-in             |
+|                                                                                                 |
+|           (s) Sloop       100        5       12     5   100%       0  they pay 3000             |
+|           (r) Brig        250        8       20     8    80%      40          yours             |
+|           (f) Frigate     200       16       40    12   125%     120          20000             |
+|                                                                                                 |
+|                                                                                                 |
+|            They'll give 4000 gold for your Brig, allowing for any damage to its hull.           |
+|                                                                                                 |
+|                Your goods and as many cannons as the new ship carries move over,                |
+|                   but hold space bought along the way stays with the old ship.                  |
+|                                                                                                 |
+|                Speed is how much sea the ship covers in a month, next to a sloop.               |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                             (b) Back                                            |
+|                                                                                                 |
+|                                                                                                 |
+---------------------------------------------------------------------------------------------------
+"#
+            .trim()
+        );
+    }
+}
@@ -7,12 +7,11 @@ use crossterm::{
     Command,
 };
 
-use crate::{
-    comp,
-    components::{Frame, FrameType, ScreenCenteredText},
-};
+use crate::components::{Frame, FrameType, ScreenCenteredText};
 
-pub struct SplashScreen();
+pub struct SplashScreen {
+    pub saved_game_available: bool,
+}
 
 const LOGO: &str = r#"
  __  __               _                 _   
@@ -31,13 +30,38 @@ impl Command for SplashScreen {
             Frame(FrameType::SimpleEmptyInside),
             ScreenCenteredText::new(&["A tribute to Drug Wars by samgqroberts".to_owned()], 12),
             ScreenCenteredText::new(&["www.samgqroberts.com".to_owned()], 14),
-            ScreenCenteredText::new_styleds(
-                &[style("Press any key to begin").attribute(Attribute::Bold)],
-                25
-            ),
             ScreenCenteredText::new(&["ctrl-c to quit at any time".to_owned()], 29),
+            ScreenCenteredText::new(&["ctrl-s to save and quit during a game".to_owned()], 30),
             Hide
         );
+        comp!(
+            f,
+            ScreenCenteredText::new_styleds(
+                &[style("(s) High scores").attribute(Attribute::Bold)],
+                23
+            ),
+        );
+        if self.saved_game_available {
+            comp!(
+                f,
+                ScreenCenteredText::new_styleds(
+                    &[style("(c) Continue saved game").attribute(Attribute::Bold)],
+                    21
+                ),
+                ScreenCenteredText::new_styleds(
+                    &[style("Press any other key to begin a new game").attribute(Attribute::Bold)],
+                    25
+                ),
+            );
+        } else {
+            comp!(
+                f,
+                ScreenCenteredText::new_styleds(
+                    &[style("Press any other key to begin").attribute(Attribute::Bold)],
+                    25
+                ),
+            );
+        }
         const OFFSET_X: u16 = 28;
         const OFFSET_Y: u16 = 4;
         for (i, line) in LOGO.trim_matches('\n').lines().enumerate() {
@@ -66,9 +90,16 @@ mod tests {
 
     #[test]
     fn basic() {
-        println!("{}", render_component(SplashScreen()));
+        println!(
+            "{}",
+            render_component(SplashScreen {
+                saved_game_available: false
+            })
+        );
         assert_eq!(
-            render_component(SplashScreen()),
+            render_component(SplashScreen {
+                saved_game_available: false
+            }),
             r#"
 ---------------------------------------------------------------------------------------------------
 |                                                                                                 |
@@ -93,14 +124,14 @@ mod tests {
 |                                                                                                 |
 |                                                                                                 |
 |                                                                                                 |
+|                                         (s) High scores                                         |
 |                                                                                                 |
-|                                                                                                 |
-|                                      Press any key to begin                                     |
+|                                   Press any other key to begin                                  |
 |                                                                                                 |
 |                                                                                                 |
 |                                                                                                 |
 |                                    ctrl-c to quit at any time                                   |
-|                                                                                                 |
+|                              ctrl-s to save and quit during a game                              |
 |                                                                                                 |
 ---------------------------------------------------------------------------------------------------
 "#
@@ -6,7 +6,6 @@ use crossterm::{
 };
 use std::{
     cell::RefCell,
-    cmp::min,
     io::{self, Write},
     time::Duration,
 };
@@ -14,16 +13,21 @@ use tracing::{debug, error, info};
 
 use crate::{
     components::{
-        BankDepositInput, BankWithdrawInput, BuyInput, BuyPrompt, CanBuyCannon, CanBuyHoldSpace,
-        CheapGoodDialog, ExpensiveGoodDialog, FindGoodsDialog, GameEndScreen, GoodsStolenDialog,
-        IntroductionScreen, NoEffect, PayDebtInput, PirateEncounter, RequireResize, SailPrompt,
-        SellInput, SellPrompt, SplashScreen, StashDepositInput, StashDepositPrompt,
-        StashWithdrawInput, StashWithdrawPrompt, ViewingInventoryActions, ViewingInventoryBase,
+        BankDepositInput, BankRunDialog, BankWithdrawInput, BorrowInput, BuyInput, BuyPrompt,
+        CanBuyCannon, CanBuyHoldSpace, CaptainsLog, CheapGoodDialog, ContractBoard,
+        ContractsSettledDialog, CustomsInspectionDialog, DebtCollectorsDialog, DesertionDialog,
+        ExpensiveGoodDialog, FindGoodsDialog, GameEndScreen, GoodsStolenDialog, HighScoresScreen,
+        HireCrewInput, IntroductionScreen, MarketRumors, MutinyDialog, NoEffect, PayDebtInput,
+        PirateEncounter, PriceChart, RequireResize, SailPrompt, SellInput, SellPrompt, Shipyard,
+        SplashScreen, StashDepositInput, StashDepositPrompt, StashWithdrawInput,
+        StashWithdrawPrompt, ViewingInventoryActions, ViewingInventoryBase, WarehouseLossesDialog,
         FRAME_HEIGHT, FRAME_WIDTH,
     },
+    high_scores::HighScores,
+    replay::ReplayRecorder,
     state::{
-        GameState, Good, Initialization, Location, LocationEvent, Mode, PirateEncounterState,
-        StateError,
+        Difficulty, GameState, Good, Initialization, Location, LocationEvent, Mode,
+        PirateEncounterState, ShipClass, StateError,
     },
 };
 
@@ -48,6 +52,11 @@ pub enum UpdateSignal {
     Continue,
     Quit,
     Restart,
+    SaveAndQuit,
+    LoadSavedGame,
+    ShowHighScores,
+    /// write the Captain's Log to a CSV file
+    ExportLedger,
 }
 
 pub type UpdateResult<T> = Result<T, UpdateError>;
@@ -64,15 +73,7 @@ where
 impl FromKeyCode for Good {
     fn from_key_code(key_code: &KeyCode) -> Option<Self> {
         if let KeyCode::Char(c) = key_code {
-            match c {
-                '1' | 't' => Some(Good::Tea),
-                '2' | 'c' => Some(Good::Coffee),
-                '3' | 's' => Some(Good::Sugar),
-                '4' | 'a' => Some(Good::Tobacco),
-                '5' | 'r' => Some(Good::Rum),
-                '6' | 'o' => Some(Good::Cotton),
-                _ => None,
-            }
+            Good::from_key(*c)
         } else {
             None
         }
@@ -82,28 +83,53 @@ impl FromKeyCode for Good {
 impl FromKeyCode for Location {
     fn from_key_code(key_code: &KeyCode) -> Option<Self> {
         if let KeyCode::Char(c) = key_code {
-            match c {
-                '1' | 'l' => Some(Location::London),
-                '2' | 's' => Some(Location::Savannah),
-                '3' | 'i' => Some(Location::Lisbon),
-                '4' | 'a' => Some(Location::Amsterdam),
-                '5' | 'c' => Some(Location::CapeTown),
-                '6' | 'v' => Some(Location::Venice),
-                _ => None,
-            }
+            Location::from_key(*c)
         } else {
             None
         }
     }
 }
 
+/// handles the key combinations that apply no matter which scene is drawn
+pub fn global_signal(event: &KeyEvent, state: &GameState) -> Option<UpdateSignal> {
+    if event.modifiers != KeyModifiers::CONTROL {
+        return None;
+    }
+    match event.code {
+        // detect exit request
+        KeyCode::Char('c') => Some(UpdateSignal::Quit),
+        KeyCode::Char('s') if state.can_save() => Some(UpdateSignal::SaveAndQuit),
+        _ => None,
+    }
+}
+
 pub struct Engine<'a, Writer: Write> {
     pub writer: &'a RefCell<Writer>,
+    /// whether the splash screen should offer to continue a saved game
+    pub saved_game_available: bool,
+    /// when set, every key press handled by a scene is recorded for later playback
+    pub recorder: Option<ReplayRecorder>,
+    /// shown from the splash and game over screens
+    pub high_scores: HighScores,
+    /// the position in the high score table of the game just finished, if it made it in
+    pub new_high_score: Option<usize>,
+    /// whether the high score table is drawn in place of the current scene
+    pub showing_high_scores: bool,
+    /// how exporting the Captain's Log went, shown until the log is closed
+    pub ledger_export: Option<String>,
 }
 
 impl<'a, Writer: Write> Engine<'a, Writer> {
     pub fn new(writer: &'a RefCell<Writer>) -> Self {
-        Self { writer }
+        Self {
+            writer,
+            saved_game_available: false,
+            recorder: None,
+            high_scores: HighScores::default(),
+            new_high_score: None,
+            showing_high_scores: false,
+            ledger_export: None,
+        }
     }
 
     pub fn draw_and_prompt(
@@ -124,7 +150,11 @@ impl<'a, Writer: Write> Engine<'a, Writer> {
         }
         // if terminal does not need to be resized draw the game state
         let mut update_fn: Option<_> = None;
-        if !require_resize {
+        if self.showing_high_scores {
+            if !require_resize {
+                self.draw_high_scores()?;
+            }
+        } else if !require_resize {
             update_fn = Some(self.draw_scene(game_state)?);
         }
         // Wait for any user event
@@ -140,15 +170,21 @@ impl<'a, Writer: Write> Engine<'a, Writer> {
                         // if we don't filter to just Press events we will double-update
                         if event.kind == KeyEventKind::Press {
                             info!("User Key Press: {:?} {:?}", event.code, event.modifiers);
-                            // detect exit request
-                            if event.modifiers == KeyModifiers::CONTROL
-                                && event.code == KeyCode::Char('c')
-                            {
-                                return Ok(UpdateSignal::Quit);
+                            // detect exit and save requests
+                            if let Some(signal) = global_signal(&event, game_state) {
+                                return Ok(signal);
+                            }
+                            // any key closes the high score table, without reaching the game
+                            if self.showing_high_scores {
+                                self.showing_high_scores = false;
+                                return Ok(UpdateSignal::Continue);
                             }
                             // update game state (if we have an update_fn, we may not if
                             // terminal needs to be resized)
                             if let Some(update_fn) = update_fn {
+                                if let Some(recorder) = &mut self.recorder {
+                                    recorder.record_key(&event)?;
+                                }
                                 return update_fn(event, game_state);
                             } else {
                                 return Ok(UpdateSignal::Continue);
@@ -168,20 +204,44 @@ impl<'a, Writer: Write> Engine<'a, Writer> {
         }
     }
 
-    fn queue_scene(writer: &mut Writer, state: &mut GameState) -> io::Result<Box<UpdateFn>> {
+    fn queue_scene(
+        writer: &mut Writer,
+        state: &mut GameState,
+        saved_game_available: bool,
+        high_score_rank: Option<usize>,
+        ledger_export: Option<&str>,
+    ) -> io::Result<Box<UpdateFn>> {
         if state.initialization == Initialization::SplashScreen {
             // initial splash screen
-            queue!(writer, SplashScreen())?;
-            Ok(Box::new(|_: KeyEvent, state: &mut GameState| {
+            queue!(
+                writer,
+                SplashScreen {
+                    saved_game_available
+                }
+            )?;
+            Ok(Box::new(move |event: KeyEvent, state: &mut GameState| {
+                if saved_game_available && event.code == KeyCode::Char('c') {
+                    return Ok(UpdateSignal::LoadSavedGame);
+                }
+                if event.code == KeyCode::Char('s') && event.modifiers != KeyModifiers::CONTROL {
+                    return Ok(UpdateSignal::ShowHighScores);
+                }
                 state.splash_to_introduction();
                 Ok(UpdateSignal::Continue)
             }))
         } else if state.game_end {
-            queue!(writer, GameEndScreen(state))?;
+            queue!(
+                writer,
+                GameEndScreen {
+                    state,
+                    high_score_rank
+                }
+            )?;
             Ok(Box::new(|event: KeyEvent, _: &mut GameState| {
                 match event.code {
                     KeyCode::Char('q') => Ok(UpdateSignal::Quit),
                     KeyCode::Enter => Ok(UpdateSignal::Restart),
+                    KeyCode::Char('s') => Ok(UpdateSignal::ShowHighScores),
                     _ => Ok(UpdateSignal::Continue),
                 }
             }))
@@ -193,16 +253,29 @@ impl<'a, Writer: Write> Engine<'a, Writer> {
                 writer,
                 PirateEncounter::from((pirate_encounter_state, state))
             )?;
-            return Ok(Box::new(move |event: KeyEvent, state: &mut GameState| {
+            Ok(Box::new(move |event: KeyEvent, state: &mut GameState| {
                 match &pirate_encounter_state {
                     PirateEncounterState::Initial => {
                         state.proceed_pirate_encounter()?;
                     }
                     PirateEncounterState::Prompt { info: _ } => {
-                        if let KeyCode::Char('r') = event.code {
-                            state.pirate_run()?;
-                        } else if let KeyCode::Char('f') = event.code {
-                            state.pirate_fight()?;
+                        let result = match event.code {
+                            KeyCode::Char('r') => state.pirate_run(),
+                            KeyCode::Char('f') => state.pirate_fight(),
+                            KeyCode::Char('b') => state.pirate_broadside(),
+                            KeyCode::Char('o') => state.pirate_board(),
+                            KeyCode::Char('g') => state.pirate_bribe_gold(),
+                            KeyCode::Char('c') => state.pirate_bribe_cargo(),
+                            KeyCode::Char('s') => state.pirate_surrender(),
+                            KeyCode::Char('d') => state.pirate_dump_cargo(),
+                            _ => Ok(()),
+                        };
+                        // offering gold or cargo that isn't there does nothing
+                        match result {
+                            Ok(_)
+                            | Err(StateError::CannotAfford)
+                            | Err(StateError::InsufficientInventory) => {}
+                            Err(e) => return Err(e.into()),
                         }
                     }
                     PirateEncounterState::RunSuccess => {
@@ -226,27 +299,61 @@ impl<'a, Writer: Write> Engine<'a, Writer> {
                     } => {
                         state.proceed_attack_result()?;
                     }
-                    PirateEncounterState::Victory { gold_recovered: _ } => {
+                    PirateEncounterState::Victory { .. } => {
                         state.proceed_pirate_encounter_victory()?;
                     }
+                    PirateEncounterState::BroadsideResult { .. } => {
+                        state.proceed_broadside_result()?;
+                    }
+                    PirateEncounterState::BoardResult { .. } => {
+                        state.proceed_board_result()?;
+                    }
+                    PirateEncounterState::BribeResult { .. } => {
+                        state.proceed_bribe_result()?;
+                    }
+                    PirateEncounterState::Surrendered { .. } => {
+                        state.proceed_surrendered()?;
+                    }
+                    PirateEncounterState::DumpResult { .. } => {
+                        state.proceed_dump_result()?;
+                    }
                 }
                 Ok(UpdateSignal::Continue)
-            }));
+            }))
         } else if state.initialization == Initialization::Introduction {
             // introduction screen
             queue!(
                 writer,
                 IntroductionScreen {
                     home: state.location_config.home_port,
-                    starting_year: state.starting_date.0
+                    starting_year: state.starting_date.0,
+                    game_length_years: state.rules.game_length_years
                 }
             )?;
-            Ok(Box::new(|_: KeyEvent, state: &mut GameState| {
+            Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
+                let difficulty = match event.code {
+                    KeyCode::Char('e') => Difficulty::Easy,
+                    KeyCode::Char('n') => Difficulty::Normal,
+                    KeyCode::Char('h') => Difficulty::Hard,
+                    _ => return Ok(UpdateSignal::Continue),
+                };
+                state.choose_difficulty(difficulty);
                 state.introduction_to_game();
                 Ok(UpdateSignal::Continue)
             }))
         } else {
-            queue!(writer, ViewingInventoryBase(state))?;
+            // the captain's log, price history, market rumors and contract board
+            // take up the whole screen
+            if !matches!(
+                state.mode,
+                Mode::CaptainsLog(_)
+                    | Mode::PriceHistory(_)
+                    | Mode::MarketRumors
+                    | Mode::Contracts
+                    | Mode::Shipyard
+            ) {
+                queue!(writer, ViewingInventoryBase(state))?;
+            }
             match &state.mode {
                 Mode::ViewingInventory => {
                     queue!(
@@ -254,10 +361,18 @@ impl<'a, Writer: Write> Engine<'a, Writer> {
                         ViewingInventoryActions {
                             location: &state.location,
                             home_port: &state.location_config.home_port,
-                            debt: state.debt.0
+                            debt: state.debt(),
+                            warehouse: state
+                                .warehouses
+                                .get(&state.location)
+                                .as_ref()
+                                .map(|warehouse| &warehouse.goods),
+                            warehouse_rent: state.rules.warehouse_rent,
+                            repair_cost: Some(state.repair_cost()).filter(|cost| *cost > 0),
+                            shipyard: state.location.info().shipyard,
                         }
                     )?;
-                    return Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
+                    Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
                         if let KeyCode::Char(ch) = event.code {
                             if ch == '1' || ch == 'b' {
                                 state.begin_buying()?;
@@ -265,29 +380,67 @@ impl<'a, Writer: Write> Engine<'a, Writer> {
                                 state.begin_selling()?;
                             } else if ch == '3' || ch == 'a' {
                                 state.begin_sailing()?;
+                            } else if ch == '9' || ch == 'l' {
+                                state.open_captains_log()?;
+                            } else if ch == '0' || ch == 'h' {
+                                state.open_price_history()?;
+                            } else if ch == 'r' {
+                                state.open_market_rumors()?;
+                            } else if ch == 'o' {
+                                state.begin_borrow()?;
+                            } else if ch == 'c' {
+                                state.open_contracts()?;
+                            } else if ch == 'y' && state.location.info().shipyard {
+                                state.open_shipyard()?;
+                            } else if ch == 'n' {
+                                state.begin_hiring()?;
+                            } else if ch == 'm' {
+                                // mending the hull when it can't be done does nothing
+                                match state.repair_hull() {
+                                    Ok(_)
+                                    | Err(StateError::CannotAfford)
+                                    | Err(StateError::HullNotDamaged) => {}
+                                    Err(e) => return Err(e.into()),
+                                }
+                            } else if ch == 'u' {
+                                // renting or giving up a warehouse when it can't be done does nothing
+                                let result = if state.warehouses.get(&state.location).is_some() {
+                                    state.give_up_warehouse()
+                                } else {
+                                    state.rent_warehouse()
+                                };
+                                match result {
+                                    Ok(_)
+                                    | Err(StateError::CannotAfford)
+                                    | Err(StateError::AlreadyHaveWarehouse)
+                                    | Err(StateError::WarehouseNotEmpty) => {}
+                                    Err(e) => return Err(e.into()),
+                                }
+                            } else if state.debt() > 0 && (ch == '8' || ch == 'p') {
+                                state.begin_pay_debt()?;
                             };
-                            if state.location == state.location_config.home_port {
+                            if state.stash_here().is_some() {
                                 if ch == '4' || ch == 'd' {
                                     state.begin_stash_deposit()?;
                                 } else if ch == '5' || ch == 'w' {
                                     state.begin_stash_withdraw()?;
-                                } else if ch == '6' || ch == 'e' {
+                                }
+                            }
+                            if state.location == state.location_config.home_port {
+                                if ch == '6' || ch == 'e' {
                                     state.begin_bank_deposit()?;
                                 } else if ch == '7' || ch == 'i' {
                                     state.begin_bank_withdraw()?;
                                 }
-                                if state.debt.0 > 0 && (ch == '8' || ch == 'p') {
-                                    state.begin_pay_debt()?;
-                                }
                             }
                         }
                         Ok(UpdateSignal::Continue)
-                    }));
+                    }))
                 }
                 Mode::Buying(info) => {
                     if let Some(info) = info {
                         queue!(writer, BuyInput { info, state })?;
-                        return Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
+                        Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
                             if let KeyCode::Char(c) = event.code {
                                 if c == 'b' {
                                     state.back()?;
@@ -307,10 +460,10 @@ impl<'a, Writer: Write> Engine<'a, Writer> {
                                 );
                             }
                             Ok(UpdateSignal::Continue)
-                        }));
+                        }))
                     } else {
                         queue!(writer, BuyPrompt)?;
-                        return Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
+                        Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
                             if let KeyCode::Char('b') = event.code {
                                 state.back()?;
                             } else if let Some(good) = Good::from_key_code(&event.code) {
@@ -319,15 +472,14 @@ impl<'a, Writer: Write> Engine<'a, Writer> {
                                 state.cancel_buy()?;
                             }
                             Ok(UpdateSignal::Continue)
-                        }));
+                        }))
                     }
                 }
                 Mode::Selling(info) => {
                     if let Some(info) = info {
                         // user has indicated which good they want to sell
-                        let current_amount = state.inventory.get_good(&info.good);
-                        queue!(writer, SellInput(info, current_amount))?;
-                        return Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
+                        queue!(writer, SellInput { info, state })?;
+                        Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
                             if let KeyCode::Char(c) = event.code {
                                 if c == 'b' {
                                     state.back()?;
@@ -348,11 +500,11 @@ impl<'a, Writer: Write> Engine<'a, Writer> {
                                     });
                             }
                             Ok(UpdateSignal::Continue)
-                        }));
+                        }))
                     } else {
                         // user is choosing which good to sell
                         queue!(writer, SellPrompt)?;
-                        return Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
+                        Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
                             if let KeyCode::Char('b') = event.code {
                                 state.back()?;
                             } else if let Some(good) = Good::from_key_code(&event.code) {
@@ -361,13 +513,13 @@ impl<'a, Writer: Write> Engine<'a, Writer> {
                                 state.cancel_sell()?;
                             }
                             Ok(UpdateSignal::Continue)
-                        }));
+                        }))
                     }
                 }
                 Mode::Sailing => {
                     // user is choosing where to sail
-                    queue!(writer, SailPrompt)?;
-                    return Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
+                    queue!(writer, SailPrompt(state))?;
+                    Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
                         if let KeyCode::Char('b') = event.code {
                             state.back()?;
                         } else if let Some(destination) = Location::from_key_code(&event.code) {
@@ -382,7 +534,7 @@ impl<'a, Writer: Write> Engine<'a, Writer> {
                             state.cancel_sail_to()?;
                         }
                         Ok(UpdateSignal::Continue)
-                    }));
+                    }))
                 }
                 Mode::StashDeposit(info) => {
                     if let Some(info) = info {
@@ -390,7 +542,7 @@ impl<'a, Writer: Write> Engine<'a, Writer> {
                         let good = &info.good;
                         let current_amount = state.inventory.get_good(good);
                         queue!(writer, StashDepositInput(info, current_amount))?;
-                        return Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
+                        Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
                             if let KeyCode::Char(c) = event.code {
                                 if c == 'b' {
                                     state.back()?;
@@ -413,11 +565,11 @@ impl<'a, Writer: Write> Engine<'a, Writer> {
                                     });
                             }
                             Ok(UpdateSignal::Continue)
-                        }));
+                        }))
                     } else {
                         // user is choosing which good to stash
                         queue!(writer, StashDepositPrompt)?;
-                        return Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
+                        Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
                             if let KeyCode::Char('b') = event.code {
                                 state.back()?;
                             } else if let Some(good) = Good::from_key_code(&event.code) {
@@ -426,16 +578,17 @@ impl<'a, Writer: Write> Engine<'a, Writer> {
                                 state.cancel_stash_deposit()?;
                             }
                             Ok(UpdateSignal::Continue)
-                        }));
+                        }))
                     }
                 }
                 Mode::StashWithdraw(info) => {
                     if let Some(info) = info {
                         // user has indicated which good they want to withdraw from stash
                         let good = &info.good;
-                        let current_amount = state.stash.get_good(good);
+                        let current_amount =
+                            state.stash_here().map_or(&0, |stash| stash.get_good(good));
                         queue!(writer, StashWithdrawInput(info, current_amount))?;
-                        return Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
+                        Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
                             if let KeyCode::Char(c) = event.code {
                                 if c == 'b' {
                                     state.back()?;
@@ -454,11 +607,11 @@ impl<'a, Writer: Write> Engine<'a, Writer> {
                                     });
                             }
                             Ok(UpdateSignal::Continue)
-                        }));
+                        }))
                     } else {
                         // user is choosing which good to withdraw from stash
                         queue!(writer, StashWithdrawPrompt)?;
-                        return Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
+                        Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
                             if let KeyCode::Char('b') = event.code {
                                 state.back()?;
                             } else if let Some(good) = Good::from_key_code(&event.code) {
@@ -467,12 +620,12 @@ impl<'a, Writer: Write> Engine<'a, Writer> {
                                 state.cancel_stash_withdraw()?;
                             }
                             Ok(UpdateSignal::Continue)
-                        }));
+                        }))
                     }
                 }
                 Mode::PayDebt(amount) => {
-                    queue!(writer, PayDebtInput(amount))?;
-                    return Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
+                    queue!(writer, PayDebtInput(amount, &state.loans))?;
+                    Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
                         if let KeyCode::Char(c) = event.code {
                             if c == 'b' {
                                 state.back()?;
@@ -494,11 +647,86 @@ impl<'a, Writer: Write> Engine<'a, Writer> {
                                 });
                         }
                         Ok(UpdateSignal::Continue)
-                    }));
+                    }))
+                }
+                Mode::Borrow(amount) => {
+                    let lender = state.lender(&state.location);
+                    let loan = state.loans.get(&state.location);
+                    queue!(
+                        writer,
+                        BorrowInput {
+                            amount,
+                            available: loan.available(&lender),
+                            lender,
+                            due: loan.due.filter(|_| loan.balance > 0),
+                        }
+                    )?;
+                    Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
+                        if let KeyCode::Char(c) = event.code {
+                            if c == 'b' {
+                                state.back()?;
+                            } else if let Some(digit) = c.to_digit(10) {
+                                state.user_typed_digit(digit)?;
+                            }
+                        } else if event.code == KeyCode::Backspace {
+                            state.user_typed_backspace()?;
+                        } else if event.code == KeyCode::Enter {
+                            return state
+                                .commit_borrow()
+                                .map(|_| UpdateSignal::Continue)
+                                .or_else(|e| match e {
+                                    StateError::BorrowAmountHigherThanLimit => {
+                                        Ok(UpdateSignal::Continue)
+                                    }
+                                    x => Err(x.into()),
+                                });
+                        }
+                        Ok(UpdateSignal::Continue)
+                    }))
+                }
+                Mode::HireCrew(amount) => {
+                    queue!(
+                        writer,
+                        HireCrewInput {
+                            amount,
+                            fee: state.rules.hiring_fee,
+                            wage: state.rules.crew_wage,
+                            free_berths: state.free_berths(),
+                        }
+                    )?;
+                    Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
+                        if let KeyCode::Char(c) = event.code {
+                            if c == 'b' {
+                                state.back()?;
+                            } else if let Some(digit) = c.to_digit(10) {
+                                state.user_typed_digit(digit)?;
+                            }
+                        } else if event.code == KeyCode::Backspace {
+                            state.user_typed_backspace()?;
+                        } else if event.code == KeyCode::Enter {
+                            return state
+                                .commit_hiring()
+                                .map(|_| UpdateSignal::Continue)
+                                .or_else(|e| match e {
+                                    StateError::NoBerths | StateError::CannotAfford => {
+                                        Ok(UpdateSignal::Continue)
+                                    }
+                                    x => Err(x.into()),
+                                });
+                        }
+                        Ok(UpdateSignal::Continue)
+                    }))
                 }
                 Mode::BankDeposit(amount) => {
-                    queue!(writer, BankDepositInput(amount))?;
-                    return Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
+                    queue!(
+                        writer,
+                        BankDepositInput {
+                            amount,
+                            interest_rate: state.rules.bank_interest_rate,
+                            run_chance: state.rules.bank_run_chance,
+                        }
+                    )?;
+                    Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
                         if let KeyCode::Char(c) = event.code {
                             if c == 'b' {
                                 state.back()?;
@@ -517,11 +745,20 @@ impl<'a, Writer: Write> Engine<'a, Writer> {
                                 });
                         }
                         Ok(UpdateSignal::Continue)
-                    }));
+                    }))
                 }
                 Mode::BankWithdraw(amount) => {
-                    queue!(writer, BankWithdrawInput(amount))?;
-                    return Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
+                    queue!(
+                        writer,
+                        BankWithdrawInput {
+                            amount,
+                            interest_rate: state.rules.bank_interest_rate,
+                            frozen_until: state
+                                .bank_frozen_until
+                                .filter(|_| state.is_bank_frozen()),
+                        }
+                    )?;
+                    Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
                         if let KeyCode::Char(c) = event.code {
                             if c == 'b' {
                                 state.back()?;
@@ -535,55 +772,189 @@ impl<'a, Writer: Write> Engine<'a, Writer> {
                                 .commit_bank_withdraw()
                                 .map(|_| UpdateSignal::Continue)
                                 .or_else(|e| match e {
-                                    StateError::InsufficientBank => Ok(UpdateSignal::Continue),
+                                    StateError::InsufficientBank | StateError::BankFrozen => {
+                                        Ok(UpdateSignal::Continue)
+                                    }
                                     x => Err(x.into()),
                                 });
                         }
                         Ok(UpdateSignal::Continue)
-                    }));
+                    }))
+                }
+                Mode::CaptainsLog(scroll) => {
+                    queue!(
+                        writer,
+                        CaptainsLog {
+                            entries: &state.ledger.entries,
+                            scroll: *scroll,
+                            export: ledger_export,
+                        }
+                    )?;
+                    Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
+                        const PAGE_SIZE: usize = CaptainsLog::PAGE_SIZE;
+                        let entries = match event.code {
+                            KeyCode::Up | KeyCode::Char('k') => 1,
+                            KeyCode::Down | KeyCode::Char('j') => -1,
+                            KeyCode::PageUp => PAGE_SIZE as isize,
+                            KeyCode::PageDown => -(PAGE_SIZE as isize),
+                            KeyCode::Char('e') => return Ok(UpdateSignal::ExportLedger),
+                            KeyCode::Char('b') | KeyCode::Backspace | KeyCode::Esc => {
+                                state.back()?;
+                                return Ok(UpdateSignal::Continue);
+                            }
+                            _ => return Ok(UpdateSignal::Continue),
+                        };
+                        state.scroll_captains_log(entries, PAGE_SIZE)?;
+                        Ok(UpdateSignal::Continue)
+                    }))
+                }
+                Mode::PriceHistory(good) => {
+                    queue!(
+                        writer,
+                        PriceChart {
+                            history: &state.price_history,
+                            good: *good,
+                            starting_date: state.starting_date,
+                            date: state.date,
+                        }
+                    )?;
+                    Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
+                        if let Some(good) = Good::from_key_code(&event.code) {
+                            state.choose_price_history_good(good)?;
+                        } else if matches!(
+                            event.code,
+                            KeyCode::Char('b') | KeyCode::Backspace | KeyCode::Esc
+                        ) {
+                            state.back()?;
+                        }
+                        Ok(UpdateSignal::Continue)
+                    }))
+                }
+                Mode::MarketRumors => {
+                    queue!(
+                        writer,
+                        MarketRumors {
+                            history: &state.price_history,
+                            location: state.location,
+                            prices: &state.locations.location_info(&state.location).prices,
+                            date: state.date,
+                        }
+                    )?;
+                    Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
+                        if matches!(
+                            event.code,
+                            KeyCode::Char('b') | KeyCode::Backspace | KeyCode::Esc
+                        ) {
+                            state.back()?;
+                        }
+                        Ok(UpdateSignal::Continue)
+                    }))
+                }
+                Mode::Contracts => {
+                    queue!(
+                        writer,
+                        ContractBoard {
+                            location: state.location,
+                            offers: &state.locations.location_info(&state.location).contracts,
+                            contracts: &state.contracts,
+                            date: state.date,
+                            max_contracts: state.rules.max_active_contracts,
+                            penalty: state.rules.contract_penalty,
+                        }
+                    )?;
+                    Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
+                        if let KeyCode::Char(c) = event.code {
+                            if c == 'b' {
+                                state.back()?;
+                            } else if let Some(digit) = c.to_digit(10).filter(|digit| *digit > 0) {
+                                // choosing a contract that can't be taken on does nothing
+                                match state.accept_contract(digit as usize - 1) {
+                                    Ok(_)
+                                    | Err(StateError::TooManyContracts)
+                                    | Err(StateError::NoSuchContract) => {}
+                                    Err(e) => return Err(e.into()),
+                                }
+                            }
+                        } else if matches!(event.code, KeyCode::Backspace | KeyCode::Esc) {
+                            state.back()?;
+                        }
+                        Ok(UpdateSignal::Continue)
+                    }))
+                }
+                Mode::Shipyard => {
+                    queue!(
+                        writer,
+                        Shipyard {
+                            location: state.location,
+                            current: state.ship_class,
+                            trade_in: state
+                                .ship_class
+                                .trade_in_value(state.hull_damage, state.rules.repair_cost),
+                        }
+                    )?;
+                    Ok(Box::new(|event: KeyEvent, state: &mut GameState| {
+                        if let KeyCode::Char(c) = event.code {
+                            if c == 'b' {
+                                state.back()?;
+                            } else if let Some(class) = ShipClass::from_key(c) {
+                                // choosing a ship that can't be had does nothing
+                                match state.trade_in_ship(class) {
+                                    Ok(_) => state.back()?,
+                                    Err(StateError::CannotAfford)
+                                    | Err(StateError::InsufficientHold)
+                                    | Err(StateError::AlreadyHaveShip(_)) => {}
+                                    Err(e) => return Err(e.into()),
+                                }
+                            }
+                        } else if matches!(event.code, KeyCode::Backspace | KeyCode::Esc) {
+                            state.back()?;
+                        }
+                        Ok(UpdateSignal::Continue)
+                    }))
                 }
                 Mode::GameEvent(event) => match event {
                     LocationEvent::CheapGood(good) => {
                         queue!(writer, CheapGoodDialog(good))?;
-                        return Ok(Box::new(|_: KeyEvent, state: &mut GameState| {
+                        Ok(Box::new(|_: KeyEvent, state: &mut GameState| {
                             state.acknowledge_event()?;
                             Ok(UpdateSignal::Continue)
-                        }));
+                        }))
                     }
                     LocationEvent::ExpensiveGood(good) => {
                         queue!(writer, ExpensiveGoodDialog(good))?;
-                        return Ok(Box::new(|_: KeyEvent, state: &mut GameState| {
+                        Ok(Box::new(|_: KeyEvent, state: &mut GameState| {
                             state.acknowledge_event()?;
                             Ok(UpdateSignal::Continue)
-                        }));
+                        }))
                     }
                     LocationEvent::FindGoods(good, amount) => {
                         queue!(writer, FindGoodsDialog(good, amount, state))?;
-                        let good = *good;
-                        let amount = *amount;
-                        return Ok(Box::new(move |_: KeyEvent, state: &mut GameState| {
-                            {
-                                let remaining_hold = state.remaining_hold();
-                                let amount_to_add = min(amount, remaining_hold);
-                                state.inventory.add_good(&good, amount_to_add);
-                            }
-                            state.acknowledge_event()?;
+                        Ok(Box::new(|_: KeyEvent, state: &mut GameState| {
+                            state.take_found_goods()?;
                             Ok(UpdateSignal::Continue)
-                        }));
+                        }))
                     }
                     LocationEvent::GoodsStolen(info) => {
                         let info = info.unwrap_or_else(|| state.compute_goods_stolen());
                         queue!(writer, GoodsStolenDialog(info))?;
-                        return Ok(Box::new(move |_: KeyEvent, state: &mut GameState| {
+                        Ok(Box::new(move |_: KeyEvent, state: &mut GameState| {
                             state.remove_stolen_goods(info);
                             state.acknowledge_event()?;
                             Ok(UpdateSignal::Continue)
-                        }));
+                        }))
                     }
                     LocationEvent::CanBuyCannon => {
-                        queue!(writer, CanBuyCannon)?;
-                        return Ok(Box::new(move |event: KeyEvent, state: &mut GameState| {
-                            if let KeyCode::Char(c) = event.code {
+                        queue!(
+                            writer,
+                            CanBuyCannon {
+                                cost: state.rules.cannon_cost,
+                                full: Some(state.ship_class).filter(|_| state.cannons_full()),
+                            }
+                        )?;
+                        Ok(Box::new(move |event: KeyEvent, state: &mut GameState| {
+                            if state.cannons_full() {
+                                state.acknowledge_event()?;
+                            } else if let KeyCode::Char(c) = event.code {
                                 if c == 'y' {
                                     state.confirm_buy_cannon()?;
                                 } else if c == 'n' {
@@ -591,7 +962,7 @@ impl<'a, Writer: Write> Engine<'a, Writer> {
                                 }
                             }
                             Ok(UpdateSignal::Continue)
-                        }));
+                        }))
                     }
                     // TODO<samgqroberts> 2024-04-10 use actual error type
                     LocationEvent::PirateEncounter(_) => panic!("Cannot encounter pirates here"),
@@ -599,7 +970,7 @@ impl<'a, Writer: Write> Engine<'a, Writer> {
                         let price = *price;
                         let more_hold = *more_hold;
                         queue!(writer, CanBuyHoldSpace { price, more_hold })?;
-                        return Ok(Box::new(move |event: KeyEvent, state: &mut GameState| {
+                        Ok(Box::new(move |event: KeyEvent, state: &mut GameState| {
                             if let KeyCode::Char(c) = event.code {
                                 if c == 'y' {
                                     state.confirm_buy_hold_space(price, more_hold)?;
@@ -608,14 +979,101 @@ impl<'a, Writer: Write> Engine<'a, Writer> {
                                 }
                             }
                             Ok(UpdateSignal::Continue)
-                        }));
+                        }))
+                    }
+                    LocationEvent::DebtCollectors {
+                        lender,
+                        months_overdue,
+                    } => {
+                        queue!(
+                            writer,
+                            DebtCollectorsDialog {
+                                lender: *lender,
+                                months_overdue: *months_overdue,
+                                collection: state.debt_collection(lender, *months_overdue),
+                            }
+                        )?;
+                        Ok(Box::new(|_: KeyEvent, state: &mut GameState| {
+                            state.pay_debt_collectors()?;
+                            Ok(UpdateSignal::Continue)
+                        }))
+                    }
+                    LocationEvent::BankRun { lost, frozen_until } => {
+                        queue!(
+                            writer,
+                            BankRunDialog {
+                                lost: *lost,
+                                frozen_until: *frozen_until,
+                            }
+                        )?;
+                        Ok(Box::new(|_: KeyEvent, state: &mut GameState| {
+                            state.acknowledge_event()?;
+                            Ok(UpdateSignal::Continue)
+                        }))
+                    }
+                    LocationEvent::Desertion { sailors } => {
+                        queue!(writer, DesertionDialog { sailors: *sailors })?;
+                        Ok(Box::new(|_: KeyEvent, state: &mut GameState| {
+                            state.acknowledge_event()?;
+                            Ok(UpdateSignal::Continue)
+                        }))
+                    }
+                    LocationEvent::Mutiny { sailors, gold } => {
+                        queue!(
+                            writer,
+                            MutinyDialog {
+                                sailors: *sailors,
+                                gold: *gold,
+                            }
+                        )?;
+                        Ok(Box::new(|_: KeyEvent, state: &mut GameState| {
+                            state.acknowledge_event()?;
+                            Ok(UpdateSignal::Continue)
+                        }))
+                    }
+                    LocationEvent::CustomsInspection => {
+                        let inspection = state.customs_inspection();
+                        let found_contraband = inspection.found_contraband();
+                        queue!(
+                            writer,
+                            CustomsInspectionDialog {
+                                inspection,
+                                gold: state.gold.0,
+                            }
+                        )?;
+                        Ok(Box::new(move |event: KeyEvent, state: &mut GameState| {
+                            if !found_contraband {
+                                state.acknowledge_event()?;
+                            } else if let KeyCode::Char(c) = event.code {
+                                if c == 'y' {
+                                    state.bribe_customs()?;
+                                } else if c == 'n' {
+                                    state.submit_to_customs()?;
+                                }
+                            }
+                            Ok(UpdateSignal::Continue)
+                        }))
+                    }
+                    LocationEvent::WarehouseLosses(losses) => {
+                        queue!(writer, WarehouseLossesDialog(losses))?;
+                        Ok(Box::new(|_: KeyEvent, state: &mut GameState| {
+                            state.acknowledge_event()?;
+                            Ok(UpdateSignal::Continue)
+                        }))
+                    }
+                    LocationEvent::ContractsSettled(outcomes) => {
+                        queue!(writer, ContractsSettledDialog(outcomes))?;
+                        Ok(Box::new(|_: KeyEvent, state: &mut GameState| {
+                            state.acknowledge_event()?;
+                            Ok(UpdateSignal::Continue)
+                        }))
                     }
                     LocationEvent::NoEffect(variant) => {
                         queue!(writer, NoEffect { variant: *variant })?;
-                        return Ok(Box::new(move |_: KeyEvent, state: &mut GameState| {
+                        Ok(Box::new(move |_: KeyEvent, state: &mut GameState| {
                             state.acknowledge_event()?;
                             Ok(UpdateSignal::Continue)
-                        }));
+                        }))
                     }
                 },
             }
@@ -624,12 +1082,34 @@ impl<'a, Writer: Write> Engine<'a, Writer> {
 
     pub fn draw_scene(&mut self, state: &mut GameState) -> io::Result<Box<UpdateFn>> {
         info!("Drawing scene: {:?}", state.mode);
+        if !matches!(state.mode, Mode::CaptainsLog(_)) {
+            self.ledger_export = None;
+        }
         let writer = &mut *self.writer.borrow_mut();
-        let update = Engine::queue_scene(writer, state)?;
+        let update = Engine::queue_scene(
+            writer,
+            state,
+            self.saved_game_available,
+            self.new_high_score,
+            self.ledger_export.as_deref(),
+        )?;
         writer.flush()?;
         Ok(update)
     }
 
+    pub fn draw_high_scores(&mut self) -> io::Result<()> {
+        info!("Drawing high scores");
+        let writer = &mut *self.writer.borrow_mut();
+        queue!(
+            writer,
+            HighScoresScreen {
+                entries: &self.high_scores.entries,
+                highlight: self.new_high_score
+            }
+        )?;
+        writer.flush()
+    }
+
     pub fn draw_need_resize(&mut self, current_x_cols: u16, current_y_cols: u16) -> io::Result<()> {
         info!("Drawing screen requiring resize");
         let writer = &mut *self.writer.borrow_mut();
@@ -0,0 +1,223 @@
+use std::{
+    cmp::Reverse,
+    fs::{self, File},
+    io::{self, BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use tracing::{error, info};
+
+use crate::{
+    components::AchievementTier,
+    state::{Difficulty, GameState},
+};
+
+/// how many of the best games are kept
+pub const MAX_HIGH_SCORES: usize = 10;
+
+/// bumped whenever the high score format changes in a way older files can't be read
+const HIGH_SCORES_VERSION: u32 = 1;
+
+/// where the high scores are kept when no other path is given,
+/// eg. ~/.local/share/merchant/high_scores.json on Linux
+pub fn default_high_scores_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("merchant").join("high_scores.json"))
+}
+
+/// one finished game
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HighScore {
+    pub name: String,
+    pub net_worth: i32,
+    pub tier: AchievementTier,
+    pub difficulty: Difficulty,
+    /// the real world date the game was played on
+    pub date: NaiveDate,
+    pub seed: Option<u64>,
+}
+
+impl HighScore {
+    pub fn from_game(state: &GameState, name: &str, date: NaiveDate) -> Self {
+        let net_worth = state.net_worth();
+        HighScore {
+            name: name.to_owned(),
+            net_worth,
+            tier: AchievementTier::from_net_worth(
+                net_worth,
+                &state.location_config.overall_price_ranges,
+                state.difficulty,
+            ),
+            difficulty: state.difficulty,
+            date,
+            seed: state.seed,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct HighScoresFile {
+    version: u32,
+    entries: Vec<HighScore>,
+}
+
+/// the best games played, highest net worth first
+#[derive(Debug, Default)]
+pub struct HighScores {
+    /// where the table is saved, if anywhere
+    path: Option<PathBuf>,
+    pub entries: Vec<HighScore>,
+}
+
+impl HighScores {
+    /// reads the table from the given path.
+    /// a missing file is an empty table, and an unreadable one is moved aside
+    /// so that it isn't overwritten by the next high score.
+    pub fn load(path: Option<PathBuf>) -> Self {
+        let entries = match &path {
+            Some(path) if path.exists() => match read_entries(path) {
+                Ok(entries) => entries,
+                Err(e) => {
+                    error!("unable to read high scores {}: {:?}", path.display(), e);
+                    let mut corrupt_path = path.clone().into_os_string();
+                    corrupt_path.push(".corrupt");
+                    if let Err(e) = fs::rename(path, &corrupt_path) {
+                        error!("unable to move aside unreadable high scores: {:?}", e);
+                    }
+                    vec![]
+                }
+            },
+            _ => vec![],
+        };
+        HighScores { path, entries }
+    }
+
+    /// adds a game to the table, returning its position if it's among the best
+    pub fn add(&mut self, high_score: HighScore) -> Option<usize> {
+        // later games go after earlier ones with the same net worth
+        let position = self
+            .entries
+            .partition_point(|entry| entry.net_worth >= high_score.net_worth);
+        if position >= MAX_HIGH_SCORES {
+            return None;
+        }
+        self.entries.insert(position, high_score);
+        self.entries.truncate(MAX_HIGH_SCORES);
+        Some(position)
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer_pretty(
+            &mut writer,
+            &HighScoresFile {
+                version: HIGH_SCORES_VERSION,
+                entries: self.entries.clone(),
+            },
+        )?;
+        writer.flush()?;
+        info!("saved high scores to {}", path.display());
+        Ok(())
+    }
+}
+
+fn read_entries(path: &Path) -> io::Result<Vec<HighScore>> {
+    let reader = BufReader::new(File::open(path)?);
+    let file: HighScoresFile = serde_json::from_reader(reader)?;
+    if file.version != HIGH_SCORES_VERSION {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "high scores version {} is not supported (expected {})",
+                file.version, HIGH_SCORES_VERSION
+            ),
+        ));
+    }
+    let mut entries = file.entries;
+    // don't trust that a hand edited file is in order
+    entries.sort_by_key(|entry| Reverse(entry.net_worth));
+    entries.truncate(MAX_HIGH_SCORES);
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn high_score(name: &str, net_worth: i32) -> HighScore {
+        HighScore {
+            name: name.to_owned(),
+            net_worth,
+            tier: AchievementTier::Ok,
+            difficulty: Difficulty::Normal,
+            date: NaiveDate::from_ymd_opt(2024, 11, 5).unwrap(),
+            seed: Some(42),
+        }
+    }
+
+    fn names(high_scores: &HighScores) -> Vec<&str> {
+        high_scores
+            .entries
+            .iter()
+            .map(|entry| entry.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn keeps_the_best_games_in_order() {
+        let mut high_scores = HighScores::default();
+        assert_eq!(high_scores.add(high_score("a", 100)), Some(0));
+        assert_eq!(high_scores.add(high_score("b", 300)), Some(0));
+        assert_eq!(high_scores.add(high_score("c", 100)), Some(2));
+        assert_eq!(names(&high_scores), vec!["b", "a", "c"]);
+        for i in 0..7 {
+            high_scores.add(high_score("d", 200 + i));
+        }
+        assert_eq!(high_scores.entries.len(), MAX_HIGH_SCORES);
+        // not good enough to make the table
+        assert_eq!(high_scores.add(high_score("e", 50)), None);
+        // pushes the worst game out of the table
+        assert_eq!(high_scores.add(high_score("f", 150)), Some(8));
+        assert_eq!(high_scores.entries.len(), MAX_HIGH_SCORES);
+        assert_eq!(high_scores.entries[9].name, "a");
+    }
+
+    #[test]
+    fn round_trip() {
+        let path = temp_dir().join("merchant_high_scores_round_trip.json");
+        let _ = fs::remove_file(&path);
+        let mut high_scores = HighScores::load(Some(path.clone()));
+        high_scores.add(high_score("a", 100));
+        high_scores.add(high_score("b", -100));
+        high_scores.save().unwrap();
+        let loaded = HighScores::load(Some(path.clone()));
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.entries, high_scores.entries);
+    }
+
+    #[test]
+    fn missing_and_corrupt_files() {
+        let path = temp_dir().join("merchant_high_scores_corrupt.json");
+        let corrupt_path = temp_dir().join("merchant_high_scores_corrupt.json.corrupt");
+        let _ = fs::remove_file(&path);
+        assert_eq!(HighScores::load(Some(path.clone())).entries, vec![]);
+        fs::write(&path, "{ not json").unwrap();
+        let high_scores = HighScores::load(Some(path.clone()));
+        assert_eq!(high_scores.entries, vec![]);
+        // the corrupt file is kept for the player to fix
+        assert!(!path.exists());
+        assert_eq!(fs::read_to_string(&corrupt_path).unwrap(), "{ not json");
+        fs::remove_file(&corrupt_path).unwrap();
+    }
+}
@@ -5,13 +5,21 @@ use tracing_subscriber::{
     EnvFilter,
 };
 
-pub fn initialize_logging() {
-    if std::env::var("MERCHANT_LOG").is_err() {
-        // there is no env var for MERCHANT_LOG
-        // logging is not enabled
-        // do not initialize tracing (do not create log file)
-        return;
-    }
+/// enables logging to ./merchant.log, using the given filter directives if any,
+/// otherwise those in the MERCHANT_LOG env var
+pub fn initialize_logging(filter: Option<&str>) {
+    let filter = match filter {
+        Some(filter) => EnvFilter::new(filter),
+        None => {
+            if std::env::var("MERCHANT_LOG").is_err() {
+                // there is no env var for MERCHANT_LOG
+                // logging is not enabled
+                // do not initialize tracing (do not create log file)
+                return;
+            }
+            EnvFilter::from_env("MERCHANT_LOG")
+        }
+    };
     // a "rolling" file appender that *never* actually rolls the file
     let file_appender = tracing_appender::rolling::never("./", "merchant.log");
     // a tracing-subscriber Layer that formats the captured spans / events and writes to the file
@@ -19,8 +27,8 @@ pub fn initialize_logging() {
     // a tracing Subscriber that has only one Layer, the file-writing layer
     let subscriber = tracing_subscriber::registry()
         .with(file_layer)
-        // use the value of MERCHANT_LOG to determine the log levels
-        .with(EnvFilter::from_env("MERCHANT_LOG"));
+        // use the filter directives to determine the log levels
+        .with(filter);
     // set the subscriber as the global default subscriber for the program
     tracing::subscriber::set_global_default(subscriber).expect("Unable to set global subscriber");
     // log a simple message
@@ -1,35 +1,102 @@
+mod cli;
 mod engine;
+mod high_scores;
 #[macro_use]
 mod macros;
 mod components;
 mod logging;
+mod replay;
+mod save;
+mod script;
 mod state;
 #[cfg(test)]
 mod test;
 
+use chrono::Local;
+use clap::Parser;
+use cli::Args;
 use engine::{Engine, UpdateSignal};
+use high_scores::{HighScore, HighScores};
 use logging::initialize_logging;
-use rand::{rngs::StdRng, SeedableRng};
+use replay::{play_replay, read_replay, ReplayPlayer, ReplayRecorder};
+use save::{load_game, save_game, saved_game_exists, DEFAULT_SAVE_PATH};
+use script::ScriptRunner;
+use state::{Good, LEDGER_EXPORT_PATH};
 use std::cell::RefCell;
+use std::fs::File;
 use std::io::Stdout;
-use std::io::{self, stdout};
+use std::io::{self, stdin, stdout, BufRead, BufReader};
+use std::path::Path;
 use tracing::{error, info, span, Level};
 
 use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
-use state::GameState;
 
 fn main() -> io::Result<()> {
-    initialize_logging();
+    let args = Args::parse();
+    initialize_logging(args.log.as_deref());
+    if let Some(replay_path) = &args.replay {
+        return watch_replay(replay_path, args.replay_speed);
+    }
+    let rules = args.rules().unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    });
+    if args.print_rules {
+        print!("{}", rules.to_toml());
+        return Ok(());
+    }
+    // initialize game state, either from the given save file or from a new seed
+    let save_path = args.load.as_deref().unwrap_or(Path::new(DEFAULT_SAVE_PATH));
+    let mut game_state = match &args.load {
+        Some(path) => load_game(path).map_err(|e| {
+            error!("unable to load saved game: {:?}", e);
+            io::Error::new(
+                e.kind(),
+                format!("unable to load saved game {}: {}", path.display(), e),
+            )
+        })?,
+        None => args.new_game(rules),
+    };
+    Good::use_names_and_keys(&game_state.rules);
+    if let Some(script_path) = &args.script {
+        // headless mode, no need for the terminal UI
+        let script: Box<dyn BufRead> = if script_path == Path::new("-") {
+            Box::new(stdin().lock())
+        } else {
+            Box::new(BufReader::new(File::open(script_path)?))
+        };
+        if let Some(difficulty) = args.difficulty {
+            game_state.choose_difficulty(difficulty);
+        }
+        let mut runner = ScriptRunner::new(&mut game_state, args.output, stdout().lock());
+        if let Err(e) = runner.run(script) {
+            error!("script failed: {:?}", e);
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
     let stdout = stdout();
     // set terminal into "non-canonical" mode so inputs are captured raw with no interpretation
     // https://docs.rs/crossterm/0.26.1/crossterm/terminal/index.html#raw-mode
     enable_raw_mode()?;
-    // initialize game state with RNG
-    let rng = StdRng::from_entropy();
-    let mut game_state = GameState::new_std_rng(rng);
     // initialize game engine, pointing it to write to stdout
     let writer: RefCell<Stdout> = RefCell::from(stdout);
     let mut engine = Engine::new(&writer);
+    engine.saved_game_available = saved_game_exists(save_path);
+    engine.high_scores = HighScores::load(args.high_scores_path());
+    // whether the current game has been added to the high scores yet
+    let mut high_score_recorded = false;
+    if let Some(record_path) = &args.record {
+        let mut recorder = ReplayRecorder::create(record_path)?;
+        if args.load.is_some() {
+            recorder.record_loaded_game(&mut game_state)?;
+        } else {
+            recorder.record_new_game(&mut game_state)?;
+        }
+        recorder.record_saved_game_available(engine.saved_game_available)?;
+        engine.recorder = Some(recorder);
+    }
     // start main game loop which draws -> reads input -> updates state
     loop {
         let span = span!(Level::INFO, "gameloop");
@@ -45,6 +112,20 @@ fn main() -> io::Result<()> {
                 break;
             }
             Ok(signal) => {
+                if game_state.game_end && !high_score_recorded {
+                    high_score_recorded = true;
+                    let high_score = HighScore::from_game(
+                        &game_state,
+                        &args.player_name(),
+                        Local::now().date_naive(),
+                    );
+                    engine.new_high_score = engine.high_scores.add(high_score);
+                    if engine.new_high_score.is_some() {
+                        if let Err(e) = engine.high_scores.save() {
+                            error!("unable to save high scores: {:?}", e);
+                        }
+                    }
+                }
                 match signal {
                     UpdateSignal::Continue => {
                         // do nothing, loop again
@@ -56,9 +137,66 @@ fn main() -> io::Result<()> {
                         break;
                     }
                     UpdateSignal::Restart => {
-                        let rng = StdRng::from_entropy();
-                        game_state = GameState::new_std_rng(rng);
+                        // a restarted game keeps the rules of the one before it
+                        game_state = args.new_game(game_state.rules.clone());
+                        high_score_recorded = false;
+                        engine.new_high_score = None;
+                        engine.saved_game_available = saved_game_exists(save_path);
+                        if let Some(recorder) = &mut engine.recorder {
+                            recorder.record_new_game(&mut game_state)?;
+                            recorder.record_saved_game_available(engine.saved_game_available)?;
+                        }
+                    }
+                    UpdateSignal::ShowHighScores => {
+                        engine.showing_high_scores = true;
                     }
+                    UpdateSignal::ExportLedger => {
+                        let path = Path::new(LEDGER_EXPORT_PATH);
+                        let outcome = match game_state.ledger.export_csv(path) {
+                            Ok(()) => {
+                                info!("exported the ledger to {}", path.display());
+                                format!("Exported to {}.", path.display())
+                            }
+                            Err(e) => {
+                                error!("unable to export the ledger: {:?}", e);
+                                format!("Unable to export the log: {}", e)
+                            }
+                        };
+                        engine.ledger_export = Some(outcome);
+                    }
+                    UpdateSignal::SaveAndQuit => {
+                        info!("save requested, saving and exiting");
+                        match save_game(save_path, &mut game_state) {
+                            Ok(()) => engine.exit_message(&[
+                                &format!("Game saved to {}.", save_path.display()),
+                                "Thank you for playing!",
+                            ])?,
+                            Err(e) => {
+                                error!("unable to save game: {:?}", e);
+                                engine.exit_message(&[&format!("Unable to save game: {}", e)])?
+                            }
+                        }
+                        break;
+                    }
+                    UpdateSignal::LoadSavedGame => match load_game(save_path) {
+                        Ok(mut loaded) => {
+                            if let Some(recorder) = &mut engine.recorder {
+                                recorder.record_loaded_game(&mut loaded)?;
+                            }
+                            game_state = loaded;
+                            Good::use_names_and_keys(&game_state.rules);
+                            high_score_recorded = false;
+                            engine.new_high_score = None;
+                        }
+                        Err(e) => {
+                            // stay on the splash screen, but stop offering the broken save
+                            error!("unable to load saved game: {:?}", e);
+                            engine.saved_game_available = false;
+                            if let Some(recorder) = &mut engine.recorder {
+                                recorder.record_saved_game_available(false)?;
+                            }
+                        }
+                    },
                 }
             }
         }
@@ -66,3 +204,19 @@ fn main() -> io::Result<()> {
     // set terminal back to canonical mode before exiting
     disable_raw_mode()
 }
+
+/// plays back a session recorded with --record
+fn watch_replay(path: &Path, speed: f64) -> io::Result<()> {
+    let player = ReplayPlayer::new(read_replay(path)?)?;
+    enable_raw_mode()?;
+    let writer = RefCell::from(stdout());
+    let mut engine = Engine::new(&writer);
+    match play_replay(&mut engine, player, speed) {
+        Ok(()) => engine.exit_message(&["End of replay."])?,
+        Err(e) => {
+            error!("an error was encountered playing the replay: {:?}", e);
+            engine.exit_message(&[&format!("Replay stopped: {:?}", e)])?
+        }
+    }
+    disable_raw_mode()
+}
@@ -0,0 +1,351 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    iter::Peekable,
+    path::Path,
+    time::{Duration, Instant},
+    vec,
+};
+
+use crossterm::event::{poll, read, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use rand::{rngs::StdRng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::{
+    engine::{Engine, UpdateFn, UpdateResult},
+    state::{GameState, Good, Rules},
+};
+
+/// bumped whenever the replay format changes in a way older replays can't be played
+const REPLAY_VERSION: u32 = 7;
+
+/// the fastest and slowest a replay can be played, in key presses per second
+pub const MAX_REPLAY_SPEED: f64 = 64.0;
+pub const MIN_REPLAY_SPEED: f64 = 0.25;
+
+/// the first line of a replay file, every following line is a [ReplayEntry]
+#[derive(Serialize, Deserialize)]
+struct ReplayHeader {
+    version: u32,
+}
+
+/// everything needed to reproduce a session, in the order it happened
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub enum ReplayEntry {
+    /// a new game was generated from a seed and rules
+    NewGame { seed: u64, rules: Box<Rules> },
+    /// an existing game was loaded, and its RNG reseeded with `rng_seed`
+    LoadedGame {
+        rng_seed: u64,
+        state: serde_json::Value,
+    },
+    /// whether the splash screen offers to continue a saved game
+    SavedGameAvailable(bool),
+    /// a key press handled by the scene being shown
+    Key(KeyEvent),
+}
+
+impl ReplayEntry {
+    fn new_game(state: &mut GameState) -> io::Result<ReplayEntry> {
+        match state.seed {
+            Some(seed) => Ok(ReplayEntry::NewGame {
+                seed,
+                rules: Box::new(state.rules.clone()),
+            }),
+            // without a seed to regenerate it from, the whole game has to be recorded
+            None => ReplayEntry::loaded_game(state),
+        }
+    }
+
+    fn loaded_game(state: &mut GameState) -> io::Result<ReplayEntry> {
+        let rng_seed = state.reseed_rng();
+        Ok(ReplayEntry::LoadedGame {
+            rng_seed,
+            state: serde_json::to_value(&*state)?,
+        })
+    }
+
+    /// the game this entry starts, if any
+    fn start_game(self) -> io::Result<Option<GameState>> {
+        let state = match self {
+            ReplayEntry::NewGame { seed, rules } => GameState::from_seed(seed, *rules),
+            ReplayEntry::LoadedGame { rng_seed, state } => {
+                let mut state: GameState = serde_json::from_value(state)?;
+                state.rng = Box::new(StdRng::seed_from_u64(rng_seed));
+                state
+            }
+            ReplayEntry::SavedGameAvailable(_) | ReplayEntry::Key(_) => return Ok(None),
+        };
+        Good::use_names_and_keys(&state.rules);
+        Ok(Some(state))
+    }
+}
+
+/// writes a replay file as the game is played.
+/// every entry is flushed right away so that the replay survives a crash.
+pub struct ReplayRecorder {
+    writer: BufWriter<File>,
+}
+
+impl ReplayRecorder {
+    pub fn create(path: &Path) -> io::Result<Self> {
+        let mut recorder = Self {
+            writer: BufWriter::new(File::create(path)?),
+        };
+        recorder.write_line(&ReplayHeader {
+            version: REPLAY_VERSION,
+        })?;
+        info!("recording replay to {}", path.display());
+        Ok(recorder)
+    }
+
+    fn write_line(&mut self, value: &impl Serialize) -> io::Result<()> {
+        serde_json::to_writer(&mut self.writer, value)?;
+        writeln!(self.writer)?;
+        self.writer.flush()
+    }
+
+    /// records the start of a freshly generated game
+    pub fn record_new_game(&mut self, state: &mut GameState) -> io::Result<()> {
+        let entry = ReplayEntry::new_game(state)?;
+        self.write_line(&entry)
+    }
+
+    /// records the start of a game loaded from a save.
+    /// the game's RNG is reseeded so that playback draws the same numbers.
+    pub fn record_loaded_game(&mut self, state: &mut GameState) -> io::Result<()> {
+        let entry = ReplayEntry::loaded_game(state)?;
+        self.write_line(&entry)
+    }
+
+    pub fn record_saved_game_available(&mut self, saved_game_available: bool) -> io::Result<()> {
+        self.write_line(&ReplayEntry::SavedGameAvailable(saved_game_available))
+    }
+
+    pub fn record_key(&mut self, event: &KeyEvent) -> io::Result<()> {
+        self.write_line(&ReplayEntry::Key(*event))
+    }
+}
+
+pub fn read_replay(path: &Path) -> io::Result<Vec<ReplayEntry>> {
+    let mut lines = BufReader::new(File::open(path)?).lines();
+    let header: ReplayHeader = match lines.next() {
+        Some(line) => serde_json::from_str(&line?)?,
+        None => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "replay file is empty",
+            ))
+        }
+    };
+    if header.version != REPLAY_VERSION {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "replay file version {} is not supported (expected {})",
+                header.version, REPLAY_VERSION
+            ),
+        ));
+    }
+    lines
+        .filter(|line| !matches!(line, Ok(line) if line.trim().is_empty()))
+        .map(|line| Ok(serde_json::from_str(&line?)?))
+        .collect()
+}
+
+/// steps through a recorded session one key press at a time
+pub struct ReplayPlayer {
+    entries: Peekable<vec::IntoIter<ReplayEntry>>,
+    pub state: GameState,
+    pub saved_game_available: bool,
+}
+
+impl ReplayPlayer {
+    pub fn new(entries: Vec<ReplayEntry>) -> io::Result<Self> {
+        let mut entries = entries.into_iter().peekable();
+        let state = entries
+            .next()
+            .map(ReplayEntry::start_game)
+            .transpose()?
+            .flatten()
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "replay file does not start with a game",
+                )
+            })?;
+        let mut player = Self {
+            entries,
+            state,
+            saved_game_available: false,
+        };
+        player.apply_until_key()?;
+        Ok(player)
+    }
+
+    pub fn is_finished(&mut self) -> bool {
+        self.entries.peek().is_none()
+    }
+
+    /// applies entries that aren't key presses, up until the next key press
+    fn apply_until_key(&mut self) -> io::Result<()> {
+        while let Some(entry) = self.entries.next_if(|e| !matches!(e, ReplayEntry::Key(_))) {
+            if let ReplayEntry::SavedGameAvailable(saved_game_available) = entry {
+                self.saved_game_available = saved_game_available;
+            } else if let Some(state) = entry.start_game()? {
+                self.state = state;
+            }
+        }
+        Ok(())
+    }
+
+    /// passes the next recorded key press to the scene's update function,
+    /// the same way the engine did when it was recorded
+    pub fn step(&mut self, update_fn: Box<UpdateFn>) -> UpdateResult<()> {
+        if let Some(ReplayEntry::Key(event)) = self.entries.next() {
+            update_fn(event, &mut self.state)?;
+        }
+        self.apply_until_key()?;
+        Ok(())
+    }
+}
+
+/// renders each frame of the replay, waiting between key presses.
+/// while watching, + and - change the speed, and q, Esc or ctrl-c stop the replay.
+pub fn play_replay<W: Write>(
+    engine: &mut Engine<W>,
+    mut player: ReplayPlayer,
+    mut speed: f64,
+) -> UpdateResult<()> {
+    loop {
+        engine.saved_game_available = player.saved_game_available;
+        let update_fn = engine.draw_scene(&mut player.state)?;
+        if player.is_finished() {
+            return Ok(());
+        }
+        let deadline = Instant::now() + Duration::from_secs_f64(1.0 / speed);
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() || !poll(remaining)? {
+                break;
+            }
+            if let Event::Key(event) = read()? {
+                if event.kind != KeyEventKind::Press {
+                    continue;
+                }
+                match event.code {
+                    KeyCode::Char('c') if event.modifiers == KeyModifiers::CONTROL => return Ok(()),
+                    KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                    KeyCode::Char('+') => speed = (speed * 2.0).min(MAX_REPLAY_SPEED),
+                    KeyCode::Char('-') => speed = (speed / 2.0).max(MIN_REPLAY_SPEED),
+                    _ => {}
+                }
+            }
+        }
+        player.step(update_fn)?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, env::temp_dir};
+
+    use captured_write::CapturedWrite;
+    use pretty_assertions::assert_eq;
+
+    use crate::state::{Difficulty, Location, Mode};
+
+    use super::*;
+
+    fn key(c: char) -> ReplayEntry {
+        ReplayEntry::Key(KeyEvent::from(KeyCode::Char(c)))
+    }
+
+    fn play(entries: Vec<ReplayEntry>) -> UpdateResult<GameState> {
+        let writer = RefCell::new(CapturedWrite::new());
+        let mut engine = Engine::new(&writer);
+        let mut player = ReplayPlayer::new(entries)?;
+        while !player.is_finished() {
+            engine.saved_game_available = player.saved_game_available;
+            let update_fn = engine.draw_scene(&mut player.state)?;
+            player.step(update_fn)?;
+        }
+        Ok(player.state)
+    }
+
+    #[test]
+    fn record_and_read() -> io::Result<()> {
+        let path = temp_dir().join("merchant_replay_record_and_read.jsonl");
+        let mut state = GameState::from_seed(42, Rules::default());
+        let mut recorder = ReplayRecorder::create(&path)?;
+        recorder.record_new_game(&mut state)?;
+        recorder.record_saved_game_available(true)?;
+        recorder.record_key(&KeyEvent::from(KeyCode::Enter))?;
+        drop(recorder);
+        let entries = read_replay(&path)?;
+        std::fs::remove_file(&path)?;
+        assert_eq!(
+            entries,
+            vec![
+                ReplayEntry::NewGame {
+                    seed: 42,
+                    rules: Box::default()
+                },
+                ReplayEntry::SavedGameAvailable(true),
+                ReplayEntry::Key(KeyEvent::from(KeyCode::Enter)),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn playback_matches_original_game() -> UpdateResult<()> {
+        let keys = "xe1r5\r3v";
+        let mut entries = vec![ReplayEntry::NewGame {
+            seed: 7,
+            rules: Box::default(),
+        }];
+        entries.extend(keys.chars().map(|c| match c {
+            '\r' => ReplayEntry::Key(KeyEvent::from(KeyCode::Enter)),
+            c => key(c),
+        }));
+        let replayed = play(entries)?;
+        // play the same keys directly
+        let mut original = GameState::from_seed(7, Rules::default());
+        original.splash_to_introduction();
+        original.choose_difficulty(Difficulty::Easy);
+        original.introduction_to_game();
+        original.begin_buying()?.choose_buy_good(Good::Rum)?;
+        original.user_typed_digit(5)?.commit_buy()?;
+        original.begin_sailing()?.sail_to(&Location::Venice)?;
+        assert_eq!(replayed.inventory[Good::Rum], 5);
+        assert_eq!(replayed.difficulty, Difficulty::Easy);
+        assert_eq!(
+            serde_json::to_value(&replayed).unwrap(),
+            serde_json::to_value(&original).unwrap()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn playback_of_loaded_game() -> UpdateResult<()> {
+        let mut state = GameState::from_seed(7, Rules::default());
+        state.splash_to_introduction();
+        state.introduction_to_game();
+        let entries = vec![
+            ReplayEntry::loaded_game(&mut state)?,
+            ReplayEntry::SavedGameAvailable(true),
+            key('b'),
+        ];
+        let replayed = play(entries)?;
+        assert_eq!(replayed.mode, Mode::Buying(None));
+        Ok(())
+    }
+
+    #[test]
+    fn replay_must_start_with_a_game() {
+        let error = ReplayPlayer::new(vec![key('b')]).map(|_| ()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+}
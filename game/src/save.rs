@@ -0,0 +1,169 @@
+use std::{
+    ffi::OsString,
+    fs::{self, File},
+    io::{self, BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use rand::{rngs::StdRng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::state::GameState;
+
+/// where games are saved to and loaded from when no other path is given
+pub const DEFAULT_SAVE_PATH: &str = "./merchant_save.json";
+
+/// bumped whenever the save format changes in a way older saves can't be read
+const SAVE_VERSION: u32 = 7;
+
+#[derive(Serialize)]
+struct SaveFileOut<'a> {
+    version: u32,
+    rng_seed: u64,
+    state: &'a GameState,
+}
+
+#[derive(Deserialize)]
+struct SaveFileIn {
+    version: u32,
+    rng_seed: u64,
+    state: GameState,
+}
+
+pub fn saved_game_exists(path: &Path) -> bool {
+    path.is_file()
+}
+
+/// the file a save is written to before it replaces the one at the given path
+fn temp_save_path(path: &Path) -> PathBuf {
+    let mut temp = OsString::from(path.as_os_str());
+    temp.push(".tmp");
+    PathBuf::from(temp)
+}
+
+/// writes the game state to the given path.
+/// the state's RNG is reseeded in the process, so that the saved game continues
+/// deterministically from the point it was saved.
+/// the save is written in full beside the path and then moved over it, so a failed save
+/// leaves any earlier one intact.
+pub fn save_game(path: &Path, state: &mut GameState) -> io::Result<()> {
+    let rng_seed = state.reseed_rng();
+    let save = SaveFileOut {
+        version: SAVE_VERSION,
+        rng_seed,
+        state,
+    };
+    let temp_path = temp_save_path(path);
+    let written = write_save(&temp_path, &save).and_then(|_| fs::rename(&temp_path, path));
+    if written.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    written?;
+    info!("saved game to {}", path.display());
+    Ok(())
+}
+
+fn write_save(path: &Path, save: &SaveFileOut) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    serde_json::to_writer(&mut writer, save)?;
+    writer.flush()?;
+    writer.get_ref().sync_all()
+}
+
+pub fn load_game(path: &Path) -> io::Result<GameState> {
+    let reader = BufReader::new(File::open(path)?);
+    let save: SaveFileIn = serde_json::from_reader(reader)?;
+    if save.version != SAVE_VERSION {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "save file version {} is not supported (expected {})",
+                save.version, SAVE_VERSION
+            ),
+        ));
+    }
+    let mut state = save.state;
+    state.rng = Box::new(StdRng::seed_from_u64(save.rng_seed));
+    info!("loaded game from {}", path.display());
+    Ok(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env::temp_dir, num::Saturating};
+
+    use pretty_assertions::assert_eq;
+
+    use crate::state::{
+        Good, LocationEvent, Mode, PirateCaptain, PirateEncounterInfo, PirateEncounterState,
+    };
+
+    use super::*;
+
+    #[test]
+    fn round_trip() -> io::Result<()> {
+        let path = temp_dir().join("merchant_save_round_trip.json");
+        let mut state = GameState::new(Box::new(StdRng::seed_from_u64(42)));
+        state.introduction_to_game();
+        state.gold = Saturating(1234);
+        state.inventory.add_good(&Good::Rum, 7);
+        state.pirates.get_mut(PirateCaptain::RedMorgan).runs = 2;
+        state.mode = Mode::GameEvent(LocationEvent::PirateEncounter(
+            PirateEncounterState::Prompt {
+                info: PirateEncounterInfo::new(3, 5),
+            },
+        ));
+        save_game(&path, &mut state)?;
+        let mut loaded = load_game(&path)?;
+        std::fs::remove_file(&path)?;
+        assert_eq!(
+            serde_json::to_value(&loaded)?,
+            serde_json::to_value(&state)?
+        );
+        // both games draw the same random numbers going forward
+        for _ in 0..10 {
+            assert_eq!(
+                loaded.rng.gen_num_pirates_encountered((2, 4)),
+                state.rng.gen_num_pirates_encountered((2, 4))
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn overwrites_earlier_save() -> io::Result<()> {
+        let path = temp_dir().join("merchant_save_overwrites_earlier_save.json");
+        let mut state = GameState::new(Box::new(StdRng::seed_from_u64(42)));
+        state.gold = Saturating(1);
+        save_game(&path, &mut state)?;
+        state.gold = Saturating(2);
+        save_game(&path, &mut state)?;
+        let loaded = load_game(&path)?;
+        std::fs::remove_file(&path)?;
+        assert_eq!(loaded.gold, Saturating(2));
+        // nothing is left behind beside the save
+        assert!(!temp_save_path(&path).exists());
+        Ok(())
+    }
+
+    #[test]
+    fn unsupported_version() -> io::Result<()> {
+        let path = temp_dir().join("merchant_save_unsupported_version.json");
+        let mut state = GameState::new(Box::new(StdRng::seed_from_u64(42)));
+        save_game(&path, &mut state)?;
+        let contents = std::fs::read_to_string(&path)?.replacen(
+            &format!("\"version\":{SAVE_VERSION}"),
+            "\"version\":0",
+            1,
+        );
+        std::fs::write(&path, contents)?;
+        let result = load_game(&path);
+        std::fs::remove_file(&path)?;
+        assert_eq!(
+            result.map(|_| ()).unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+        Ok(())
+    }
+}
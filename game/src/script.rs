@@ -0,0 +1,1448 @@
+use std::{
+    fmt::{self, Display},
+    io::{self, BufRead, Write},
+    path::PathBuf,
+};
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::state::{
+    Bounty, CustomsInspection, Difficulty, GameState, Good, GoodsStolenResult, Initialization,
+    Inventory, Location, LocationEvent, Mode, NoEffectEvent, PirateBribe, PirateEncounterState,
+    Rules, ShipClass, StateError,
+};
+
+/// how the state of a scripted game is reported
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+    /// a human readable summary
+    Text,
+    /// one JSON object per line
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Amount {
+    Exactly(u32),
+    /// as much as the player is able to
+    Max,
+}
+
+/// a single line of a script, e.g. `buy tea 10` or `sail lisbon`
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScriptCommand {
+    Buy(Good, Amount),
+    Sell(Good, Amount),
+    Sail(Location),
+    StashDeposit(Good, Amount),
+    StashWithdraw(Good, Amount),
+    BankDeposit(Amount),
+    BankWithdraw(Amount),
+    PayDebt(Amount),
+    Borrow(Amount),
+    /// sign on sailors, with `Max` filling every free berth that can be afforded
+    Hire(Amount),
+    /// rent a warehouse in the current port
+    RentWarehouse,
+    /// give up the (empty) warehouse in the current port
+    GiveUpWarehouse,
+    /// have a shipwright mend as much of the hull as can be afforded
+    Repair,
+    /// trade in the ship for one of the given class at the current port's shipyard
+    Shipyard(ShipClass),
+    /// take on the contract with the given number on the current port's board, from 1
+    AcceptContract(usize),
+    /// accept an offer, like a cannon or more hold space, or pay customs officers' bribe
+    Accept,
+    /// decline an offer, or let customs officers seize the contraband
+    Decline,
+    /// try to outrun pirates
+    Run,
+    /// fight pirates
+    Fight,
+    /// fire every cannon at the pirates at once
+    Broadside,
+    /// send a boarding party onto the nearest pirate ship
+    Board,
+    /// offer the pirates the gold they ask to leave
+    BribeGold,
+    /// offer the pirates a share of the cargo to leave
+    BribeCargo,
+    /// give up part of the hold to the pirates
+    Surrender,
+    /// throw part of the cargo overboard and try to outrun the pirates
+    Dump,
+    /// report the current state
+    Print,
+    /// write the ledger to the given CSV file
+    Ledger(PathBuf),
+}
+
+#[derive(Debug)]
+pub enum ScriptError {
+    Io(io::Error),
+    Line { line: usize, message: String },
+}
+
+impl From<io::Error> for ScriptError {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScriptError::Io(e) => write!(f, "{}", e),
+            ScriptError::Line { line, message } => write!(f, "line {}: {}", line, message),
+        }
+    }
+}
+
+fn parse_good(word: &str) -> Result<Good, String> {
+    Good::variants_iter()
+        .find(|good| good.to_string().eq_ignore_ascii_case(word))
+        .copied()
+        .ok_or_else(|| format!("unknown good '{}'", word))
+}
+
+fn parse_location(words: &[&str]) -> Result<Location, String> {
+    // allows for "cape town", "capetown" and "cape-town"
+    let normalize = |s: &str| {
+        s.chars()
+            .filter(|c| c.is_alphanumeric())
+            .collect::<String>()
+            .to_lowercase()
+    };
+    let name = normalize(&words.concat());
+    Location::variants()
+        .iter()
+        .find(|location| normalize(&location.to_string()) == name)
+        .copied()
+        .ok_or_else(|| format!("unknown location '{}'", words.join(" ")))
+}
+
+fn parse_ship_class(word: &str) -> Result<ShipClass, String> {
+    ShipClass::variants_iter()
+        .find(|class| class.to_string().eq_ignore_ascii_case(word))
+        .ok_or_else(|| format!("unknown ship '{}'", word))
+}
+
+fn parse_amount(word: &str) -> Result<Amount, String> {
+    if word.eq_ignore_ascii_case("max") || word.eq_ignore_ascii_case("all") {
+        Ok(Amount::Max)
+    } else {
+        word.parse()
+            .map(Amount::Exactly)
+            .map_err(|_| format!("invalid amount '{}'", word))
+    }
+}
+
+impl ScriptCommand {
+    /// parses one line of a script. blank lines and comments starting with `#` yield `None`.
+    pub fn parse(line: &str) -> Result<Option<ScriptCommand>, String> {
+        let line = line.split('#').next().unwrap_or_default();
+        let words = line.split_whitespace().collect::<Vec<&str>>();
+        let Some((command, args)) = words.split_first() else {
+            return Ok(None);
+        };
+        let command = command.to_lowercase();
+        let good_and_amount = |args: &[&str]| match args {
+            [good, amount] => Ok((parse_good(good)?, parse_amount(amount)?)),
+            _ => Err(format!("usage: {} <good> <amount>", command)),
+        };
+        let amount = |args: &[&str]| match args {
+            [amount] => parse_amount(amount),
+            _ => Err(format!("usage: {} <amount>", command)),
+        };
+        let no_args = |result: ScriptCommand| {
+            if args.is_empty() {
+                Ok(result)
+            } else {
+                Err(format!("{} takes no arguments", command))
+            }
+        };
+        let result = match command.as_str() {
+            "buy" => good_and_amount(args).map(|(good, amount)| ScriptCommand::Buy(good, amount)),
+            "sell" => good_and_amount(args).map(|(good, amount)| ScriptCommand::Sell(good, amount)),
+            "stash" => good_and_amount(args)
+                .map(|(good, amount)| ScriptCommand::StashDeposit(good, amount)),
+            "unstash" => good_and_amount(args)
+                .map(|(good, amount)| ScriptCommand::StashWithdraw(good, amount)),
+            "sail" => {
+                if args.is_empty() {
+                    Err("usage: sail <location>".to_owned())
+                } else {
+                    parse_location(args).map(ScriptCommand::Sail)
+                }
+            }
+            "deposit" => amount(args).map(ScriptCommand::BankDeposit),
+            "withdraw" => amount(args).map(ScriptCommand::BankWithdraw),
+            "pay" => amount(args).map(ScriptCommand::PayDebt),
+            "borrow" => amount(args).map(ScriptCommand::Borrow),
+            "hire" => amount(args).map(ScriptCommand::Hire),
+            "rent" => no_args(ScriptCommand::RentWarehouse),
+            "vacate" => no_args(ScriptCommand::GiveUpWarehouse),
+            "repair" => no_args(ScriptCommand::Repair),
+            "shipyard" => match args {
+                [class] => parse_ship_class(class).map(ScriptCommand::Shipyard),
+                _ => Err("usage: shipyard <class>".to_owned()),
+            },
+            "contract" => match args {
+                [number] => number
+                    .parse()
+                    .ok()
+                    .filter(|number| *number > 0)
+                    .map(ScriptCommand::AcceptContract)
+                    .ok_or_else(|| format!("invalid contract number '{}'", number)),
+                _ => Err("usage: contract <number>".to_owned()),
+            },
+            "accept" | "yes" => no_args(ScriptCommand::Accept),
+            "decline" | "no" => no_args(ScriptCommand::Decline),
+            "run" => no_args(ScriptCommand::Run),
+            "fight" => no_args(ScriptCommand::Fight),
+            "broadside" => no_args(ScriptCommand::Broadside),
+            "board" => no_args(ScriptCommand::Board),
+            "bribe" => match args {
+                [offer] if offer.eq_ignore_ascii_case("gold") => Ok(ScriptCommand::BribeGold),
+                [offer] if offer.eq_ignore_ascii_case("cargo") => Ok(ScriptCommand::BribeCargo),
+                _ => Err("usage: bribe gold|cargo".to_owned()),
+            },
+            "surrender" => no_args(ScriptCommand::Surrender),
+            "dump" => no_args(ScriptCommand::Dump),
+            "print" => no_args(ScriptCommand::Print),
+            "ledger" => {
+                if args.is_empty() {
+                    Err("usage: ledger <file>".to_owned())
+                } else {
+                    Ok(ScriptCommand::Ledger(PathBuf::from(args.join(" "))))
+                }
+            }
+            _ => Err(format!("unknown command '{}'", command)),
+        };
+        result.map(Some)
+    }
+}
+
+/// a plain language version of the errors the game state can return
+fn describe_state_error(error: StateError) -> String {
+    match error {
+        StateError::InvalidMode(mode) => format!("not possible right now ({:?})", mode),
+        StateError::CannotAfford => "not enough gold".to_owned(),
+        StateError::InsufficientHold => "not enough room in the hold".to_owned(),
+        StateError::InsufficientInventory => "not enough goods in the hold".to_owned(),
+        StateError::InsufficientStash => "not enough goods in the stash".to_owned(),
+        StateError::AlreadyInLocation => "already in that location".to_owned(),
+        StateError::LocationNotHomeBase(location) => {
+            format!("only possible at the home port, not {}", location)
+        }
+        StateError::PayDownAmountHigherThanDebt => "amount is higher than the debt".to_owned(),
+        StateError::BorrowAmountHigherThanLimit => {
+            "amount is more than the lender will lend".to_owned()
+        }
+        StateError::InsufficientBank => "not enough gold in the bank".to_owned(),
+        StateError::BankFrozen => "withdrawals from the bank are frozen".to_owned(),
+        StateError::NoWarehouse(location) => format!("no stash or warehouse in {}", location),
+        StateError::AlreadyHaveWarehouse => "there is already a stash or warehouse here".to_owned(),
+        StateError::WarehouseNotEmpty => "the warehouse still has goods in it".to_owned(),
+        StateError::TooManyContracts => "already taken on as many contracts as allowed".to_owned(),
+        StateError::NoSuchContract => "no contract with that number here".to_owned(),
+        StateError::HullNotDamaged => "the hull doesn't need mending".to_owned(),
+        StateError::NoShipyard(location) => format!("no shipyard in {}", location),
+        StateError::AlreadyHaveShip(class) => format!("already sailing a {}", class),
+        StateError::NoRoomForCannons => "the ship carries no more cannons".to_owned(),
+        StateError::NoBerths => "no berths for that many more sailors".to_owned(),
+    }
+}
+
+/// what customs officers found, and what they want for it
+fn describe_inspection(inspection: &CustomsInspection) -> String {
+    if !inspection.found_contraband() {
+        return "customs officers searched the hold and found nothing amiss".to_owned();
+    }
+    let found = inspection
+        .contraband
+        .iter()
+        .filter(|(_, amount)| **amount > 0)
+        .map(|(good, amount)| format!("{} {}", amount, good))
+        .collect::<Vec<String>>()
+        .join(", ");
+    format!(
+        "customs officers found {} forbidden here, to be seized with a fine of {} gold unless bribed with {} gold",
+        found, inspection.fine, inspection.bribe
+    )
+}
+
+/// what the player would see on screen for the given event
+fn describe_event(event: &LocationEvent, rules: &Rules) -> String {
+    match event {
+        LocationEvent::CheapGood(good) => format!("{} is unusually cheap here", good),
+        LocationEvent::ExpensiveGood(good) => format!("{} is unusually expensive here", good),
+        LocationEvent::FindGoods(good, amount) => format!("found {} {}", amount, good),
+        LocationEvent::GoodsStolen(None) => "thieves boarded the ship".to_owned(),
+        LocationEvent::GoodsStolen(Some(GoodsStolenResult::NothingStolen)) => {
+            "thieves boarded the ship but found nothing to steal".to_owned()
+        }
+        LocationEvent::GoodsStolen(Some(GoodsStolenResult::WasStolen { good, amount })) => {
+            format!("thieves stole {} {}", amount, good)
+        }
+        LocationEvent::CanBuyCannon => {
+            format!("offered a cannon for {} gold", rules.cannon_cost)
+        }
+        LocationEvent::CanBuyHoldSpace { price, more_hold } => {
+            format!("offered {} more hold space for {} gold", more_hold, price)
+        }
+        LocationEvent::DebtCollectors {
+            lender,
+            months_overdue,
+        } => format!(
+            "collectors came for the loan from {}, {} months overdue",
+            lender, months_overdue
+        ),
+        LocationEvent::BankRun { lost, frozen_until } => format!(
+            "a run on the bank lost {} gold, withdrawals frozen until {} {}",
+            lost,
+            frozen_until.1.name(),
+            frozen_until.0
+        ),
+        LocationEvent::Desertion { sailors } => format!("{} sailors deserted", sailors),
+        LocationEvent::Mutiny { sailors, gold } => format!(
+            "the crew mutinied, {} sailors leaving with {} gold",
+            sailors, gold
+        ),
+        LocationEvent::WarehouseLosses(losses) => losses
+            .iter()
+            .map(|loss| loss.to_string())
+            .collect::<Vec<String>>()
+            .join("; "),
+        LocationEvent::ContractsSettled(outcomes) => outcomes
+            .iter()
+            .map(|outcome| outcome.to_string())
+            .collect::<Vec<String>>()
+            .join("; "),
+        LocationEvent::CustomsInspection => "customs officers searched the hold".to_owned(),
+        LocationEvent::NoEffect(NoEffectEvent::SunnyDay) => "a sunny day".to_owned(),
+        LocationEvent::NoEffect(NoEffectEvent::StormOnHorizon) => {
+            "a storm on the horizon passed by".to_owned()
+        }
+        LocationEvent::PirateEncounter(pirate_encounter_state) => match pirate_encounter_state {
+            PirateEncounterState::Initial => "pirates spotted on the horizon".to_owned(),
+            PirateEncounterState::Prompt { info } => format!(
+                "{} of {} pirate ships{} remain, ship health {}",
+                info.cur_pirates,
+                info.total_pirates,
+                info.captain
+                    .map_or("".to_owned(), |captain| format!(" under {}", captain)),
+                info.health
+            ),
+            PirateEncounterState::AttackResult {
+                did_kill_a_pirate, ..
+            } => if *did_kill_a_pirate {
+                "sank a pirate ship"
+            } else {
+                "missed the pirates"
+            }
+            .to_owned(),
+            PirateEncounterState::RunSuccess => "got away from the pirates".to_owned(),
+            PirateEncounterState::RunFailure { .. } => "couldn't get away".to_owned(),
+            PirateEncounterState::PiratesAttack {
+                damage_this_attack, ..
+            } => format!("pirates hit the ship for {} damage", damage_this_attack),
+            PirateEncounterState::Destroyed => {
+                "the ship was overrun, losing all cargo and half the gold".to_owned()
+            }
+            PirateEncounterState::Victory {
+                gold_recovered,
+                bounty,
+            } => match bounty {
+                Some(Bounty { captain, gold }) => format!(
+                    "victory, recovered {} gold and a bounty of {} gold for {}",
+                    gold_recovered, gold, captain
+                ),
+                None => format!("victory, recovered {} gold", gold_recovered),
+            },
+            PirateEncounterState::BroadsideResult { sunk, .. } => {
+                format!("a broadside sank {} pirate ships", sunk)
+            }
+            PirateEncounterState::BoardResult { plunder, .. } => match plunder {
+                Some(plunder) => format!("boarded a pirate ship, taking {} gold", plunder),
+                None => "the boarding party was driven back".to_owned(),
+            },
+            PirateEncounterState::BribeResult {
+                offer, accepted, ..
+            } => format!(
+                "the pirates {} {}",
+                if *accepted { "took" } else { "refused" },
+                match offer {
+                    PirateBribe::Gold(gold) => format!("{} gold", gold),
+                    PirateBribe::Cargo(goods) => format!("{} goods", goods),
+                }
+            ),
+            PirateEncounterState::Surrendered { goods } => {
+                format!("surrendered {} goods to the pirates", goods)
+            }
+            PirateEncounterState::DumpResult {
+                dumped, escaped, ..
+            } => format!(
+                "dumped {} goods overboard, {}",
+                dumped,
+                if *escaped {
+                    "and got away"
+                } else {
+                    "but couldn't get away"
+                }
+            ),
+        },
+    }
+}
+
+/// a snapshot of the parts of the game state a script author cares about
+#[derive(Serialize)]
+pub struct Report<'a> {
+    pub year: u16,
+    pub month: &'static str,
+    pub location: Location,
+    pub home_port: Location,
+    pub difficulty: Difficulty,
+    pub gold: u32,
+    pub bank: u32,
+    pub debt: u32,
+    pub net_worth: i32,
+    pub ship_class: ShipClass,
+    pub hold_size: u32,
+    pub cannons: u8,
+    /// how much more damage the hull can take, out of `max_hull`
+    pub hull: u8,
+    pub max_hull: u8,
+    pub crew: u32,
+    /// the crew's morale, from 0 to 100
+    pub morale: u8,
+    pub inventory: &'a Inventory,
+    pub stash: &'a Inventory,
+    pub prices: &'a Inventory,
+    pub game_end: bool,
+    /// everything that happened since the previous report
+    pub events: &'a [String],
+}
+
+impl<'a> Report<'a> {
+    pub fn new(state: &'a GameState, events: &'a [String]) -> Self {
+        Report {
+            year: state.date.0,
+            month: state.date.1.name(),
+            location: state.location,
+            home_port: state.location_config.home_port,
+            difficulty: state.difficulty,
+            gold: state.gold.0,
+            bank: state.bank.0,
+            debt: state.debt(),
+            net_worth: state.net_worth(),
+            ship_class: state.ship_class,
+            hold_size: state.hold_size.0,
+            cannons: state.cannons.0,
+            hull: state.hull(),
+            max_hull: state.ship_class.info().hull,
+            crew: state.crew.sailors,
+            morale: state.crew.morale,
+            inventory: &state.inventory,
+            stash: &state.stash,
+            prices: &state.locations.location_info(&state.location).prices,
+            game_end: state.game_end,
+            events,
+        }
+    }
+}
+
+impl<'a> Display for Report<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} {}, {}", self.month, self.year, self.location)?;
+        writeln!(
+            f,
+            "Gold: {}  Bank: {}  Debt: {}  Net worth: {}",
+            self.gold, self.bank, self.debt, self.net_worth
+        )?;
+        writeln!(
+            f,
+            "Ship: {}  Hold: {}/{}  Cannons: {}  Hull: {}/{}  Crew: {} ({} morale)",
+            self.ship_class,
+            self.inventory.total_amount(),
+            self.hold_size,
+            self.cannons,
+            self.hull,
+            self.max_hull,
+            self.crew,
+            self.morale
+        )?;
+        writeln!(
+            f,
+            "{:<width$} {:>6} {:>6} {:>6}",
+            "Good",
+            "Hold",
+            "Stash",
+            "Price",
+            width = Good::longest_name()
+        )?;
+        for good in Good::variants_iter() {
+            writeln!(
+                f,
+                "{:<width$} {:>6} {:>6} {:>6}",
+                good.to_string(),
+                self.inventory.get_good(good),
+                self.stash.get_good(good),
+                self.prices.get_good(good),
+                width = Good::longest_name()
+            )?;
+        }
+        for event in self.events {
+            writeln!(f, "- {}", event)?;
+        }
+        if self.game_end {
+            writeln!(f, "The game has ended.")?;
+        }
+        Ok(())
+    }
+}
+
+/// plays a game without a terminal, by applying script commands to the same
+/// game state methods the engine uses for key presses
+pub struct ScriptRunner<'a, W: Write> {
+    state: &'a mut GameState,
+    format: OutputFormat,
+    out: W,
+    events: Vec<String>,
+}
+
+impl<'a, W: Write> ScriptRunner<'a, W> {
+    pub fn new(state: &'a mut GameState, format: OutputFormat, out: W) -> Self {
+        Self {
+            state,
+            format,
+            out,
+            events: vec![],
+        }
+    }
+
+    /// runs every command in the script, then reports the final state.
+    /// stops at the first command that can't be carried out.
+    pub fn run(&mut self, script: impl BufRead) -> Result<(), ScriptError> {
+        // skip past the splash and introduction screens
+        if self.state.initialization != Initialization::Game {
+            self.state.splash_to_introduction();
+            self.state.introduction_to_game();
+        }
+        let to_script_error = |line: usize| move |message| ScriptError::Line { line, message };
+        self.resolve_events().map_err(to_script_error(0))?;
+        for (index, line) in script.lines().enumerate() {
+            let line_number = index + 1;
+            let Some(command) =
+                ScriptCommand::parse(&line?).map_err(to_script_error(line_number))?
+            else {
+                continue;
+            };
+            self.apply(command).map_err(to_script_error(line_number))?;
+        }
+        self.report()?;
+        Ok(())
+    }
+
+    fn report(&mut self) -> io::Result<()> {
+        let report = Report::new(self.state, &self.events);
+        match self.format {
+            OutputFormat::Text => writeln!(self.out, "{}", report)?,
+            OutputFormat::Json => {
+                serde_json::to_writer(&mut self.out, &report)?;
+                writeln!(self.out)?;
+            }
+        }
+        self.events.clear();
+        Ok(())
+    }
+
+    fn log_event(&mut self, description: String) {
+        let (year, month) = self.state.date;
+        self.events
+            .push(format!("{} {}: {}", month.name(), year, description));
+    }
+
+    /// enters an amount as if it were typed digit by digit
+    fn type_amount(&mut self, amount: u32) -> Result<(), StateError> {
+        for digit in amount.to_string().chars().filter_map(|c| c.to_digit(10)) {
+            self.state.user_typed_digit(digit)?;
+        }
+        Ok(())
+    }
+
+    fn apply(&mut self, command: ScriptCommand) -> Result<(), String> {
+        if self.state.game_end {
+            return Err("the game has ended".to_owned());
+        }
+        let is_decision = matches!(
+            command,
+            ScriptCommand::Accept
+                | ScriptCommand::Decline
+                | ScriptCommand::Run
+                | ScriptCommand::Fight
+                | ScriptCommand::Broadside
+                | ScriptCommand::Board
+                | ScriptCommand::BribeGold
+                | ScriptCommand::BribeCargo
+                | ScriptCommand::Surrender
+                | ScriptCommand::Dump
+                | ScriptCommand::Print
+                | ScriptCommand::Ledger(_)
+        );
+        if let (Mode::GameEvent(event), false) = (&self.state.mode, is_decision) {
+            let options = match event {
+                LocationEvent::PirateEncounter(_) => {
+                    "run, fight, broadside, board, bribe, surrender or dump"
+                }
+                _ => "accept or decline",
+            };
+            return Err(format!(
+                "waiting on a decision ({}): {}",
+                options,
+                describe_event(event, &self.state.rules)
+            ));
+        }
+        if command == ScriptCommand::Print {
+            return self.report().map_err(|e| e.to_string());
+        }
+        if let ScriptCommand::Ledger(path) = &command {
+            return self
+                .state
+                .ledger
+                .export_csv(path)
+                .map_err(|e| format!("unable to export the ledger: {}", e));
+        }
+        if let Err(e) = self.apply_command(command) {
+            // back out of any half finished transaction, like a player pressing back would
+            if !matches!(self.state.mode, Mode::GameEvent(_)) {
+                self.state.mode = Mode::ViewingInventory;
+            }
+            return Err(describe_state_error(e));
+        }
+        self.resolve_events()
+    }
+
+    fn apply_command(&mut self, command: ScriptCommand) -> Result<(), StateError> {
+        let state = &mut *self.state;
+        match command {
+            ScriptCommand::Buy(good, amount) => {
+                let amount = match amount {
+                    Amount::Exactly(amount) => amount,
+                    Amount::Max => state.can_afford(&good).min(state.remaining_hold()),
+                };
+                state.begin_buying()?.choose_buy_good(good)?;
+                self.type_amount(amount)?;
+                self.state.commit_buy()?;
+            }
+            ScriptCommand::Sell(good, amount) => {
+                let amount = match amount {
+                    Amount::Exactly(amount) => amount,
+                    Amount::Max => *state.inventory.get_good(&good),
+                };
+                state.begin_selling()?.choose_sell_good(good)?;
+                self.type_amount(amount)?;
+                self.state.commit_sell()?;
+            }
+            ScriptCommand::StashDeposit(good, amount) => {
+                let amount = match amount {
+                    Amount::Exactly(amount) => amount,
+                    Amount::Max => *state.inventory.get_good(&good),
+                };
+                state
+                    .begin_stash_deposit()?
+                    .choose_stash_deposit_good(good)?;
+                self.type_amount(amount)?;
+                self.state.commit_stash_deposit()?;
+            }
+            ScriptCommand::StashWithdraw(good, amount) => {
+                let amount = match amount {
+                    Amount::Exactly(amount) => amount,
+                    Amount::Max => state.stash_here().map_or(0, |stash| *stash.get_good(&good)),
+                };
+                state
+                    .begin_stash_withdraw()?
+                    .choose_stash_withdraw_good(good)?;
+                self.type_amount(amount)?;
+                self.state.commit_stash_withdraw()?;
+            }
+            ScriptCommand::BankDeposit(amount) => {
+                let amount = match amount {
+                    Amount::Exactly(amount) => amount,
+                    Amount::Max => state.gold.0,
+                };
+                state.begin_bank_deposit()?;
+                self.type_amount(amount)?;
+                self.state.commit_bank_deposit()?;
+            }
+            ScriptCommand::BankWithdraw(amount) => {
+                let amount = match amount {
+                    Amount::Exactly(amount) => amount,
+                    Amount::Max => state.bank.0,
+                };
+                state.begin_bank_withdraw()?;
+                self.type_amount(amount)?;
+                self.state.commit_bank_withdraw()?;
+            }
+            ScriptCommand::PayDebt(amount) => {
+                let amount = match amount {
+                    Amount::Exactly(amount) => amount,
+                    Amount::Max => state.gold.0.min(state.debt()),
+                };
+                state.begin_pay_debt()?;
+                self.type_amount(amount)?;
+                self.state.commit_pay_debt()?;
+            }
+            ScriptCommand::Borrow(amount) => {
+                let amount = match amount {
+                    Amount::Exactly(amount) => amount,
+                    Amount::Max => state
+                        .loans
+                        .get(&state.location)
+                        .available(&state.lender(&state.location)),
+                };
+                state.begin_borrow()?;
+                self.type_amount(amount)?;
+                self.state.commit_borrow()?;
+            }
+            ScriptCommand::Hire(amount) => {
+                let amount = match amount {
+                    Amount::Exactly(amount) => amount,
+                    Amount::Max => state
+                        .free_berths()
+                        .min(state.gold.0 / state.rules.hiring_fee.max(1)),
+                };
+                state.begin_hiring()?;
+                self.type_amount(amount)?;
+                self.state.commit_hiring()?;
+            }
+            ScriptCommand::RentWarehouse => {
+                state.rent_warehouse()?;
+            }
+            ScriptCommand::GiveUpWarehouse => {
+                state.give_up_warehouse()?;
+            }
+            ScriptCommand::Repair => {
+                state.repair_hull()?;
+            }
+            ScriptCommand::Shipyard(class) => {
+                let result = state.open_shipyard()?.trade_in_ship(class).map(|_| ());
+                self.state.back()?;
+                result?;
+            }
+            ScriptCommand::AcceptContract(number) => {
+                let result = state
+                    .open_contracts()?
+                    .accept_contract(number - 1)
+                    .map(|_| ());
+                self.state.back()?;
+                result?;
+            }
+            ScriptCommand::Sail(destination) => {
+                state.begin_sailing()?.sail_to(&destination)?;
+                self.log_event(format!("arrived in {}", destination));
+            }
+            ScriptCommand::Accept => match state.mode {
+                Mode::GameEvent(LocationEvent::CanBuyCannon) => {
+                    state.confirm_buy_cannon()?;
+                    // the offer is still open if it couldn't be afforded
+                    if state.mode == Mode::GameEvent(LocationEvent::CanBuyCannon) {
+                        return Err(StateError::CannotAfford);
+                    }
+                }
+                Mode::GameEvent(LocationEvent::CanBuyHoldSpace { price, more_hold }) => {
+                    state.confirm_buy_hold_space(price, more_hold)?;
+                    if state.mode
+                        == Mode::GameEvent(LocationEvent::CanBuyHoldSpace { price, more_hold })
+                    {
+                        return Err(StateError::CannotAfford);
+                    }
+                }
+                Mode::GameEvent(LocationEvent::CustomsInspection) => {
+                    state.bribe_customs()?;
+                    if state.mode == Mode::GameEvent(LocationEvent::CustomsInspection) {
+                        return Err(StateError::CannotAfford);
+                    }
+                }
+                _ => return Err(StateError::InvalidMode(state.mode.clone())),
+            },
+            ScriptCommand::Decline => match state.mode {
+                Mode::GameEvent(LocationEvent::CanBuyCannon)
+                | Mode::GameEvent(LocationEvent::CanBuyHoldSpace { .. }) => {
+                    state.acknowledge_event()?;
+                }
+                Mode::GameEvent(LocationEvent::CustomsInspection) => {
+                    state.submit_to_customs()?;
+                }
+                _ => return Err(StateError::InvalidMode(state.mode.clone())),
+            },
+            ScriptCommand::Run => state.pirate_run()?,
+            ScriptCommand::Fight => state.pirate_fight()?,
+            ScriptCommand::Broadside => state.pirate_broadside()?,
+            ScriptCommand::Board => state.pirate_board()?,
+            ScriptCommand::BribeGold => state.pirate_bribe_gold()?,
+            ScriptCommand::BribeCargo => state.pirate_bribe_cargo()?,
+            ScriptCommand::Surrender => state.pirate_surrender()?,
+            ScriptCommand::Dump => state.pirate_dump_cargo()?,
+            // reporting doesn't touch the game state, see [ScriptRunner::apply]
+            ScriptCommand::Print | ScriptCommand::Ledger(_) => {}
+        }
+        Ok(())
+    }
+
+    /// steps through any events that don't need a decision from the player,
+    /// the same way pressing any key would in the game
+    fn resolve_events(&mut self) -> Result<(), String> {
+        loop {
+            let Mode::GameEvent(event) = &self.state.mode else {
+                return Ok(());
+            };
+            let event = event.clone();
+            if let LocationEvent::GoodsStolen(None) = event {
+                // decide what gets stolen before describing it
+                let stolen = self.state.compute_goods_stolen();
+                self.log_event(describe_event(
+                    &LocationEvent::GoodsStolen(Some(stolen)),
+                    &self.state.rules,
+                ));
+            } else if let LocationEvent::CustomsInspection = event {
+                // what the officers find decides what happens next
+                self.log_event(describe_inspection(&self.state.customs_inspection()));
+            } else {
+                self.log_event(describe_event(&event, &self.state.rules));
+            }
+            let result = match event {
+                LocationEvent::CanBuyCannon | LocationEvent::CanBuyHoldSpace { .. } => {
+                    return Ok(());
+                }
+                LocationEvent::PirateEncounter(pirate_encounter_state) => {
+                    match pirate_encounter_state {
+                        PirateEncounterState::Prompt { .. } => return Ok(()),
+                        PirateEncounterState::Initial => self.state.proceed_pirate_encounter(),
+                        PirateEncounterState::AttackResult { .. } => {
+                            self.state.proceed_attack_result()
+                        }
+                        PirateEncounterState::RunSuccess => self.state.proceed_pirate_run_success(),
+                        PirateEncounterState::RunFailure { .. } => {
+                            self.state.proceed_pirate_run_failure()
+                        }
+                        PirateEncounterState::PiratesAttack { .. } => {
+                            self.state.proceed_pirates_attack()
+                        }
+                        PirateEncounterState::Destroyed => self.state.proceed_destroyed(),
+                        PirateEncounterState::Victory { .. } => {
+                            self.state.proceed_pirate_encounter_victory()
+                        }
+                        PirateEncounterState::BroadsideResult { .. } => {
+                            self.state.proceed_broadside_result()
+                        }
+                        PirateEncounterState::BoardResult { .. } => {
+                            self.state.proceed_board_result()
+                        }
+                        PirateEncounterState::BribeResult { .. } => {
+                            self.state.proceed_bribe_result()
+                        }
+                        PirateEncounterState::Surrendered { .. } => {
+                            self.state.proceed_surrendered()
+                        }
+                        PirateEncounterState::DumpResult { .. } => self.state.proceed_dump_result(),
+                    }
+                }
+                LocationEvent::CustomsInspection => {
+                    if self.state.customs_inspection().found_contraband() {
+                        return Ok(());
+                    }
+                    self.state.acknowledge_event().map(|_| ())
+                }
+                LocationEvent::FindGoods(_, _) => self.state.take_found_goods(),
+                LocationEvent::DebtCollectors { .. } => self.state.pay_debt_collectors(),
+                LocationEvent::GoodsStolen(_) => {
+                    let stolen = self.state.compute_goods_stolen();
+                    self.state.remove_stolen_goods(stolen);
+                    self.state.acknowledge_event().map(|_| ())
+                }
+                LocationEvent::CheapGood(_)
+                | LocationEvent::ExpensiveGood(_)
+                | LocationEvent::BankRun { .. }
+                | LocationEvent::Desertion { .. }
+                | LocationEvent::Mutiny { .. }
+                | LocationEvent::WarehouseLosses(_)
+                | LocationEvent::ContractsSettled(_)
+                | LocationEvent::NoEffect(_) => self.state.acknowledge_event().map(|_| ()),
+            };
+            result.map_err(describe_state_error)?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::Saturating;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        state::{LedgerEvent, LocationInfo, PirateCaptain, WarehouseMishap},
+        test::rng::{default_location_info, MockRng},
+    };
+
+    use super::*;
+
+    fn run(state: &mut GameState, script: &str) -> Result<String, ScriptError> {
+        let mut out = vec![];
+        ScriptRunner::new(state, OutputFormat::Text, &mut out).run(script.as_bytes())?;
+        Ok(String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            ScriptCommand::parse("buy Tea 10"),
+            Ok(Some(ScriptCommand::Buy(Good::Tea, Amount::Exactly(10))))
+        );
+        assert_eq!(
+            ScriptCommand::parse("  sell rum all  # everything"),
+            Ok(Some(ScriptCommand::Sell(Good::Rum, Amount::Max)))
+        );
+        assert_eq!(
+            ScriptCommand::parse("sail cape town"),
+            Ok(Some(ScriptCommand::Sail(Location::CapeTown)))
+        );
+        assert_eq!(
+            ScriptCommand::parse("deposit 500"),
+            Ok(Some(ScriptCommand::BankDeposit(Amount::Exactly(500))))
+        );
+        assert_eq!(
+            ScriptCommand::parse("borrow max"),
+            Ok(Some(ScriptCommand::Borrow(Amount::Max)))
+        );
+        assert_eq!(
+            ScriptCommand::parse("hire 5"),
+            Ok(Some(ScriptCommand::Hire(Amount::Exactly(5))))
+        );
+        assert_eq!(
+            ScriptCommand::parse("rent"),
+            Ok(Some(ScriptCommand::RentWarehouse))
+        );
+        assert_eq!(
+            ScriptCommand::parse("bribe Gold"),
+            Ok(Some(ScriptCommand::BribeGold))
+        );
+        assert_eq!(
+            ScriptCommand::parse("bribe rum"),
+            Err("usage: bribe gold|cargo".to_owned())
+        );
+        assert_eq!(
+            ScriptCommand::parse("repair"),
+            Ok(Some(ScriptCommand::Repair))
+        );
+        assert_eq!(
+            ScriptCommand::parse("shipyard Frigate"),
+            Ok(Some(ScriptCommand::Shipyard(ShipClass::Frigate)))
+        );
+        assert_eq!(
+            ScriptCommand::parse("shipyard galleon"),
+            Err("unknown ship 'galleon'".to_owned())
+        );
+        assert_eq!(
+            ScriptCommand::parse("contract 2"),
+            Ok(Some(ScriptCommand::AcceptContract(2)))
+        );
+        assert_eq!(
+            ScriptCommand::parse("contract 0"),
+            Err("invalid contract number '0'".to_owned())
+        );
+        assert_eq!(
+            ScriptCommand::parse("ledger my ledger.csv"),
+            Ok(Some(ScriptCommand::Ledger(PathBuf::from("my ledger.csv"))))
+        );
+        assert_eq!(ScriptCommand::parse("# just a comment"), Ok(None));
+        assert_eq!(ScriptCommand::parse(""), Ok(None));
+        assert_eq!(
+            ScriptCommand::parse("buy gold 10"),
+            Err("unknown good 'gold'".to_owned())
+        );
+        assert_eq!(
+            ScriptCommand::parse("buy tea"),
+            Err("usage: buy <good> <amount>".to_owned())
+        );
+        assert_eq!(
+            ScriptCommand::parse("fight harder"),
+            Err("fight takes no arguments".to_owned())
+        );
+        assert_eq!(
+            ScriptCommand::parse("dance"),
+            Err("unknown command 'dance'".to_owned())
+        );
+    }
+
+    #[test]
+    fn trade_and_bank() -> Result<(), ScriptError> {
+        let mut state = GameState::new(MockRng::new_with_default_locations().into());
+        let output = run(
+            &mut state,
+            r"
+            buy tea 10
+            buy rum max
+            sell rum 45
+            stash tea 4
+            deposit 100
+            pay max
+            print
+            ",
+        )?;
+        assert_eq!(state.gold, Saturating(0));
+        assert_eq!(state.bank, Saturating(100));
+        assert_eq!(state.debt(), 1250);
+        assert_eq!(state.inventory[Good::Tea], 6);
+        assert_eq!(state.inventory[Good::Rum], 45);
+        assert_eq!(state.stash[Good::Tea], 4);
+        assert_eq!(
+            output,
+            r"March 1782, London
+Gold: 0  Bank: 100  Debt: 1250  Net worth: 3901178
+Ship: Sloop  Hold: 51/100  Cannons: 1  Hull: 5/5  Crew: 12 (75 morale)
+Good        Hold  Stash  Price
+Tea            6      4      6
+Coffee         0      0      5
+Sugar          0      0      4
+Tobacco        0      0      3
+Rum           45      0      2
+Cotton         0      0      1
+Spices         0      0      5
+Silk           0      0      6
+Indigo         0      0      2
+Porcelain      0      0      7
+
+March 1782, London
+Gold: 0  Bank: 100  Debt: 1250  Net worth: 3901178
+Ship: Sloop  Hold: 51/100  Cannons: 1  Hull: 5/5  Crew: 12 (75 morale)
+Good        Hold  Stash  Price
+Tea            6      4      6
+Coffee         0      0      5
+Sugar          0      0      4
+Tobacco        0      0      3
+Rum           45      0      2
+Cotton         0      0      1
+Spices         0      0      5
+Silk           0      0      6
+Indigo         0      0      2
+Porcelain      0      0      7
+
+"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn events_and_decisions() -> Result<(), ScriptError> {
+        let mut state = GameState::new(
+            MockRng::new_with_default_locations()
+                .push_location_info(LocationInfo {
+                    event: Some(LocationEvent::CanBuyCannon),
+                    ..default_location_info()
+                })
+                .push_location_info(LocationInfo {
+                    event: Some(LocationEvent::PirateEncounter(
+                        PirateEncounterState::Initial,
+                    )),
+                    ..default_location_info()
+                })
+                .push_pirate_captain(None)
+                .push_num_pirates_encountered(1)
+                .push_did_kill_a_pirate(true)
+                .push_gold_recovered_from_pirate_encounter(300)
+                .into(),
+        );
+        // enough for the cannon and two months of wages
+        state.gold = Saturating(5072);
+        let mut out = vec![];
+        ScriptRunner::new(&mut state, OutputFormat::Json, &mut out).run(
+            r"
+            sail venice
+            accept
+            sail lisbon
+            fight
+            "
+            .as_bytes(),
+        )?;
+        assert_eq!(state.cannons, Saturating(2));
+        assert_eq!(state.gold, Saturating(300));
+        assert_eq!(state.mode, Mode::ViewingInventory);
+        assert_eq!(
+            state
+                .ledger
+                .entries
+                .iter()
+                .map(|entry| (entry.location, entry.event, entry.gold))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    Location::Venice,
+                    LedgerEvent::Wages {
+                        paid: 36,
+                        unpaid: 0
+                    },
+                    5036
+                ),
+                (
+                    Location::Venice,
+                    LedgerEvent::BoughtCannon { price: 5000 },
+                    36
+                ),
+                (
+                    Location::Lisbon,
+                    LedgerEvent::Wages {
+                        paid: 36,
+                        unpaid: 0
+                    },
+                    0
+                ),
+                (
+                    Location::Lisbon,
+                    LedgerEvent::DefeatedPirates {
+                        gold_recovered: 300
+                    },
+                    300
+                ),
+            ]
+        );
+        let report: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(
+            report["events"],
+            serde_json::json!([
+                "April 1782: arrived in Venice",
+                "April 1782: offered a cannon for 5000 gold",
+                "May 1782: arrived in Lisbon",
+                "May 1782: pirates spotted on the horizon",
+                "May 1782: 1 of 1 pirate ships remain, ship health 5",
+                "May 1782: sank a pirate ship",
+                "May 1782: victory, recovered 300 gold",
+            ])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn pirate_tactics() -> Result<(), ScriptError> {
+        let mut state = GameState::new(
+            MockRng::new_with_default_locations()
+                .push_location_info(LocationInfo {
+                    event: Some(LocationEvent::PirateEncounter(
+                        PirateEncounterState::Initial,
+                    )),
+                    ..default_location_info()
+                })
+                .push_pirate_captain(None)
+                .push_num_pirates_encountered(2)
+                .push_cargo_bribe_accepted(false)
+                .push_damage_from_pirates(1)
+                .push_run_success_after_dumping(false)
+                .push_damage_from_pirates(0)
+                .push_boarding_success(true)
+                .push_gold_recovered_from_pirate_encounter(700)
+                .push_damage_from_pirates(1)
+                .push_ships_sunk_by_broadside(1)
+                .push_gold_recovered_from_pirate_encounter(300)
+                .into(),
+        );
+        state.inventory.add_good(&Good::Tea, 40);
+        let mut out = vec![];
+        ScriptRunner::new(&mut state, OutputFormat::Json, &mut out).run(
+            r"
+            sail venice
+            bribe cargo
+            dump
+            board
+            broadside
+            "
+            .as_bytes(),
+        )?;
+        // half the tea went overboard, and none to the pirates
+        assert_eq!(*state.inventory.get_good(&Good::Tea), 20);
+        assert_eq!(state.gold, Saturating(1464));
+        assert_eq!(state.hull(), 3);
+        assert_eq!(state.mode, Mode::ViewingInventory);
+        let report: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(
+            report["events"],
+            serde_json::json!([
+                "April 1782: arrived in Venice",
+                "April 1782: pirates spotted on the horizon",
+                "April 1782: 2 of 2 pirate ships remain, ship health 5",
+                "April 1782: the pirates refused 10 goods",
+                "April 1782: pirates hit the ship for 1 damage",
+                "April 1782: 2 of 2 pirate ships remain, ship health 4",
+                "April 1782: dumped 20 goods overboard, but couldn't get away",
+                "April 1782: pirates hit the ship for 0 damage",
+                "April 1782: 2 of 2 pirate ships remain, ship health 4",
+                "April 1782: boarded a pirate ship, taking 700 gold",
+                "April 1782: pirates hit the ship for 1 damage",
+                "April 1782: 1 of 2 pirate ships remain, ship health 3",
+                "April 1782: a broadside sank 1 pirate ships",
+                "April 1782: victory, recovered 300 gold",
+            ])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn pirate_captains() -> Result<(), ScriptError> {
+        let encounter = LocationInfo {
+            event: Some(LocationEvent::PirateEncounter(
+                PirateEncounterState::Initial,
+            )),
+            ..default_location_info()
+        };
+        let mut state = GameState::new(
+            MockRng::new_with_default_locations()
+                .push_location_infos(&[encounter.clone(), encounter])
+                .push_pirate_captain(Some(PirateCaptain::GentlemanFlint))
+                .push_num_pirates_encountered(1)
+                .push_run_success(true)
+                .push_pirate_captain(Some(PirateCaptain::GentlemanFlint))
+                .push_num_pirates_encountered(1)
+                .push_did_kill_a_pirate(true)
+                .push_gold_recovered_from_pirate_encounter(300)
+                .into(),
+        );
+        let mut out = vec![];
+        ScriptRunner::new(&mut state, OutputFormat::Json, &mut out).run(
+            r"
+            sail venice
+            run
+            sail lisbon
+            fight
+            "
+            .as_bytes(),
+        )?;
+        // running from the captain once added to the bounty
+        let record = state.pirates.get(PirateCaptain::GentlemanFlint);
+        assert_eq!(record.encounters, 2);
+        assert_eq!(record.runs, 1);
+        assert!(record.sunk);
+        assert_eq!(
+            state.ledger.entries.last().map(|entry| entry.event),
+            Some(LedgerEvent::PirateBounty {
+                captain: PirateCaptain::GentlemanFlint,
+                gold: 1150
+            })
+        );
+        let report: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(
+            report["events"],
+            serde_json::json!([
+                "April 1782: arrived in Venice",
+                "April 1782: pirates spotted on the horizon",
+                "April 1782: 1 of 1 pirate ships under Gentleman Flint remain, ship health 5",
+                "April 1782: got away from the pirates",
+                "May 1782: arrived in Lisbon",
+                "May 1782: pirates spotted on the horizon",
+                "May 1782: 1 of 1 pirate ships under Gentleman Flint remain, ship health 5",
+                "May 1782: sank a pirate ship",
+                "May 1782: victory, recovered 300 gold and a bounty of 1150 gold for Gentleman Flint",
+            ])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn customs() -> Result<(), ScriptError> {
+        let inspection = LocationInfo {
+            event: Some(LocationEvent::CustomsInspection),
+            ..default_location_info()
+        };
+        let mut state = GameState::new(
+            MockRng::new_with_default_locations()
+                .push_location_infos(&[inspection.clone(), inspection])
+                .into(),
+        );
+        state.inventory[Good::Tobacco] = 10;
+        for location in [Location::Venice, Location::Lisbon] {
+            state.location_config.personalities[location]
+                .customs
+                .contraband = vec![Good::Tobacco];
+        }
+        let mut out = vec![];
+        ScriptRunner::new(&mut state, OutputFormat::Json, &mut out).run(
+            r"
+            sail venice
+            accept
+            sail lisbon
+            decline
+            "
+            .as_bytes(),
+        )?;
+        // less two months of wages
+        assert_eq!(state.gold, Saturating(404));
+        assert_eq!(state.inventory[Good::Tobacco], 0);
+        let report: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(
+            report["events"],
+            serde_json::json!([
+                "April 1782: arrived in Venice",
+                "April 1782: customs officers found 10 Tobacco forbidden here, to be seized with a fine of 15 gold unless bribed with 9 gold",
+                "May 1782: arrived in Lisbon",
+                "May 1782: customs officers found 10 Tobacco forbidden here, to be seized with a fine of 15 gold unless bribed with 9 gold",
+            ])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn warehouses() -> Result<(), ScriptError> {
+        let mut state = GameState::new(
+            MockRng::new_with_default_locations()
+                .push_location_infos(&[
+                    default_location_info(),
+                    default_location_info(),
+                    default_location_info(),
+                ])
+                .push_warehouse_mishap(Some((WarehouseMishap::Theft, Good::Rum, 5)))
+                .into(),
+        );
+        // with three months of wages to pay along the way
+        state.gold = Saturating(408);
+        state.inventory[Good::Rum] = 20;
+        let mut out = vec![];
+        ScriptRunner::new(&mut state, OutputFormat::Json, &mut out).run(
+            r"
+            sail venice
+            rent
+            stash rum max
+            sail lisbon
+            buy tea 10
+            sail london
+            "
+            .as_bytes(),
+        )?;
+        assert_eq!(state.gold, Saturating(40));
+        assert_eq!(state.warehouses[Location::Venice], None);
+        assert_eq!(state.inventory[Good::Rum], 0);
+        let report: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(
+            report["events"],
+            serde_json::json!([
+                "April 1782: arrived in Venice",
+                "May 1782: arrived in Lisbon",
+                "May 1782: Venice: thieves took 5 Rum",
+                "June 1782: arrived in London",
+                "June 1782: Venice: evicted for unpaid rent",
+            ])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn repair() -> Result<(), ScriptError> {
+        let mut state = GameState::new(MockRng::new_with_default_locations().into());
+        state.hull_damage = 3;
+        let mut out = vec![];
+        ScriptRunner::new(&mut state, OutputFormat::Json, &mut out).run("repair".as_bytes())?;
+        // only as much as can be afforded
+        assert_eq!(state.gold, Saturating(100));
+        assert_eq!(state.hull(), 4);
+        let report: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(report["hull"], 4);
+        assert_eq!(report["max_hull"], 5);
+        state.gold = Saturating(1000);
+        let error = ScriptRunner::new(&mut state, OutputFormat::Json, &mut vec![])
+            .run("repair\nrepair".as_bytes())
+            .unwrap_err();
+        assert_eq!(error.to_string(), "line 2: the hull doesn't need mending");
+        Ok(())
+    }
+
+    #[test]
+    fn hire() -> Result<(), ScriptError> {
+        let mut state = GameState::new(MockRng::new_with_default_locations().into());
+        state.crew.sailors = 4;
+        state.gold = Saturating(100);
+        let mut out = vec![];
+        ScriptRunner::new(&mut state, OutputFormat::Json, &mut out).run("hire max".as_bytes())?;
+        // only as many as can be afforded
+        assert_eq!(state.gold, Saturating(0));
+        assert_eq!(state.crew.sailors, 9);
+        let report: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(report["crew"], 9);
+        assert_eq!(report["morale"], 75);
+        state.gold = Saturating(1000);
+        let error = ScriptRunner::new(&mut state, OutputFormat::Json, &mut vec![])
+            .run("hire 3\nhire 1".as_bytes())
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2: no berths for that many more sailors"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn shipyard() -> Result<(), ScriptError> {
+        let mut state = GameState::new(
+            MockRng::new_with_default_locations()
+                .push_location_info(default_location_info())
+                .into(),
+        );
+        let error = ScriptRunner::new(&mut state, OutputFormat::Json, &mut vec![])
+            .run("shipyard brig".as_bytes())
+            .unwrap_err();
+        assert_eq!(error.to_string(), "line 1: no shipyard in London");
+        state.location = Location::Amsterdam;
+        state.gold = Saturating(10000);
+        let mut out = vec![];
+        ScriptRunner::new(&mut state, OutputFormat::Json, &mut out)
+            .run("shipyard brig".as_bytes())?;
+        // the sloop is traded in for half of what a new one costs
+        assert_eq!(state.gold, Saturating(2500));
+        assert_eq!(state.mode, Mode::ViewingInventory);
+        let report: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(report["ship_class"], "Brig");
+        assert_eq!(report["hold_size"], 250);
+        let error = ScriptRunner::new(&mut state, OutputFormat::Json, &mut vec![])
+            .run("shipyard brig".as_bytes())
+            .unwrap_err();
+        assert_eq!(error.to_string(), "line 1: already sailing a Brig");
+        // upkeep the gold can't cover is owed at home
+        state.gold = Saturating(60);
+        run(&mut state, "sail venice")?;
+        assert_eq!(state.gold, Saturating(0));
+        assert_eq!(
+            state
+                .ledger
+                .entries
+                .iter()
+                .rev()
+                .find(|entry| matches!(entry.event, LedgerEvent::Upkeep { .. }))
+                .map(|entry| &entry.event),
+            Some(&LedgerEvent::Upkeep {
+                paid: 60,
+                borrowed: 20
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn stops_at_first_failure() {
+        let mut state = GameState::new(
+            MockRng::new_with_default_locations()
+                .push_location_info(LocationInfo {
+                    event: Some(LocationEvent::CanBuyCannon),
+                    ..default_location_info()
+                })
+                .into(),
+        );
+        let error = run(&mut state, "buy tea 1000").unwrap_err();
+        assert_eq!(error.to_string(), "line 1: not enough gold");
+        let error = run(&mut state, "sail venice\n\nbuy tea 1").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3: waiting on a decision (accept or decline): offered a cannon for 5000 gold"
+        );
+        let error = run(&mut state, "accept").unwrap_err();
+        assert_eq!(error.to_string(), "line 1: not enough gold");
+    }
+}
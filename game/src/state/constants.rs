@@ -1,2 +0,0 @@
-pub const GOLD_PER_PIRATE_VICTORY_MIN: u32 = 500;
-pub const GOLD_PER_PIRATE_VICTORY_MAX: u32 = 2000;
@@ -0,0 +1,135 @@
+use std::fmt::{self, Display};
+
+use chrono::Month;
+use serde::{Deserialize, Serialize};
+
+use super::{add_months, months_between, Good, Location};
+
+/// What a port's merchants offer for deliveries.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ContractTerms {
+    /// how many contracts are on the port's board at a time
+    pub offers: u8,
+    /// the least and most of a good a contract asks for
+    pub amount: (u32, u32),
+    /// the fewest and most months there are to make a delivery
+    pub months: (u16, u16),
+    /// how much more than the good's usual price the merchants pay, eg. 1.5 is 50% more
+    pub premium: f64,
+}
+
+/// A delivery on offer on a port's contract board.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContractOffer {
+    pub good: Good,
+    pub amount: u32,
+    pub destination: Location,
+    /// how many months there are to make the delivery, once accepted
+    pub months: u16,
+    pub reward: u32,
+}
+
+impl ContractOffer {
+    pub fn accept(&self, issuer: Location, date: (u16, Month)) -> Contract {
+        Contract {
+            good: self.good,
+            amount: self.amount,
+            destination: self.destination,
+            issuer,
+            due: add_months(date, self.months),
+            reward: self.reward,
+        }
+    }
+}
+
+/// A delivery the player has agreed to make.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Contract {
+    pub good: Good,
+    pub amount: u32,
+    pub destination: Location,
+    /// the port whose merchants offered the contract
+    pub issuer: Location,
+    /// the last month the delivery can be made in
+    pub due: (u16, Month),
+    pub reward: u32,
+}
+
+impl Contract {
+    pub fn is_overdue(&self, date: (u16, Month)) -> bool {
+        months_between(self.due, date) > 0
+    }
+}
+
+impl Display for Contract {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} to {} by {} {}",
+            self.amount,
+            self.good,
+            self.destination,
+            &self.due.1.name()[..3],
+            self.due.0
+        )
+    }
+}
+
+/// What became of a contract on arriving in a port.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ContractOutcome {
+    Delivered(Contract),
+    /// the deadline passed, and the penalty was paid
+    Missed {
+        contract: Contract,
+        penalty: u32,
+    },
+}
+
+impl Display for ContractOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContractOutcome::Delivered(contract) => write!(
+                f,
+                "Delivered {} {} for {} gold",
+                contract.amount, contract.good, contract.reward
+            ),
+            ContractOutcome::Missed { contract, penalty } => write!(
+                f,
+                "Missed {} {} to {}, paid {} gold",
+                contract.amount, contract.good, contract.destination, penalty
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn accept() {
+        let offer = ContractOffer {
+            good: Good::Sugar,
+            amount: 40,
+            destination: Location::Venice,
+            months: 8,
+            reward: 9000,
+        };
+        let contract = offer.accept(Location::London, (1782, Month::March));
+        assert_eq!(contract.due, (1782, Month::November));
+        assert_eq!(contract.to_string(), "40 Sugar to Venice by Nov 1782");
+        assert!(!contract.is_overdue((1782, Month::November)));
+        assert!(contract.is_overdue((1782, Month::December)));
+        assert_eq!(
+            ContractOutcome::Missed {
+                contract,
+                penalty: 2250
+            }
+            .to_string(),
+            "Missed 40 Sugar to Venice, paid 2250 gold"
+        );
+    }
+}
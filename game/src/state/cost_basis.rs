@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+
+use super::{goods_map::GoodsMap, Good, Inventory};
+
+/// What the player paid in total for each good in an [Inventory],
+/// so that the average cost of a good can be compared against its price.
+/// Goods that were found rather than bought cost nothing.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CostBasis(GoodsMap<u32>);
+
+impl CostBasis {
+    pub fn total(&self, good: &Good) -> u32 {
+        *self.0.get_good(good)
+    }
+
+    pub fn add(&mut self, good: &Good, cost: u32) {
+        let total = self.0.get_good_mut(good);
+        *total = total.saturating_add(cost);
+    }
+
+    /// the share of the total cost of a good that `amount` of the `held` goods account for
+    pub fn cost_of(&self, good: &Good, amount: u32, held: u32) -> u32 {
+        if held == 0 {
+            return 0;
+        }
+        let amount = amount.min(held);
+        (self.total(good) as u64 * amount as u64 / held as u64) as u32
+    }
+
+    /// removes `amount` of the `held` goods, returning what they cost.
+    /// the average cost of the goods left behind is unchanged.
+    pub fn remove(&mut self, good: &Good, amount: u32, held: u32) -> u32 {
+        let cost = self.cost_of(good, amount, held);
+        *self.0.get_good_mut(good) -= cost;
+        cost
+    }
+
+    /// what each of the `held` goods cost on average, if there are any
+    pub fn average(&self, good: &Good, held: u32) -> Option<u32> {
+        (held > 0).then(|| ((self.total(good) as u64 + held as u64 / 2) / held as u64) as u32)
+    }
+
+    /// how much more (or less) the goods in the inventory would sell for at the given prices
+    /// than they cost
+    pub fn unrealized_gain(&self, good: &Good, inventory: &Inventory, prices: &Inventory) -> i64 {
+        let held = *inventory.get_good(good);
+        held as i64 * *prices.get_good(good) as i64 - self.total(good) as i64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn averages_through_buys_and_sales() {
+        let mut cost_basis = CostBasis::default();
+        // 10 at 20, then 30 at 10
+        cost_basis.add(&Good::Tea, 200);
+        cost_basis.add(&Good::Tea, 300);
+        assert_eq!(cost_basis.average(&Good::Tea, 40), Some(13));
+        // selling some keeps the average
+        assert_eq!(cost_basis.remove(&Good::Tea, 20, 40), 250);
+        assert_eq!(cost_basis.average(&Good::Tea, 20), Some(13));
+        // found goods are free, bringing the average down
+        assert_eq!(cost_basis.average(&Good::Tea, 25), Some(10));
+        // selling everything leaves nothing behind
+        assert_eq!(cost_basis.remove(&Good::Tea, 25, 25), 250);
+        assert_eq!(cost_basis.total(&Good::Tea), 0);
+        assert_eq!(cost_basis.average(&Good::Tea, 0), None);
+    }
+
+    #[test]
+    fn unrealized_gain() {
+        let mut cost_basis = CostBasis::default();
+        cost_basis.add(&Good::Rum, 100);
+        let inventory = Inventory::from([(Good::Rum, 10)]);
+        let prices = Inventory::from([(Good::Rum, 15)]);
+        assert_eq!(
+            cost_basis.unrealized_gain(&Good::Rum, &inventory, &prices),
+            50
+        );
+        let prices = Inventory::from([(Good::Rum, 8)]);
+        assert_eq!(
+            cost_basis.unrealized_gain(&Good::Rum, &inventory, &prices),
+            -20
+        );
+    }
+}
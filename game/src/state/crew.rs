@@ -0,0 +1,124 @@
+use std::fmt::{self, Display};
+
+use serde::{Deserialize, Serialize};
+
+use super::Rules;
+
+/// The sailors aboard the player's ship, and how they feel about their captain.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub struct Crew {
+    pub sailors: u32,
+    /// from 0 (mutinous) to 100 (devoted)
+    pub morale: u8,
+}
+
+/// How the crew's morale shows.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Mood {
+    Content,
+    Grumbling,
+    Restless,
+    Mutinous,
+}
+
+impl Display for Mood {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let string = match self {
+            Mood::Content => "content",
+            Mood::Grumbling => "grumbling",
+            Mood::Restless => "restless",
+            Mood::Mutinous => "mutinous",
+        };
+        write!(f, "{}", string)
+    }
+}
+
+impl Crew {
+    pub fn new(rules: &Rules) -> Crew {
+        Crew {
+            sailors: rules.starting_crew,
+            morale: rules.starting_morale,
+        }
+    }
+
+    pub fn mood(&self) -> Mood {
+        match self.morale {
+            60.. => Mood::Content,
+            40..=59 => Mood::Grumbling,
+            20..=39 => Mood::Restless,
+            _ => Mood::Mutinous,
+        }
+    }
+
+    /// how many of the given cannons there are sailors enough to fire
+    pub fn cannons_manned(&self, cannons: u8, crew_per_cannon: u32) -> u8 {
+        let manned = self.sailors / crew_per_cannon.max(1);
+        cannons.min(manned.try_into().unwrap_or(u8::MAX))
+    }
+
+    /// the share of the ship's berths filled, from 0.0 to 1.0
+    pub fn manning(&self, berths: u32) -> f64 {
+        if berths == 0 {
+            return 0.0;
+        }
+        (f64::from(self.sailors) / f64::from(berths)).min(1.0)
+    }
+
+    pub fn raise_morale(&mut self, points: u8) {
+        self.morale = self.morale.saturating_add(points).min(100);
+    }
+
+    pub fn lower_morale(&mut self, points: u8) {
+        self.morale = self.morale.saturating_sub(points);
+    }
+
+    /// new sailors bring their own morale with them, which the rest of the crew's is averaged with
+    pub fn sign_on(&mut self, sailors: u32, their_morale: u8) {
+        let total = self.sailors + sailors;
+        if total > 0 {
+            let morale = (u64::from(self.morale) * u64::from(self.sailors)
+                + u64::from(their_morale) * u64::from(sailors))
+                / u64::from(total);
+            self.morale = morale as u8;
+        }
+        self.sailors = total;
+    }
+
+    /// how far below the given morale the crew's is, as a share of it, if it is below at all.
+    /// eg. a morale of 10 is 0.75 below 40
+    pub fn discontent(&self, threshold: u8) -> Option<f64> {
+        if self.morale >= threshold {
+            None
+        } else {
+            Some(f64::from(threshold - self.morale) / f64::from(threshold))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn crew() {
+        let mut crew = Crew {
+            sailors: 9,
+            morale: 50,
+        };
+        assert_eq!(crew.mood(), Mood::Grumbling);
+        assert_eq!(crew.cannons_manned(5, 2), 4);
+        assert_eq!(crew.cannons_manned(3, 2), 3);
+        assert_eq!(crew.manning(12), 0.75);
+        assert_eq!(crew.discontent(40), None);
+        crew.lower_morale(40);
+        assert_eq!(crew.mood(), Mood::Mutinous);
+        assert_eq!(crew.discontent(40), Some(0.75));
+        crew.sign_on(3, 90);
+        assert_eq!(crew.sailors, 12);
+        assert_eq!(crew.morale, 30);
+        crew.raise_morale(200);
+        assert_eq!(crew.morale, 100);
+    }
+}
@@ -0,0 +1,145 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Good, Inventory};
+
+/// A duty charged on a good, as a share of its price.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Duty {
+    pub good: Good,
+    /// eg. 0.1 is 10% of the price
+    pub rate: f64,
+}
+
+/// What a port's customs house charges on goods crossing its docks, and what it won't allow at all.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Customs {
+    /// charged on goods sold in the port
+    pub import_duties: Vec<Duty>,
+    /// charged on goods bought in the port
+    pub export_duties: Vec<Duty>,
+    /// goods that customs officers seize when they find them in the hold
+    pub contraband: Vec<Good>,
+}
+
+fn rate_of(duties: &[Duty], good: &Good) -> f64 {
+    duties
+        .iter()
+        .find(|duty| &duty.good == good)
+        .map_or(0.0, |duty| duty.rate)
+}
+
+impl Customs {
+    pub fn import_rate(&self, good: &Good) -> f64 {
+        rate_of(&self.import_duties, good)
+    }
+
+    pub fn export_rate(&self, good: &Good) -> f64 {
+        rate_of(&self.export_duties, good)
+    }
+
+    pub fn is_contraband(&self, good: &Good) -> bool {
+        self.contraband.contains(good)
+    }
+}
+
+/// the duty owed on trading the given amount of a good at the given price, to the nearest gold
+pub fn duty(rate: f64, price: u32, amount: u32) -> u32 {
+    (rate * f64::from(price) * f64::from(amount)).round() as u32
+}
+
+/// What customs officers want after searching the hold: the contraband and a fine,
+/// or a bribe to look the other way.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomsInspection {
+    /// the contraband found in the hold
+    pub contraband: Inventory,
+    /// what the player is fined as well as losing the contraband, no more than the gold they have
+    pub fine: u32,
+    pub bribe: u32,
+}
+
+impl CustomsInspection {
+    /// the contraband is valued at the port's prices, and the fine and bribe are shares of its worth
+    pub fn new(
+        customs: &Customs,
+        hold: &Inventory,
+        prices: &Inventory,
+        gold: u32,
+        (fine, bribe): (f64, f64),
+    ) -> Self {
+        let contraband = Inventory::from_fn(|good| {
+            if customs.is_contraband(&good) {
+                *hold.get_good(&good)
+            } else {
+                0
+            }
+        });
+        let worth = contraband
+            .iter()
+            .map(|(good, amount)| f64::from(*amount) * f64::from(*prices.get_good(&good)))
+            .sum::<f64>();
+        CustomsInspection {
+            contraband,
+            fine: ((worth * fine).round() as u32).min(gold),
+            bribe: (worth * bribe).round() as u32,
+        }
+    }
+
+    pub fn found_contraband(&self) -> bool {
+        self.contraband.total_amount() > 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn customs() -> Customs {
+        Customs {
+            import_duties: vec![Duty {
+                good: Good::Tea,
+                rate: 0.1,
+            }],
+            export_duties: vec![Duty {
+                good: Good::Rum,
+                rate: 0.25,
+            }],
+            contraband: vec![Good::Tobacco],
+        }
+    }
+
+    #[test]
+    fn duties() {
+        let customs = customs();
+        assert_eq!(customs.import_rate(&Good::Tea), 0.1);
+        assert_eq!(customs.export_rate(&Good::Tea), 0.0);
+        assert_eq!(customs.export_rate(&Good::Rum), 0.25);
+        assert_eq!(duty(0.1, 55, 3), 17);
+        assert_eq!(duty(0.0, 55, 3), 0);
+    }
+
+    #[test]
+    fn inspection() {
+        let prices = Inventory::from([(Good::Tea, 100), (Good::Tobacco, 40)]);
+        let hold = Inventory::from([(Good::Tea, 10), (Good::Tobacco, 5)]);
+        let inspection = CustomsInspection::new(&customs(), &hold, &prices, 1000, (0.5, 0.3));
+        assert_eq!(
+            inspection,
+            CustomsInspection {
+                contraband: Inventory::from([(Good::Tobacco, 5)]),
+                fine: 100,
+                bribe: 60,
+            }
+        );
+        assert!(inspection.found_contraband());
+        // the fine can't take more gold than there is
+        let inspection = CustomsInspection::new(&customs(), &hold, &prices, 30, (0.5, 0.3));
+        assert_eq!(inspection.fine, 30);
+        let hold = Inventory::from([(Good::Tea, 10)]);
+        let inspection = CustomsInspection::new(&customs(), &hold, &prices, 1000, (0.5, 0.3));
+        assert!(!inspection.found_contraband());
+        assert_eq!(inspection.bribe, 0);
+    }
+}
@@ -0,0 +1,157 @@
+use std::fmt::{self, Display};
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use super::{location_personalities::EventWeights, Lender, LocationPersonality, Rules};
+
+/// A preset chosen on the introduction screen that scales the game's [Rules].
+/// The rules themselves are left untouched, the scaling is applied wherever they are used.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let string = match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+        };
+        write!(f, "{}", string)
+    }
+}
+
+impl Difficulty {
+    pub fn starting_gold(&self, rules: &Rules) -> u32 {
+        match self {
+            Difficulty::Easy => rules.starting_gold.saturating_mul(2),
+            Difficulty::Normal => rules.starting_gold,
+            Difficulty::Hard => rules.starting_gold / 2,
+        }
+    }
+
+    /// the starting debt is scaled separately from the starting gold,
+    /// so that easy games start closer to breaking even
+    pub fn starting_debt(&self, rules: &Rules) -> u32 {
+        let debt = rules
+            .starting_gold
+            .saturating_mul(rules.starting_debt_multiplier);
+        match self {
+            Difficulty::Easy => debt / 2,
+            Difficulty::Normal => debt,
+            Difficulty::Hard => debt.saturating_mul(2),
+        }
+    }
+
+    pub fn debt_interest_rate(&self, rules: &Rules) -> f64 {
+        self.interest_rate(rules.debt_interest_rate)
+    }
+
+    /// scales the interest rate of any loan
+    pub fn interest_rate(&self, rate: f64) -> f64 {
+        match self {
+            Difficulty::Easy => rate * 0.5,
+            Difficulty::Normal => rate,
+            Difficulty::Hard => rate * 1.5,
+        }
+    }
+
+    /// makes pirate encounters half as likely on easy and twice as likely on hard,
+    /// relative to every other event
+    pub fn event_weights(&self, weights: &EventWeights) -> EventWeights {
+        let (pirates, others) = match self {
+            Difficulty::Easy => (1, 2),
+            Difficulty::Normal => (1, 1),
+            Difficulty::Hard => (2, 1),
+        };
+        let scale = |weight: u8, by: u8| weight.saturating_mul(by);
+        EventWeights {
+            no_event: scale(weights.no_event, others),
+            cheap_good: scale(weights.cheap_good, others),
+            expensive_good: scale(weights.expensive_good, others),
+            find_goods: scale(weights.find_goods, others),
+            stolen_goods: scale(weights.stolen_goods, others),
+            can_buy_cannon: scale(weights.can_buy_cannon, others),
+            pirate_encounter: scale(weights.pirate_encounter, pirates),
+            can_buy_more_hold_space: scale(weights.can_buy_more_hold_space, others),
+            no_effect: scale(weights.no_effect, others),
+            customs_inspection: scale(weights.customs_inspection, others),
+        }
+    }
+
+    pub fn location_personality(&self, personality: &LocationPersonality) -> LocationPersonality {
+        LocationPersonality {
+            event_weights: self.event_weights(&personality.event_weights),
+            lender: Lender {
+                interest_rate: self.interest_rate(personality.lender.interest_rate),
+                ..personality.lender.clone()
+            },
+            ..personality.clone()
+        }
+    }
+
+    /// the fewest and most pirate ships that can be encountered at once
+    pub fn pirates_encountered(&self, rules: &Rules) -> (u8, u8) {
+        let (min, max) = (rules.min_pirates_encountered, rules.max_pirates_encountered);
+        match self {
+            Difficulty::Easy => (min.saturating_sub(1).max(1), max.saturating_sub(1).max(1)),
+            Difficulty::Normal => (min, max),
+            Difficulty::Hard => (min.saturating_add(1), max.saturating_add(1)),
+        }
+    }
+
+    /// how many of a good's highest price the player's net worth is compared against
+    /// when judging how well they did
+    pub fn achievement_multiple(&self) -> i64 {
+        match self {
+            Difficulty::Easy => 100,
+            Difficulty::Normal => 50,
+            Difficulty::Hard => 25,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn normal_keeps_the_rules() {
+        let rules = Rules::default();
+        let difficulty = Difficulty::Normal;
+        assert_eq!(difficulty.starting_gold(&rules), 500);
+        assert_eq!(difficulty.starting_debt(&rules), 1500);
+        assert_eq!(difficulty.debt_interest_rate(&rules), 0.1);
+        assert_eq!(
+            difficulty.event_weights(&rules.port_event_weights),
+            rules.port_event_weights
+        );
+        assert_eq!(difficulty.pirates_encountered(&rules), (2, 4));
+    }
+
+    #[test]
+    fn easy_and_hard() {
+        let rules = Rules::default();
+        assert_eq!(Difficulty::Easy.starting_gold(&rules), 1000);
+        assert_eq!(Difficulty::Easy.starting_debt(&rules), 750);
+        assert_eq!(Difficulty::Easy.debt_interest_rate(&rules), 0.05);
+        assert_eq!(Difficulty::Easy.pirates_encountered(&rules), (1, 3));
+        assert_eq!(Difficulty::Hard.starting_gold(&rules), 250);
+        assert_eq!(Difficulty::Hard.starting_debt(&rules), 3000);
+        assert_eq!(Difficulty::Hard.pirates_encountered(&rules), (3, 5));
+        let easy = Difficulty::Easy.event_weights(&rules.home_port_event_weights);
+        assert_eq!(easy.pirate_encounter, 1);
+        assert_eq!(easy.no_event, 12);
+        assert_eq!(easy.find_goods, 0);
+        let hard = Difficulty::Hard.event_weights(&rules.home_port_event_weights);
+        assert_eq!(hard.pirate_encounter, 2);
+        assert_eq!(hard.no_event, 6);
+    }
+}
@@ -1,4 +1,4 @@
-use super::{game_state::Mode, Location};
+use super::{game_state::Mode, Location, ShipClass};
 use std::fmt::{self, Display};
 
 #[derive(Debug)]
@@ -13,7 +13,27 @@ pub enum StateError {
     #[allow(dead_code)]
     LocationNotHomeBase(Location),
     PayDownAmountHigherThanDebt,
+    BorrowAmountHigherThanLimit,
     InsufficientBank,
+    BankFrozen,
+    /// there's no stash or rented warehouse in the given port
+    NoWarehouse(Location),
+    /// the home stash or a warehouse is already there to use
+    AlreadyHaveWarehouse,
+    WarehouseNotEmpty,
+    /// as many contracts as can be taken on at once already have been
+    TooManyContracts,
+    NoSuchContract,
+    /// there's no damage to the hull for a shipwright to mend
+    HullNotDamaged,
+    /// there's no shipyard in the given port
+    NoShipyard(Location),
+    /// the player already sails a ship of the given class
+    AlreadyHaveShip(ShipClass),
+    /// the ship carries as many cannons as it can
+    NoRoomForCannons,
+    /// the ship hasn't berths enough for the sailors
+    NoBerths,
 }
 
 impl Display for StateError {
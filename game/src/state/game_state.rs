@@ -1,39 +1,50 @@
 use crate::state::{location_personalities::LocationConfig, Inventory, Location, LocationInfos};
-use std::{borrow::BorrowMut, num::Saturating};
+use std::{borrow::BorrowMut, cmp::min, collections::VecDeque, num::Saturating};
 
 use chrono::Month;
-use rand::rngs::StdRng;
+use rand::{rngs::StdRng, SeedableRng};
+use serde::{Deserialize, Serialize};
 use tracing::debug;
 
-use super::{rng::MerchantRng, Good, StateError};
+use super::{
+    add_months, average_trade_price, duty, months_between, price_after_trade, rng::MerchantRng,
+    travel_months_at, Aging, Bounty, Contract, ContractOutcome, CostBasis, Crew, Customs,
+    CustomsInspection, DebtCollection, Difficulty, Good, GoodsMap, Ledger, LedgerEntry,
+    LedgerEvent, Lender, Loans, Lots, Markets, PirateCaptain, PirateRoster, PriceHistory, Rules,
+    ShipClass, StateError, Warehouse, WarehouseLoss, WarehouseMishap, Warehouses,
+};
 
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
 pub struct Transaction {
     pub good: Good,
     pub amount: Option<u32>,
 }
 
-pub const CANNON_COST: u16 = 5000;
-pub const SHIP_HEALTH: u8 = 5;
-
-#[derive(PartialEq, Clone, Debug, Copy)]
+#[derive(PartialEq, Clone, Debug, Copy, Serialize, Deserialize)]
 pub struct PirateEncounterInfo {
     pub health: u8,
     pub total_pirates: u8,
     pub cur_pirates: u8,
+    /// the named captain leading the pirates, if it isn't just any pirate fleet
+    pub captain: Option<PirateCaptain>,
+    /// the ships taken by boarding, whose gold was plundered rather than left in the wreckage
+    #[serde(default)]
+    pub boarded: u8,
 }
 
 impl PirateEncounterInfo {
-    pub fn new(pirates: u8) -> PirateEncounterInfo {
+    pub fn new(pirates: u8, health: u8) -> PirateEncounterInfo {
         PirateEncounterInfo {
-            health: SHIP_HEALTH,
+            health,
             total_pirates: pirates,
             cur_pirates: pirates,
+            captain: None,
+            boarded: 0,
         }
     }
 }
 
-#[derive(PartialEq, Clone, Debug, Copy)]
+#[derive(PartialEq, Clone, Debug, Copy, Serialize, Deserialize)]
 pub enum PirateEncounterState {
     Initial,
     Prompt {
@@ -54,9 +65,45 @@ pub enum PirateEncounterState {
     Destroyed,
     Victory {
         gold_recovered: u32,
+        /// what the port authorities pay for sinking a named captain's fleet
+        bounty: Option<Bounty>,
+    },
+    /// every cannon fired at once, and how many pirate ships that sank
+    BroadsideResult {
+        info: PirateEncounterInfo,
+        sunk: u8,
+    },
+    /// a boarding party sent onto the nearest pirate ship, and the gold found aboard if it was taken
+    BoardResult {
+        info: PirateEncounterInfo,
+        plunder: Option<u32>,
+    },
+    /// gold or cargo offered to the pirates to leave, and whether they took it
+    BribeResult {
+        info: PirateEncounterInfo,
+        offer: PirateBribe,
+        accepted: bool,
+    },
+    /// the ship gave up, and the pirates left with the given number of goods from the hold
+    Surrendered {
+        goods: u32,
+    },
+    /// cargo thrown overboard to outrun the pirates, and whether the lighter ship got away
+    DumpResult {
+        info: PirateEncounterInfo,
+        dumped: u32,
+        escaped: bool,
     },
 }
 
+/// what's offered to pirates to leave
+#[derive(PartialEq, Clone, Debug, Copy, Serialize, Deserialize)]
+pub enum PirateBribe {
+    Gold(u32),
+    /// the number of goods from the hold
+    Cargo(u32),
+}
+
 impl PirateEncounterState {
     pub fn pirates_attack(
         info: PirateEncounterInfo,
@@ -70,13 +117,13 @@ impl PirateEncounterState {
     }
 }
 
-#[derive(PartialEq, Clone, Copy, Debug)]
+#[derive(PartialEq, Clone, Copy, Debug, Serialize, Deserialize)]
 pub enum NoEffectEvent {
     SunnyDay,
     StormOnHorizon,
 }
 
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
 pub enum LocationEvent {
     CheapGood(Good),
     ExpensiveGood(Good),
@@ -84,11 +131,39 @@ pub enum LocationEvent {
     GoodsStolen(Option<GoodsStolenResult>),
     CanBuyCannon,
     PirateEncounter(PirateEncounterState),
-    CanBuyHoldSpace { price: u32, more_hold: u32 },
+    CanBuyHoldSpace {
+        price: u32,
+        more_hold: u32,
+    },
     NoEffect(NoEffectEvent),
+    /// collectors sent by the lender of a port whose loan is overdue
+    DebtCollectors {
+        lender: Location,
+        months_overdue: u32,
+    },
+    /// sailors unhappy with their captain slipped away on reaching port
+    Desertion {
+        sailors: u32,
+    },
+    /// the crew rose up, and the given number of them left with some of the gold
+    Mutiny {
+        sailors: u32,
+        gold: u32,
+    },
+    /// a run on the bank lost part of the deposit and froze the rest
+    BankRun {
+        lost: u32,
+        frozen_until: (u16, Month),
+    },
+    /// goods went missing from warehouses away from home
+    WarehouseLosses(Vec<WarehouseLoss>),
+    /// contracts were delivered on arriving, or their deadlines passed
+    ContractsSettled(Vec<ContractOutcome>),
+    /// customs officers search the hold for contraband
+    CustomsInspection,
 }
 
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
 pub enum Mode {
     ViewingInventory,
     Buying(Option<Transaction>),
@@ -97,44 +172,117 @@ pub enum Mode {
     StashDeposit(Option<Transaction>),
     StashWithdraw(Option<Transaction>),
     PayDebt(Option<u32>),
+    Borrow(Option<u32>),
     BankDeposit(Option<u32>),
     BankWithdraw(Option<u32>),
     GameEvent(LocationEvent),
+    /// how many of the most recent ledger entries have been scrolled past
+    CaptainsLog(usize),
+    /// charting the prices seen of the given good
+    PriceHistory(Good),
+    /// the last prices seen in every port
+    MarketRumors,
+    /// the contracts on offer in the current port, and those taken on
+    Contracts,
+    /// the ships for sale in the current port's shipyard
+    Shipyard,
+    /// signing on the given number of sailors
+    HireCrew(Option<u32>),
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum Initialization {
     SplashScreen,
     Introduction,
     Game,
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct GameState {
+    // the RNG's internal state isn't serializable, see [GameState::reseed_rng]
+    #[serde(skip, default = "unseeded_rng")]
     pub rng: Box<dyn MerchantRng>,
     pub initialization: Initialization,
     pub starting_date: (u16, Month),
     pub date: (u16, Month),
     pub hold_size: Saturating<u32>,
     pub cannons: Saturating<u8>,
+    /// the kind of ship the player sails, which sets the most the hold and hull can be
+    #[serde(default)]
+    pub ship_class: ShipClass,
+    /// the damage the hull has taken and not yet had mended, out of the ship's hull
+    #[serde(default)]
+    pub hull_damage: u8,
+    pub crew: Crew,
+    /// what the pirate captains remember of the player
+    pub pirates: PirateRoster,
     pub starting_gold: Saturating<u32>,
     pub gold: Saturating<u32>,
     pub bank: Saturating<u32>,
+    /// after a run on the bank, withdrawals are frozen until this date
+    #[serde(default)]
+    pub bank_frozen_until: Option<(u16, Month)>,
     pub location: Location,
     pub stash: Inventory,
     pub inventory: Inventory,
+    /// what was paid for the goods in the stash
+    #[serde(default)]
+    pub stash_cost: CostBasis,
+    /// what was paid for the goods in the hold
+    #[serde(default)]
+    pub inventory_cost: CostBasis,
+    /// when the goods in the stash were acquired, so that they can age
+    #[serde(default)]
+    pub stash_lots: Lots,
+    /// when the goods in the hold were acquired
+    #[serde(default)]
+    pub inventory_lots: Lots,
     pub location_config: LocationConfig,
     pub locations: LocationInfos,
     pub starting_debt: Saturating<u32>,
-    pub debt: Saturating<u32>,
+    /// what is owed to the lender of each port
+    pub loans: Loans,
     pub mode: Mode,
+    /// what else happened on reaching port, to be shown once the current event is dealt with
+    #[serde(default)]
+    pub pending_events: VecDeque<LocationEvent>,
     pub game_end: bool,
+    /// the balance constants this game is played with
+    #[serde(default)]
+    pub rules: Rules,
+    /// chosen on the introduction screen, scales the rules
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    /// the seed the game's world was generated from, if known
+    #[serde(default)]
+    pub seed: Option<u64>,
+    /// every transaction and event so far, shown in the Captain's Log
+    #[serde(default)]
+    pub ledger: Ledger,
+    /// the prices seen in each port so far
+    #[serde(default)]
+    pub price_history: PriceHistory,
+    /// how the player's trading has moved the prices in each port
+    #[serde(default)]
+    pub markets: Markets,
+    /// the warehouses rented in ports away from home
+    #[serde(default)]
+    pub warehouses: Warehouses,
+    /// the deliveries the player has agreed to make
+    #[serde(default)]
+    pub contracts: Vec<Contract>,
 }
 
 impl GameState {
-    pub fn new(mut rng: Box<dyn MerchantRng>) -> GameState {
-        let starting_gold = Saturating(500);
-        let starting_debt = starting_gold * Saturating(3u32);
-        let location_config = rng.gen_location_config(starting_gold.0);
+    #[cfg(test)]
+    pub fn new(rng: Box<dyn MerchantRng>) -> GameState {
+        GameState::with_rules(rng, Rules::default())
+    }
+
+    pub fn with_rules(mut rng: Box<dyn MerchantRng>, rules: Rules) -> GameState {
+        let starting_gold = Saturating(rules.starting_gold);
+        let starting_debt = starting_gold * Saturating(rules.starting_debt_multiplier);
+        let location_config = rng.gen_location_config(&rules);
         debug!("location_config: {:#?}", location_config);
         let locations = LocationInfos::new(
             &mut rng,
@@ -142,36 +290,104 @@ impl GameState {
             starting_gold.0 as i32 - starting_debt.0 as i32,
         );
         let starting_date = (1782, Month::March);
+        let mut price_history = PriceHistory::default();
+        price_history.record(
+            starting_date,
+            &location_config.home_port,
+            &locations.location_info(&location_config.home_port).prices,
+        );
         GameState {
             rng,
             initialization: Initialization::SplashScreen,
             date: starting_date,
             starting_date,
-            hold_size: Saturating(100),
-            cannons: Saturating(1),
+            hold_size: Saturating(ShipClass::default().info().hold),
+            cannons: Saturating(rules.starting_cannons),
+            ship_class: ShipClass::default(),
+            hull_damage: 0,
+            crew: Crew::new(&rules),
+            pirates: PirateRoster::default(),
             gold: starting_gold,
             starting_gold,
             bank: Saturating(0),
+            bank_frozen_until: None,
             location: location_config.home_port,
             stash: Inventory::default(),
             inventory: Inventory::default(),
+            stash_cost: CostBasis::default(),
+            inventory_cost: CostBasis::default(),
+            stash_lots: Lots::default(),
+            inventory_lots: Lots::default(),
+            loans: Loans::starting(
+                &location_config.home_port,
+                starting_debt.0,
+                rules.debt_interest_rate,
+            ),
             location_config,
             locations,
-            debt: starting_debt,
             starting_debt,
             mode: Mode::ViewingInventory,
+            pending_events: VecDeque::new(),
             game_end: false,
+            rules,
+            difficulty: Difficulty::default(),
+            seed: None,
+            ledger: Ledger::default(),
+            price_history,
+            markets: Markets::default(),
+            warehouses: Warehouses::default(),
+            contracts: vec![],
         }
     }
 
-    pub fn new_std_rng(rng: StdRng) -> GameState {
-        GameState::new(Box::new(rng))
+    /// creates a game whose world is fully determined by the given seed,
+    /// so that it can be shared and played again
+    pub fn from_seed(seed: u64, rules: Rules) -> GameState {
+        let mut state = GameState::with_rules(Box::new(StdRng::seed_from_u64(seed)), rules);
+        state.seed = Some(seed);
+        state
+    }
+
+    /// the date on which the game ends
+    pub fn end_date(&self) -> (u16, Month) {
+        (
+            self.starting_date.0 + self.rules.game_length_years,
+            self.starting_date.1,
+        )
+    }
+
+    /// replaces the RNG with a freshly seeded one, returning the seed used.
+    /// persisting the seed alongside the rest of the state allows a loaded game
+    /// to continue exactly as this one would have.
+    pub fn reseed_rng(&mut self) -> u64 {
+        let seed = self.rng.gen_seed();
+        self.rng = Box::new(StdRng::seed_from_u64(seed));
+        seed
+    }
+
+    /// whether there is a game in progress worth saving
+    pub fn can_save(&self) -> bool {
+        self.initialization == Initialization::Game && !self.game_end
     }
 
     pub fn splash_to_introduction(&mut self) {
         self.initialization = Initialization::Introduction;
     }
 
+    /// sets up the player's finances for the given difficulty.
+    /// the rest of its effects are applied as the game is played.
+    pub fn choose_difficulty(&mut self, difficulty: Difficulty) {
+        self.difficulty = difficulty;
+        self.starting_gold = Saturating(difficulty.starting_gold(&self.rules));
+        self.gold = self.starting_gold;
+        self.starting_debt = Saturating(difficulty.starting_debt(&self.rules));
+        self.loans = Loans::starting(
+            &self.location_config.home_port,
+            self.starting_debt.0,
+            difficulty.debt_interest_rate(&self.rules),
+        );
+    }
+
     pub fn introduction_to_game(&mut self) {
         self.initialization = Initialization::Game;
     }
@@ -184,7 +400,19 @@ impl GameState {
                 .inventory
                 .net_worth(&self.location_config.overall_price_ranges))
             + (self.bank.0 as i32)
-            - (self.debt.0 as i32)
+            - (self.debt() as i32)
+    }
+
+    /// everything owed to every lender
+    pub fn debt(&self) -> u32 {
+        self.loans.total()
+    }
+
+    /// the terms offered by the lender of the given port
+    pub fn lender(&self, location: &Location) -> Lender {
+        self.difficulty
+            .location_personality(self.location_config.personalities.get(location))
+            .lender
     }
 
     fn require_viewing_inventory(&self) -> Result<(), StateError> {
@@ -221,27 +449,318 @@ impl GameState {
         Ok(self)
     }
 
+    /// how much more damage the hull can take before the ship is lost
+    pub fn hull(&self) -> u8 {
+        self.ship_class.info().hull.saturating_sub(self.hull_damage)
+    }
+
+    /// whether the hull is damaged enough to slow the ship down
+    pub fn hull_slows_ship(&self) -> bool {
+        self.hull() <= self.rules.slow_hull_health
+    }
+
+    /// how many months the voyage from here to the given port takes, given the state of the hull
+    pub fn voyage_months(&self, destination: &Location) -> u16 {
+        let months = travel_months_at(&self.location, destination, self.ship_class.info().speed);
+        if self.hull_slows_ship() {
+            months + self.rules.slow_hull_months
+        } else {
+            months
+        }
+    }
+
+    /// what a shipwright would charge to mend all the damage to the hull
+    pub fn repair_cost(&self) -> u32 {
+        u32::from(self.hull_damage) * self.rules.repair_cost
+    }
+
+    /// has a shipwright mend as much of the damage to the hull as can be afforded
+    pub fn repair_hull(&mut self) -> Result<&mut GameState, StateError> {
+        self.require_viewing_inventory()?;
+        if self.hull_damage == 0 {
+            return Err(StateError::HullNotDamaged);
+        }
+        let affordable = self.gold.0 / self.rules.repair_cost.max(1);
+        let points = u32::from(self.hull_damage).min(affordable) as u8;
+        if points == 0 {
+            return Err(StateError::CannotAfford);
+        }
+        let price = u32::from(points) * self.rules.repair_cost;
+        self.gold -= price;
+        self.hull_damage -= points;
+        self.record(LedgerEvent::RepairedHull { points, price });
+        Ok(self)
+    }
+
+    pub fn open_shipyard(&mut self) -> Result<&mut GameState, StateError> {
+        self.require_viewing_inventory()?;
+        if !self.location.info().shipyard {
+            return Err(StateError::NoShipyard(self.location));
+        }
+        self.mode = Mode::Shipyard;
+        Ok(self)
+    }
+
+    /// what the shipyard asks for a ship of the given class, after trading in the current one.
+    /// negative if the shipyard would pay the difference
+    pub fn ship_price(&self, class: ShipClass) -> i64 {
+        i64::from(class.info().price)
+            - i64::from(
+                self.ship_class
+                    .trade_in_value(self.hull_damage, self.rules.repair_cost),
+            )
+    }
+
+    /// trades in the current ship for a new one of the given class. the goods and as many of
+    /// the cannons as it carries move over, but hold space bought along the way stays behind
+    pub fn trade_in_ship(&mut self, class: ShipClass) -> Result<&mut GameState, StateError> {
+        if let Mode::Shipyard = self.mode {
+            if class == self.ship_class {
+                return Err(StateError::AlreadyHaveShip(class));
+            }
+            let info = class.info();
+            if self.inventory.total_amount() > info.hold {
+                return Err(StateError::InsufficientHold);
+            }
+            let price = self.ship_price(class);
+            if price > i64::from(self.gold.0) {
+                return Err(StateError::CannotAfford);
+            }
+            let trade_in = self
+                .ship_class
+                .trade_in_value(self.hull_damage, self.rules.repair_cost);
+            self.gold = Saturating((i64::from(self.gold.0) - price) as u32);
+            self.ship_class = class;
+            self.hold_size = Saturating(info.hold);
+            self.cannons = Saturating(self.cannons.0.min(info.max_cannons));
+            // sailors without a berth on the new ship are paid off
+            self.crew.sailors = self.crew.sailors.min(info.berths);
+            self.hull_damage = 0;
+            self.record(LedgerEvent::TradedInShip {
+                class,
+                price: info.price,
+                trade_in,
+            });
+            Ok(self)
+        } else {
+            Err(StateError::InvalidMode(self.mode.clone()))
+        }
+    }
+
+    /// whether the ship carries as many cannons as it can
+    pub fn cannons_full(&self) -> bool {
+        self.cannons.0 >= self.ship_class.info().max_cannons
+    }
+
+    /// how many of the ship's cannons there are sailors enough to fire
+    pub fn cannons_manned(&self) -> u8 {
+        self.crew
+            .cannons_manned(self.cannons.0, self.rules.crew_per_cannon)
+    }
+
+    /// the share of the ship's berths filled
+    pub fn manning(&self) -> f64 {
+        self.crew.manning(self.ship_class.info().berths)
+    }
+
+    /// how many more sailors the ship has berths for
+    pub fn free_berths(&self) -> u32 {
+        self.ship_class
+            .info()
+            .berths
+            .saturating_sub(self.crew.sailors)
+    }
+
+    pub fn begin_hiring(&mut self) -> Result<&mut GameState, StateError> {
+        self.require_viewing_inventory()?;
+        self.mode = Mode::HireCrew(None);
+        Ok(self)
+    }
+
+    pub fn commit_hiring(&mut self) -> Result<&mut GameState, StateError> {
+        if let Mode::HireCrew(sailors) = &self.mode {
+            let sailors = sailors.unwrap_or(0);
+            if sailors > self.free_berths() {
+                return Err(StateError::NoBerths);
+            }
+            let price = sailors.saturating_mul(self.rules.hiring_fee);
+            if price > self.gold.0 {
+                return Err(StateError::CannotAfford);
+            }
+            if sailors > 0 {
+                self.gold -= price;
+                self.crew.sign_on(sailors, self.rules.starting_morale);
+                self.record(LedgerEvent::HiredCrew {
+                    sailors,
+                    fee: self.rules.hiring_fee,
+                });
+            }
+            self.mode = Mode::ViewingInventory;
+            return Ok(self);
+        }
+        Err(StateError::InvalidMode(self.mode.clone()))
+    }
+
+    /// pays the crew's wages for the months gone by. a crew paid in full grows happier,
+    /// and one that isn't grows unhappier
+    fn pay_wages(&mut self, months: u16) {
+        let wages = self
+            .crew
+            .sailors
+            .saturating_mul(self.rules.crew_wage)
+            .saturating_mul(months.into());
+        if wages == 0 {
+            return;
+        }
+        let paid = wages.min(self.gold.0);
+        let unpaid = wages - paid;
+        self.gold -= paid;
+        if unpaid == 0 {
+            self.crew.raise_morale(
+                self.rules
+                    .paid_morale_gain
+                    .saturating_mul(months.try_into().unwrap_or(u8::MAX)),
+            );
+        } else {
+            self.crew.lower_morale(self.rules.unpaid_morale_loss);
+        }
+        self.record(LedgerEvent::Wages { paid, unpaid });
+    }
+
+    /// an unhappy crew may mutiny or desert on reaching port
+    fn crew_unrest(&mut self) -> Option<LocationEvent> {
+        if let Some(discontent) = self.crew.discontent(self.rules.mutiny_morale) {
+            if self.rng.gen_mutiny(discontent) {
+                let sailors = (f64::from(self.crew.sailors) * self.rules.mutiny_loss).ceil() as u32;
+                let gold = (f64::from(self.gold.0) * self.rules.mutiny_loss).floor() as u32;
+                self.crew.sailors -= sailors;
+                self.gold -= gold;
+                // those who stay on are the captain's loyal few
+                self.crew.morale = self.rules.starting_morale;
+                self.record(LedgerEvent::Mutiny { sailors, gold });
+                return Some(LocationEvent::Mutiny { sailors, gold });
+            }
+        }
+        let discontent = self.crew.discontent(self.rules.desertion_morale)?;
+        let sailors = self.rng.gen_deserters(self.crew.sailors, discontent);
+        if sailors == 0 {
+            return None;
+        }
+        self.crew.sailors -= sailors;
+        self.record(LedgerEvent::Deserted { sailors });
+        Some(LocationEvent::Desertion { sailors })
+    }
+
+    /// pays the ship's upkeep for the months gone by. what can't be paid is added to
+    /// the debt at home
+    fn pay_upkeep(&mut self, months: u16) {
+        let upkeep = self.ship_class.info().upkeep.saturating_mul(months.into());
+        if upkeep == 0 {
+            return;
+        }
+        let paid = upkeep.min(self.gold.0);
+        let borrowed = upkeep - paid;
+        self.gold -= paid;
+        let home_port = self.location_config.home_port;
+        let loan = self.loans.get_mut(&home_port);
+        loan.balance = loan.balance.saturating_add(borrowed);
+        self.record(LedgerEvent::Upkeep { paid, borrowed });
+    }
+
+    /// the goods stashed in the current port, in the home stash or a rented warehouse
+    pub fn stash_here(&self) -> Option<&Inventory> {
+        if self.location == self.location_config.home_port {
+            Some(&self.stash)
+        } else {
+            self.warehouses
+                .get(&self.location)
+                .as_ref()
+                .map(|warehouse| &warehouse.goods)
+        }
+    }
+
+    /// the goods stashed in the current port along with what was paid for them and when
+    fn stash_here_mut(&mut self) -> Option<(&mut Inventory, &mut CostBasis, &mut Lots)> {
+        if self.location == self.location_config.home_port {
+            Some((&mut self.stash, &mut self.stash_cost, &mut self.stash_lots))
+        } else {
+            self.warehouses
+                .get_mut(&self.location)
+                .as_mut()
+                .map(|warehouse| {
+                    (
+                        &mut warehouse.goods,
+                        &mut warehouse.cost,
+                        &mut warehouse.lots,
+                    )
+                })
+        }
+    }
+
+    fn require_stash_here(&self) -> Result<(), StateError> {
+        if self.stash_here().is_none() {
+            Err(StateError::NoWarehouse(self.location))
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn begin_stash_deposit(&mut self) -> Result<&mut GameState, StateError> {
         self.require_viewing_inventory()?;
-        self.require_location_home_base()?;
+        self.require_stash_here()?;
         self.mode = Mode::StashDeposit(None);
         Ok(self)
     }
 
     pub fn begin_stash_withdraw(&mut self) -> Result<&mut GameState, StateError> {
         self.require_viewing_inventory()?;
-        self.require_location_home_base()?;
+        self.require_stash_here()?;
         self.mode = Mode::StashWithdraw(None);
         Ok(self)
     }
 
+    /// rents a warehouse in the current port, paying the first month's rent up front
+    pub fn rent_warehouse(&mut self) -> Result<&mut GameState, StateError> {
+        self.require_viewing_inventory()?;
+        if self.stash_here().is_some() {
+            return Err(StateError::AlreadyHaveWarehouse);
+        }
+        let rent = self.rules.warehouse_rent;
+        if rent > self.gold.0 {
+            return Err(StateError::CannotAfford);
+        }
+        self.gold -= rent;
+        *self.warehouses.get_mut(&self.location) = Some(Warehouse::default());
+        self.record(LedgerEvent::WarehouseRent(rent));
+        Ok(self)
+    }
+
+    /// stops renting the warehouse in the current port, once it has been emptied
+    pub fn give_up_warehouse(&mut self) -> Result<&mut GameState, StateError> {
+        self.require_viewing_inventory()?;
+        let warehouse = self.warehouses.get_mut(&self.location);
+        match warehouse {
+            None => Err(StateError::NoWarehouse(self.location)),
+            Some(w) if w.goods.total_amount() > 0 => Err(StateError::WarehouseNotEmpty),
+            Some(_) => {
+                *warehouse = None;
+                Ok(self)
+            }
+        }
+    }
+
     pub fn begin_pay_debt(&mut self) -> Result<&mut GameState, StateError> {
         self.require_viewing_inventory()?;
-        self.require_location_home_base()?;
         self.mode = Mode::PayDebt(None);
         Ok(self)
     }
 
+    pub fn begin_borrow(&mut self) -> Result<&mut GameState, StateError> {
+        self.require_viewing_inventory()?;
+        self.mode = Mode::Borrow(None);
+        Ok(self)
+    }
+
     pub fn begin_bank_deposit(&mut self) -> Result<&mut GameState, StateError> {
         self.require_viewing_inventory()?;
         self.require_location_home_base()?;
@@ -336,6 +855,8 @@ impl GameState {
             let binding: Option<&mut Option<u32>> = None;
             let amount = match &mut self.mode {
                 Mode::PayDebt(amount) => Some(amount),
+                Mode::Borrow(amount) => Some(amount),
+                Mode::HireCrew(amount) => Some(amount),
                 Mode::BankDeposit(amount) => Some(amount),
                 Mode::BankWithdraw(amount) => Some(amount),
                 _ => binding,
@@ -367,6 +888,8 @@ impl GameState {
             let binding: Option<&mut Option<u32>> = None;
             let amount = match &mut self.mode {
                 Mode::PayDebt(amount) => Some(amount),
+                Mode::Borrow(amount) => Some(amount),
+                Mode::HireCrew(amount) => Some(amount),
                 Mode::BankDeposit(amount) => Some(amount),
                 Mode::BankWithdraw(amount) => Some(amount),
                 _ => binding,
@@ -384,13 +907,7 @@ impl GameState {
     pub fn commit_buy(&mut self) -> Result<&mut GameState, StateError> {
         if let Mode::Buying(Some(info)) = &self.mode {
             let amount = info.amount.unwrap_or(0);
-            let good_price = self
-                .locations
-                .location_info(&self.location)
-                .prices
-                .get_good(&info.good);
-            let can_afford = self.gold / Saturating(*good_price);
-            if amount > can_afford.0 {
+            if amount > self.can_afford(&info.good) {
                 return Err(StateError::CannotAfford);
             } else {
                 let hold_size = self.hold_size;
@@ -398,8 +915,25 @@ impl GameState {
                 if current_hold.saturating_add(amount) > hold_size.0 {
                     return Err(StateError::InsufficientHold);
                 } else {
-                    self.inventory.add_good(&info.good, amount);
-                    self.gold -= good_price * amount;
+                    let good = info.good;
+                    let price = self.trade_price(&good, amount as i64);
+                    let duty = self.export_duty(&good, amount);
+                    self.inventory.add_good(&good, amount);
+                    // the duty is part of what the goods cost
+                    self.inventory_cost.add(&good, price * amount + duty);
+                    self.inventory_lots.add(&good, amount, self.date);
+                    self.gold -= price * amount + duty;
+                    self.trade(&good, amount as i64);
+                    if amount > 0 {
+                        self.record(LedgerEvent::Bought {
+                            good,
+                            amount,
+                            price,
+                        });
+                    }
+                    if duty > 0 {
+                        self.record(LedgerEvent::DutyPaid { good, duty });
+                    }
                     self.mode = Mode::ViewingInventory;
                     return Ok(self);
                 }
@@ -411,17 +945,29 @@ impl GameState {
     pub fn commit_sell(&mut self) -> Result<&mut GameState, StateError> {
         if let Mode::Selling(Some(info)) = &self.mode {
             let amount = info.amount.unwrap_or(0);
-            let good_price = self
-                .locations
-                .location_info(&self.location)
-                .prices
-                .get_good(&info.good);
             let user_amount = self.inventory.get_good(&info.good);
             if &amount > user_amount {
                 return Err(StateError::InsufficientInventory);
             } else {
-                self.inventory.remove_good(&info.good, amount);
-                self.gold += good_price * amount;
+                let good = info.good;
+                let price = self.sale_price(&good, amount);
+                let duty = self.import_duty(&good, amount);
+                self.inventory_cost.remove(&good, amount, *user_amount);
+                self.inventory_lots.remove(&good, amount, *user_amount);
+                self.inventory.remove_good(&good, amount);
+                self.gold += price * amount;
+                self.gold -= duty;
+                self.trade(&good, -(amount as i64));
+                if amount > 0 {
+                    self.record(LedgerEvent::Sold {
+                        good,
+                        amount,
+                        price,
+                    });
+                }
+                if duty > 0 {
+                    self.record(LedgerEvent::DutyPaid { good, duty });
+                }
                 self.mode = Mode::ViewingInventory;
                 return Ok(self);
             }
@@ -432,12 +978,25 @@ impl GameState {
     pub fn commit_stash_deposit(&mut self) -> Result<&mut GameState, StateError> {
         if let Mode::StashDeposit(Some(info)) = &self.mode {
             let amount = info.amount.unwrap_or(0);
-            let user_amount = self.inventory.get_good(&info.good);
-            if &amount > user_amount {
+            let user_amount = *self.inventory.get_good(&info.good);
+            if amount > user_amount {
                 return Err(StateError::InsufficientInventory);
             } else {
-                self.inventory.remove_good(&info.good, amount);
-                self.stash.add_good(&info.good, amount);
+                let good = info.good;
+                self.require_stash_here()?;
+                let cost = self.inventory_cost.remove(&good, amount, user_amount);
+                let lots = self.inventory_lots.remove(&good, amount, user_amount);
+                self.inventory.remove_good(&good, amount);
+                let location = self.location;
+                let (stash, stash_cost, stash_lots) = self
+                    .stash_here_mut()
+                    .ok_or(StateError::NoWarehouse(location))?;
+                stash_cost.add(&good, cost);
+                stash_lots.add_lots(&good, lots);
+                stash.add_good(&good, amount);
+                if amount > 0 {
+                    self.record(LedgerEvent::Stashed { good, amount });
+                }
                 self.mode = Mode::ViewingInventory;
                 return Ok(self);
             }
@@ -448,12 +1007,24 @@ impl GameState {
     pub fn commit_stash_withdraw(&mut self) -> Result<&mut GameState, StateError> {
         if let Mode::StashWithdraw(Some(info)) = &self.mode {
             let amount = info.amount.unwrap_or(0);
-            let stash_amount = self.stash.get_good(&info.good);
-            if &amount > stash_amount {
+            let good = info.good;
+            let location = self.location;
+            let (stash, stash_cost, stash_lots) = self
+                .stash_here_mut()
+                .ok_or(StateError::NoWarehouse(location))?;
+            let stash_amount = *stash.get_good(&good);
+            if amount > stash_amount {
                 return Err(StateError::InsufficientStash);
             } else {
-                self.stash.remove_good(&info.good, amount);
-                self.inventory.add_good(&info.good, amount);
+                let cost = stash_cost.remove(&good, amount, stash_amount);
+                let lots = stash_lots.remove(&good, amount, stash_amount);
+                stash.remove_good(&good, amount);
+                self.inventory_cost.add(&good, cost);
+                self.inventory_lots.add_lots(&good, lots);
+                self.inventory.add_good(&good, amount);
+                if amount > 0 {
+                    self.record(LedgerEvent::Unstashed { good, amount });
+                }
                 self.mode = Mode::ViewingInventory;
                 return Ok(self);
             }
@@ -461,17 +1032,41 @@ impl GameState {
         Err(StateError::InvalidMode(self.mode.clone()))
     }
 
+    /// repays the loans, the soonest due first, through the current port's lender
     pub fn commit_pay_debt(&mut self) -> Result<&mut GameState, StateError> {
         if let Mode::PayDebt(amount) = &self.mode {
             let amount = amount.unwrap_or(0);
-            if amount > self.debt.0 {
+            if amount > self.debt() {
                 return Err(StateError::PayDownAmountHigherThanDebt);
             }
             if amount > self.gold.0 {
                 return Err(StateError::CannotAfford);
             }
-            self.debt -= amount;
+            self.loans.repay(amount);
             self.gold -= amount;
+            if amount > 0 {
+                self.record(LedgerEvent::DebtPayment(amount));
+            }
+            self.mode = Mode::ViewingInventory;
+            return Ok(self);
+        }
+        Err(StateError::InvalidMode(self.mode.clone()))
+    }
+
+    /// borrows from the lender of the current port
+    pub fn commit_borrow(&mut self) -> Result<&mut GameState, StateError> {
+        if let Mode::Borrow(amount) = &self.mode {
+            let amount = amount.unwrap_or(0);
+            let lender = self.lender(&self.location);
+            let loan = self.loans.get_mut(&self.location);
+            if amount > loan.available(&lender) {
+                return Err(StateError::BorrowAmountHigherThanLimit);
+            }
+            if amount > 0 {
+                loan.borrow(amount, &lender, self.date);
+                self.gold += amount;
+                self.record(LedgerEvent::Borrowed(amount));
+            }
             self.mode = Mode::ViewingInventory;
             return Ok(self);
         }
@@ -486,6 +1081,9 @@ impl GameState {
             }
             self.gold -= amount;
             self.bank += amount;
+            if amount > 0 {
+                self.record(LedgerEvent::BankDeposit(amount));
+            }
             self.mode = Mode::ViewingInventory;
             return Ok(self);
         }
@@ -498,48 +1096,222 @@ impl GameState {
             if amount > self.bank.0 {
                 return Err(StateError::InsufficientBank);
             }
+            if amount > 0 && self.is_bank_frozen() {
+                return Err(StateError::BankFrozen);
+            }
             self.gold += amount;
             self.bank -= amount;
+            if amount > 0 {
+                self.record(LedgerEvent::BankWithdrawal(amount));
+            }
             self.mode = Mode::ViewingInventory;
             return Ok(self);
         }
         Err(StateError::InvalidMode(self.mode.clone()))
     }
 
+    /// whether withdrawals are still frozen after a run on the bank
+    pub fn is_bank_frozen(&self) -> bool {
+        self.bank_frozen_until
+            .is_some_and(|until| months_between(self.date, until) > 0)
+    }
+
+    /// pays the rent on every warehouse for the months gone by,
+    /// and rolls for what goes missing from them
+    fn keep_warehouses(&mut self, months: u16) -> Vec<WarehouseLoss> {
+        let mut losses = vec![];
+        let mut rent_paid = 0;
+        let rent = self.rules.warehouse_rent.saturating_mul(months.into());
+        for location in self.warehouses.rented() {
+            let warehouse = self.warehouses.get_mut(&location);
+            if self.gold.0 < rent {
+                // a warehouse that can't be paid for is lost along with everything in it
+                if let Some(warehouse) = warehouse.take() {
+                    losses.push(WarehouseLoss {
+                        location,
+                        mishap: WarehouseMishap::Evicted,
+                        goods: warehouse.goods,
+                    });
+                }
+                continue;
+            }
+            self.gold -= rent;
+            rent_paid += rent;
+            let Some(warehouse) = warehouse else {
+                continue;
+            };
+            let goods_with_inventory = warehouse
+                .goods
+                .clone()
+                .into_iter()
+                .filter(|x| x.1 > 0)
+                .collect::<Vec<(Good, u32)>>();
+            if goods_with_inventory.is_empty() {
+                continue;
+            }
+            if let Some((mishap, good, amount)) = self.rng.gen_warehouse_mishap(
+                &goods_with_inventory,
+                self.rules.warehouse_theft_chance,
+                self.rules.warehouse_spoilage_chance,
+            ) {
+                let held = *warehouse.goods.get_good(&good);
+                warehouse.cost.remove(&good, amount, held);
+                warehouse.lots.remove(&good, amount, held);
+                warehouse.goods.remove_good(&good, amount);
+                let mut goods = Inventory::default();
+                goods.add_good(&good, amount);
+                losses.push(WarehouseLoss {
+                    location,
+                    mishap,
+                    goods,
+                });
+            }
+        }
+        if rent_paid > 0 {
+            self.record(LedgerEvent::WarehouseRent(rent_paid));
+        }
+        for loss in &losses {
+            for (good, amount) in loss.goods.clone().into_iter() {
+                if amount > 0 {
+                    self.record_at(loss.location, LedgerEvent::WarehouseLoss { good, amount });
+                }
+            }
+        }
+        losses
+    }
+
+    /// charges the penalty for the contracts whose deadline has passed, even on arriving at
+    /// their destination late, and delivers those due here that the hold has the goods for
+    fn settle_contracts(&mut self) -> Vec<ContractOutcome> {
+        let mut outcomes = vec![];
+        for contract in std::mem::take(&mut self.contracts) {
+            let good = contract.good;
+            let held = *self.inventory.get_good(&good);
+            if contract.is_overdue(self.date) {
+                let penalty = ((f64::from(contract.reward) * self.rules.contract_penalty).round()
+                    as u32)
+                    .min(self.gold.0);
+                self.gold -= penalty;
+                self.record(LedgerEvent::ContractMissed { penalty });
+                outcomes.push(ContractOutcome::Missed { contract, penalty });
+            } else if contract.destination == self.location && held >= contract.amount {
+                let amount = contract.amount;
+                self.inventory_cost.remove(&good, amount, held);
+                self.inventory_lots.remove(&good, amount, held);
+                self.inventory.remove_good(&good, amount);
+                self.gold += contract.reward;
+                self.record(LedgerEvent::ContractDelivered {
+                    good,
+                    amount,
+                    reward: contract.reward,
+                });
+                outcomes.push(ContractOutcome::Delivered(contract));
+            } else {
+                self.contracts.push(contract);
+            }
+        }
+        outcomes
+    }
+
+    /// rolls for a run on the bank in the given month, losing part of the deposit and
+    /// freezing the rest
+    fn bank_run(&mut self, date: (u16, Month)) -> Option<LocationEvent> {
+        if self.bank.0 == 0 {
+            return None;
+        }
+        let share = self
+            .rng
+            .gen_bank_run(self.rules.bank_run_chance, self.rules.bank_run_max_loss)?;
+        let lost = (f64::from(self.bank.0) * share).floor() as u32;
+        self.bank -= lost;
+        let frozen_until = add_months(date, self.rules.bank_run_freeze_months);
+        self.bank_frozen_until = Some(frozen_until);
+        self.record(LedgerEvent::BankRun { lost });
+        Some(LocationEvent::BankRun { lost, frozen_until })
+    }
+
     pub fn sail_to(&mut self, destination: &Location) -> Result<&mut GameState, StateError> {
         if let Mode::Sailing = self.mode {
             if destination == &self.location {
                 Err(StateError::AlreadyInLocation)
             } else {
                 self.mode = Mode::ViewingInventory;
-                // increment the month
-                self.date.1 = self.date.1.succ();
-                if self.date.1 == Month::January {
-                    self.date.0 += 1;
-                }
-                if self.date.0 == 1785 && self.date.1 == Month::March {
-                    // 3 years have elapsed
+                // the voyage takes longer the further the destination is,
+                // though never past the end of the game
+                let months = self
+                    .voyage_months(destination)
+                    .min(months_between(self.date, self.end_date()).max(1) as u16);
+                let departure = self.date;
+                self.date = add_months(self.date, months);
+                if months_between(self.date, self.end_date()) <= 0 {
+                    // the allotted years have elapsed
                     // end the game
                     self.game_end = true
                 }
                 // update location info for location we just left
                 let player_net_worth = self.net_worth();
-                let new_location_info = self.locations.generate_location(
+                self.locations.generate_location(
                     &mut self.rng,
                     destination,
-                    self.location_config.personalities.get(destination),
+                    &self
+                        .difficulty
+                        .location_personality(self.location_config.personalities.get(destination)),
                     true,
                     player_net_worth,
                 );
+                // prices pushed around by earlier trading are still recovering
+                let market = self.markets.get_mut(destination);
+                let new_location_info = self.locations.location_info_mut(destination);
+                if !market.is_calm() {
+                    let remaining = self.rng.gen_market_pressure_remaining(
+                        market.months_since_update(self.date),
+                        self.rules.market_recovery,
+                    );
+                    market.settle(remaining, &mut new_location_info.prices, self.date);
+                }
+                self.price_history
+                    .record(self.date, destination, &new_location_info.prices);
                 // set current location
                 self.location = *destination;
-                // increment debt, if any
-                let new_debt = f64::from(self.debt.0) * 1.1;
-                self.debt = Saturating(new_debt.floor() as u32);
-                // determine if we've encountered an event
-                if let Some(event) = &new_location_info.event {
-                    self.mode = Mode::GameEvent(event.clone());
+                let mut bank_run = None;
+                for month in 1..=months {
+                    // increment debts, if any
+                    for location in Location::variants() {
+                        self.loans.get_mut(location).accrue_interest();
+                    }
+                    // the bank pays interest on deposits
+                    let interest = f64::from(self.bank.0) * self.rules.bank_interest_rate;
+                    self.bank += Saturating(interest.floor() as u32);
+                    // the bank can have a run in any month of the voyage, though after one
+                    // there's no more to fear until the player is back
+                    if bank_run.is_none() {
+                        bank_run = self.bank_run(add_months(departure, month));
+                    }
+                }
+                self.pay_upkeep(months);
+                self.pay_wages(months);
+                let warehouse_losses = self.keep_warehouses(months);
+                let contract_outcomes = self.settle_contracts();
+                // everything that happened is shown one after another, and
+                // collectors for an overdue loan find the player before anything else can
+                let mut events = VecDeque::new();
+                if let Some((lender, months_overdue)) = self.loans.most_overdue(self.date) {
+                    events.push_back(LocationEvent::DebtCollectors {
+                        lender,
+                        months_overdue,
+                    });
                 }
+                if !contract_outcomes.is_empty() {
+                    events.push_back(LocationEvent::ContractsSettled(contract_outcomes));
+                }
+                if !warehouse_losses.is_empty() {
+                    events.push_back(LocationEvent::WarehouseLosses(warehouse_losses));
+                }
+                events.extend(bank_run);
+                events.extend(self.crew_unrest());
+                events.extend(self.locations.location_info(destination).event.clone());
+                self.pending_events = events;
+                self.next_event();
                 Ok(self)
             }
         } else {
@@ -558,19 +1330,239 @@ impl GameState {
 
     pub fn acknowledge_event(&mut self) -> Result<&mut GameState, StateError> {
         if let Mode::GameEvent(_) = self.mode {
-            self.mode = Mode::ViewingInventory;
+            self.next_event();
             Ok(self)
         } else {
             Err(StateError::InvalidMode(self.mode.clone()))
         }
     }
 
+    /// moves on to the next event waiting to be shown, if any
+    fn next_event(&mut self) {
+        self.mode = match self.pending_events.pop_front() {
+            Some(event) => Mode::GameEvent(event),
+            None => Mode::ViewingInventory,
+        };
+    }
+
     pub fn remaining_hold(&self) -> u32 {
         self.hold_size
             .0
             .saturating_sub(self.inventory.total_amount())
     }
 
+    /// how each of the goods in the hold is aging
+    pub fn hold_aging(&self) -> GoodsMap<Aging> {
+        self.inventory_lots
+            .aging(&self.inventory, self.date, &self.rules)
+    }
+
+    /// the average quality of the given amount of a good in the hold, the oldest going first
+    pub fn quality(&self, good: &Good, amount: u32) -> f64 {
+        let held = *self.inventory.get_good(good);
+        self.inventory_lots
+            .quality(good, amount, held, self.date, &self.rules)
+    }
+
+    /// what each of the given amount of a good in the hold would sell for here,
+    /// given how well it has aged
+    pub fn sale_price(&self, good: &Good, amount: u32) -> u32 {
+        let price = self.trade_price(good, -(amount as i64));
+        (price as f64 * self.quality(good, amount)).round() as u32
+    }
+
+    /// what everything in the hold would sell for here, for each good, after duty
+    pub fn hold_sale_prices(&self) -> Inventory {
+        self.inventory
+            .iter()
+            .map(|(good, held)| {
+                let price = self.sale_price(&good, *held);
+                let rate = self.customs().import_rate(&good);
+                (good, (f64::from(price) * (1.0 - rate)).round() as u32)
+            })
+            .collect()
+    }
+
+    /// how much more (or less) selling the given amount of a good here would make
+    /// than was paid for it, after duty
+    pub fn profit_from_selling(&self, good: &Good, amount: u32) -> i64 {
+        let held = *self.inventory.get_good(good);
+        let amount = amount.min(held);
+        let price = self.sale_price(good, amount);
+        amount as i64 * price as i64
+            - self.import_duty(good, amount) as i64
+            - self.inventory_cost.cost_of(good, amount, held) as i64
+    }
+
+    /// the duties charged and goods forbidden in the current port
+    pub fn customs(&self) -> &Customs {
+        &self
+            .location_config
+            .personalities
+            .get(&self.location)
+            .customs
+    }
+
+    /// the duty owed on buying the given amount of a good here
+    pub fn export_duty(&self, good: &Good, amount: u32) -> u32 {
+        let price = self.trade_price(good, amount as i64);
+        duty(self.customs().export_rate(good), price, amount)
+    }
+
+    /// the duty owed on selling the given amount of a good from the hold here
+    pub fn import_duty(&self, good: &Good, amount: u32) -> u32 {
+        let price = self.sale_price(good, amount);
+        duty(self.customs().import_rate(good), price, amount)
+    }
+
+    /// the most of a good the player's gold can buy here, duty included
+    pub fn can_afford(&self, good: &Good) -> u32 {
+        let price = *self
+            .locations
+            .location_info(&self.location)
+            .prices
+            .get_good(good);
+        let gold = u64::from(self.gold.0);
+        let cost = |amount: u32| {
+            u64::from(amount) * u64::from(self.trade_price(good, amount as i64))
+                + u64::from(self.export_duty(good, amount))
+        };
+        // each unit costs at least the price now, and the cost only grows with the amount
+        let (mut low, mut high) = (0, (gold / u64::from(price.max(1))) as u32);
+        while low < high {
+            let mid = low + (high - low).div_ceil(2);
+            if cost(mid) <= gold {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+        low
+    }
+
+    /// what each unit of the given amount of a good costs to buy here (or fetches when selling,
+    /// when negative) on average, before duty and the quality of the goods
+    pub fn trade_price(&self, good: &Good, amount: i64) -> u32 {
+        let price = *self
+            .locations
+            .location_info(&self.location)
+            .prices
+            .get_good(good);
+        average_trade_price(price, amount, self.rules.market_impact)
+    }
+
+    /// what the price of a good here would become after buying (or selling, when negative)
+    /// the given amount of it
+    pub fn price_after_trade(&self, good: &Good, amount: i64) -> u32 {
+        let price = *self
+            .locations
+            .location_info(&self.location)
+            .prices
+            .get_good(good);
+        price_after_trade(price, amount, self.rules.market_impact)
+    }
+
+    /// moves the local price of a good after buying (or selling, when negative) some of it
+    fn trade(&mut self, good: &Good, amount: i64) {
+        if amount == 0 {
+            return;
+        }
+        let new_price = self.price_after_trade(good, amount);
+        *self
+            .locations
+            .location_info_mut(&self.location)
+            .prices
+            .get_good_mut(good) = new_price;
+        self.markets.get_mut(&self.location).trade(
+            good,
+            amount,
+            self.rules.market_impact,
+            self.date,
+        );
+    }
+
+    /// adds an event to the ledger, as of the current date and location
+    fn record(&mut self, event: LedgerEvent) {
+        self.record_at(self.location, event);
+    }
+
+    /// records something that happened away from the current port
+    fn record_at(&mut self, location: Location, event: LedgerEvent) {
+        self.ledger.record(LedgerEntry {
+            date: self.date,
+            location,
+            event,
+            gold: self.gold.0,
+        });
+    }
+
+    pub fn open_captains_log(&mut self) -> Result<&mut GameState, StateError> {
+        self.require_viewing_inventory()?;
+        self.mode = Mode::CaptainsLog(0);
+        Ok(self)
+    }
+
+    pub fn open_price_history(&mut self) -> Result<&mut GameState, StateError> {
+        self.require_viewing_inventory()?;
+        self.mode = Mode::PriceHistory(Good::Tea);
+        Ok(self)
+    }
+
+    pub fn open_market_rumors(&mut self) -> Result<&mut GameState, StateError> {
+        self.require_viewing_inventory()?;
+        self.mode = Mode::MarketRumors;
+        Ok(self)
+    }
+
+    pub fn open_contracts(&mut self) -> Result<&mut GameState, StateError> {
+        self.require_viewing_inventory()?;
+        self.mode = Mode::Contracts;
+        Ok(self)
+    }
+
+    /// takes on the contract at the given index of the current port's board
+    pub fn accept_contract(&mut self, index: usize) -> Result<&mut GameState, StateError> {
+        if let Mode::Contracts = self.mode {
+            if self.contracts.len() >= self.rules.max_active_contracts as usize {
+                return Err(StateError::TooManyContracts);
+            }
+            let board = &mut self.locations.location_info_mut(&self.location).contracts;
+            if index >= board.len() {
+                return Err(StateError::NoSuchContract);
+            }
+            let offer = board.remove(index);
+            self.contracts.push(offer.accept(self.location, self.date));
+            Ok(self)
+        } else {
+            Err(StateError::InvalidMode(self.mode.clone()))
+        }
+    }
+
+    pub fn choose_price_history_good(&mut self, good: Good) -> Result<&mut GameState, StateError> {
+        if let Mode::PriceHistory(_) = &self.mode {
+            self.mode = Mode::PriceHistory(good);
+            Ok(self)
+        } else {
+            Err(StateError::InvalidMode(self.mode.clone()))
+        }
+    }
+
+    /// scrolls the Captain's Log back in time by the given number of entries,
+    /// or forward for negative numbers, keeping a full page of entries in view
+    pub fn scroll_captains_log(
+        &mut self,
+        entries: isize,
+        page_size: usize,
+    ) -> Result<&mut GameState, StateError> {
+        if let Mode::CaptainsLog(scroll) = &mut self.mode {
+            let max_scroll = self.ledger.entries.len().saturating_sub(page_size);
+            *scroll = scroll.saturating_add_signed(entries).min(max_scroll);
+            Ok(self)
+        } else {
+            Err(StateError::InvalidMode(self.mode.clone()))
+        }
+    }
+
     pub(crate) fn compute_goods_stolen(&mut self) -> GoodsStolenResult {
         if let Mode::GameEvent(event) = &mut self.mode {
             if let LocationEvent::GoodsStolen(info) = event.borrow_mut() {
@@ -604,14 +1596,136 @@ impl GameState {
 
     pub(crate) fn remove_stolen_goods(&mut self, goods_stolen_info: GoodsStolenResult) {
         if let GoodsStolenResult::WasStolen { good, amount } = goods_stolen_info {
+            let held = *self.inventory.get_good(&good);
+            self.inventory_cost.remove(&good, amount, held);
+            self.inventory_lots.remove(&good, amount, held);
             self.inventory.remove_good(&good, amount);
+            self.record(LedgerEvent::GoodsStolen { good, amount });
+        }
+    }
+
+    pub(crate) fn take_found_goods(&mut self) -> Result<(), StateError> {
+        if let Mode::GameEvent(LocationEvent::FindGoods(good, amount)) = self.mode {
+            // only take as much as fits in the hold
+            let amount_to_add = min(amount, self.remaining_hold());
+            self.inventory.add_good(&good, amount_to_add);
+            self.inventory_lots.add(&good, amount_to_add, self.date);
+            if amount_to_add > 0 {
+                self.record(LedgerEvent::FoundGoods {
+                    good,
+                    amount: amount_to_add,
+                });
+            }
+            self.acknowledge_event()?;
+            Ok(())
+        } else {
+            Err(StateError::InvalidMode(self.mode.clone()))
+        }
+    }
+
+    /// what the collectors for an overdue loan will take
+    pub fn debt_collection(&self, lender: &Location, months_overdue: u32) -> DebtCollection {
+        DebtCollection::new(months_overdue, self.gold.0, self.loans.get(lender).balance)
+    }
+
+    pub(crate) fn pay_debt_collectors(&mut self) -> Result<(), StateError> {
+        if let Mode::GameEvent(LocationEvent::DebtCollectors {
+            lender,
+            months_overdue,
+        }) = self.mode
+        {
+            let collection = self.debt_collection(&lender, months_overdue);
+            self.gold -= collection.gold;
+            let loan = self.loans.get_mut(&lender);
+            loan.repay(collection.gold);
+            let mut goods = 0;
+            if collection.seizes_cargo {
+                // the cargo is counted against the debt at its usual worth
+                let worth = self
+                    .inventory
+                    .net_worth(&self.location_config.overall_price_ranges);
+                loan.repay(worth.max(0) as u32);
+                goods = self.inventory.total_amount();
+                self.inventory = Inventory::default();
+                self.inventory_cost = CostBasis::default();
+                self.inventory_lots = Lots::default();
+            }
+            self.record(LedgerEvent::DebtCollected {
+                gold: collection.gold,
+                goods,
+            });
+            self.acknowledge_event()?;
+            Ok(())
+        } else {
+            Err(StateError::InvalidMode(self.mode.clone()))
+        }
+    }
+
+    /// what customs officers find in the hold here, and what they want for it
+    pub fn customs_inspection(&self) -> CustomsInspection {
+        CustomsInspection::new(
+            self.customs(),
+            &self.inventory,
+            &self.locations.location_info(&self.location).prices,
+            self.gold.0,
+            (self.rules.customs_fine, self.rules.customs_bribe),
+        )
+    }
+
+    /// pays customs officers to look the other way, if there's gold enough
+    pub(crate) fn bribe_customs(&mut self) -> Result<(), StateError> {
+        if let Mode::GameEvent(LocationEvent::CustomsInspection) = self.mode {
+            let inspection = self.customs_inspection();
+            if self.gold.0 >= inspection.bribe {
+                self.gold -= inspection.bribe;
+                if inspection.bribe > 0 {
+                    self.record(LedgerEvent::CustomsBribe(inspection.bribe));
+                }
+                self.acknowledge_event()?;
+            }
+            Ok(())
+        } else {
+            Err(StateError::InvalidMode(self.mode.clone()))
+        }
+    }
+
+    /// lets customs officers seize the contraband and fine the player
+    pub(crate) fn submit_to_customs(&mut self) -> Result<(), StateError> {
+        if let Mode::GameEvent(LocationEvent::CustomsInspection) = self.mode {
+            let inspection = self.customs_inspection();
+            for (good, amount) in inspection.contraband.iter() {
+                if *amount > 0 {
+                    let held = *self.inventory.get_good(&good);
+                    self.inventory_cost.remove(&good, *amount, held);
+                    self.inventory_lots.remove(&good, *amount, held);
+                    self.inventory.remove_good(&good, *amount);
+                    self.record(LedgerEvent::Confiscated {
+                        good,
+                        amount: *amount,
+                    });
+                }
+            }
+            if inspection.fine > 0 {
+                self.gold -= inspection.fine;
+                self.record(LedgerEvent::CustomsFine(inspection.fine));
+            }
+            self.acknowledge_event()?;
+            Ok(())
+        } else {
+            Err(StateError::InvalidMode(self.mode.clone()))
         }
     }
 
     pub(crate) fn confirm_buy_cannon(&mut self) -> Result<(), StateError> {
-        if self.gold.0 >= CANNON_COST.into() {
-            self.gold -= Saturating(CANNON_COST as u32);
+        if self.cannons_full() {
+            return Err(StateError::NoRoomForCannons);
+        }
+        if self.gold.0 >= self.rules.cannon_cost {
+            self.gold -= Saturating(self.rules.cannon_cost);
             self.cannons += 1;
+            self.record(LedgerEvent::BoughtCannon {
+                price: self.rules.cannon_cost,
+            });
             self.acknowledge_event()?;
         }
         Ok(())
@@ -621,10 +1735,26 @@ impl GameState {
         if let Mode::GameEvent(LocationEvent::PirateEncounter(PirateEncounterState::Initial)) =
             self.mode
         {
-            let pirates = self.rng.gen_num_pirates_encountered();
+            let captain = self.rng.gen_pirate_captain(
+                &self.pirates.at_large(&self.location),
+                self.rules.named_captain_chance,
+            );
+            let (min, max) = self.difficulty.pirates_encountered(&self.rules);
+            // a captain's own fleet brings more ships the stronger they are
+            let strength = captain.map_or(0, |captain| captain.info().strength);
+            let pirates = self.rng.gen_num_pirates_encountered((
+                min.saturating_add(strength),
+                max.saturating_add(strength),
+            ));
+            if let Some(captain) = captain {
+                self.pirates.get_mut(captain).encounters += 1;
+            }
             self.mode = Mode::GameEvent(LocationEvent::PirateEncounter(
                 PirateEncounterState::Prompt {
-                    info: PirateEncounterInfo::new(pirates),
+                    info: PirateEncounterInfo {
+                        captain,
+                        ..PirateEncounterInfo::new(pirates, self.hull())
+                    },
                 },
             ));
             Ok(())
@@ -638,7 +1768,8 @@ impl GameState {
             info,
         })) = self.mode
         {
-            if self.rng.gen_run_success(info.cur_pirates) {
+            self.ran_from(info.captain);
+            if self.rng.gen_run_success(info.cur_pirates, self.manning()) {
                 // success
                 self.mode = Mode::GameEvent(LocationEvent::PirateEncounter(
                     PirateEncounterState::RunSuccess,
@@ -660,7 +1791,7 @@ impl GameState {
             info,
         })) = self.mode
         {
-            let did_kill_a_pirate = self.rng.gen_did_kill_a_pirate(self.cannons.0);
+            let did_kill_a_pirate = self.rng.gen_did_kill_a_pirate(self.cannons_manned());
             self.mode = Mode::GameEvent(LocationEvent::PirateEncounter(
                 PirateEncounterState::AttackResult {
                     info,
@@ -673,6 +1804,304 @@ impl GameState {
         }
     }
 
+    /// the gold the pirates ask to leave, which is more the more of them there are
+    pub fn pirate_bribe(&self, cur_pirates: u8) -> u32 {
+        self.rules
+            .pirate_bribe_gold
+            .saturating_mul(cur_pirates.into())
+    }
+
+    /// the given share of every good in the hold, rounded up
+    fn share_of_cargo(&self, share: f64) -> Vec<(Good, u32)> {
+        self.inventory
+            .iter()
+            .filter(|(_, amount)| **amount > 0)
+            .map(|(good, amount)| (good, (*amount as f64 * share).ceil() as u32))
+            .collect()
+    }
+
+    /// takes the given goods out of the hold, returning how many there were in all
+    fn remove_cargo(&mut self, cargo: &[(Good, u32)]) -> u32 {
+        for (good, amount) in cargo {
+            let held = *self.inventory.get_good(good);
+            self.inventory_cost.remove(good, *amount, held);
+            self.inventory_lots.remove(good, *amount, held);
+            self.inventory.remove_good(good, *amount);
+        }
+        cargo.iter().map(|(_, amount)| amount).sum()
+    }
+
+    fn pirate_prompt_info(&self) -> Result<PirateEncounterInfo, StateError> {
+        if let Mode::GameEvent(LocationEvent::PirateEncounter(PirateEncounterState::Prompt {
+            info,
+        })) = self.mode
+        {
+            Ok(info)
+        } else {
+            Err(StateError::InvalidMode(self.mode.clone()))
+        }
+    }
+
+    pub(crate) fn pirate_broadside(&mut self) -> Result<(), StateError> {
+        let info = self.pirate_prompt_info()?;
+        let sunk = self
+            .rng
+            .gen_ships_sunk_by_broadside(self.cannons_manned(), info.cur_pirates);
+        self.mode = Mode::GameEvent(LocationEvent::PirateEncounter(
+            PirateEncounterState::BroadsideResult { info, sunk },
+        ));
+        Ok(())
+    }
+
+    pub(crate) fn pirate_board(&mut self) -> Result<(), StateError> {
+        let info = self.pirate_prompt_info()?;
+        let plunder = if self
+            .rng
+            .gen_boarding_success(info.cur_pirates, self.manning())
+        {
+            let plunder = self.rng.gen_gold_recovered_from_pirate_encounter(
+                1,
+                (
+                    self.rules.gold_per_pirate_victory_min,
+                    self.rules.gold_per_pirate_victory_max,
+                ),
+            );
+            self.gold += plunder;
+            self.record(LedgerEvent::BoardedPirates { plunder });
+            Some(plunder)
+        } else {
+            None
+        };
+        self.mode = Mode::GameEvent(LocationEvent::PirateEncounter(
+            PirateEncounterState::BoardResult { info, plunder },
+        ));
+        Ok(())
+    }
+
+    pub(crate) fn pirate_bribe_gold(&mut self) -> Result<(), StateError> {
+        let info = self.pirate_prompt_info()?;
+        let gold = self.pirate_bribe(info.cur_pirates);
+        if self.gold.0 < gold {
+            return Err(StateError::CannotAfford);
+        }
+        let accepted = self.rng.gen_gold_bribe_accepted(info.cur_pirates);
+        if accepted {
+            self.gold -= gold;
+            self.record(LedgerEvent::BribedPirates { gold, goods: 0 });
+        }
+        self.mode = Mode::GameEvent(LocationEvent::PirateEncounter(
+            PirateEncounterState::BribeResult {
+                info,
+                offer: PirateBribe::Gold(gold),
+                accepted,
+            },
+        ));
+        Ok(())
+    }
+
+    pub(crate) fn pirate_bribe_cargo(&mut self) -> Result<(), StateError> {
+        let info = self.pirate_prompt_info()?;
+        if self.inventory.total_amount() == 0 {
+            return Err(StateError::InsufficientInventory);
+        }
+        let cargo = self.share_of_cargo(self.rules.cargo_bribe_share);
+        let accepted = self.rng.gen_cargo_bribe_accepted(info.cur_pirates);
+        let goods = if accepted {
+            let goods = self.remove_cargo(&cargo);
+            self.record(LedgerEvent::BribedPirates { gold: 0, goods });
+            goods
+        } else {
+            cargo.iter().map(|(_, amount)| amount).sum()
+        };
+        self.mode = Mode::GameEvent(LocationEvent::PirateEncounter(
+            PirateEncounterState::BribeResult {
+                info,
+                offer: PirateBribe::Cargo(goods),
+                accepted,
+            },
+        ));
+        Ok(())
+    }
+
+    pub(crate) fn pirate_surrender(&mut self) -> Result<(), StateError> {
+        let info = self.pirate_prompt_info()?;
+        if self.inventory.total_amount() == 0 {
+            return Err(StateError::InsufficientInventory);
+        }
+        let share = self.rng.gen_share_of_hold_surrendered(info.cur_pirates);
+        let cargo = self.share_of_cargo(share);
+        let goods = self.remove_cargo(&cargo);
+        self.record(LedgerEvent::SurrenderedCargo { goods });
+        self.mode = Mode::GameEvent(LocationEvent::PirateEncounter(
+            PirateEncounterState::Surrendered { goods },
+        ));
+        Ok(())
+    }
+
+    pub(crate) fn pirate_dump_cargo(&mut self) -> Result<(), StateError> {
+        let info = self.pirate_prompt_info()?;
+        if self.inventory.total_amount() == 0 {
+            return Err(StateError::InsufficientInventory);
+        }
+        let cargo = self.share_of_cargo(self.rules.dump_cargo_share);
+        let dumped = self.remove_cargo(&cargo);
+        self.record(LedgerEvent::DumpedCargo { goods: dumped });
+        self.ran_from(info.captain);
+        let escaped = self
+            .rng
+            .gen_run_success_after_dumping(info.cur_pirates, self.manning());
+        self.mode = Mode::GameEvent(LocationEvent::PirateEncounter(
+            PirateEncounterState::DumpResult {
+                info,
+                dumped,
+                escaped,
+            },
+        ));
+        Ok(())
+    }
+
+    /// running from a named captain only adds to the stories told of them
+    fn ran_from(&mut self, captain: Option<PirateCaptain>) {
+        if let Some(captain) = captain {
+            self.pirates.get_mut(captain).runs += 1;
+        }
+    }
+
+    /// what the port authorities pay for sinking the given captain's fleet, which is more
+    /// the more infamous they've become
+    pub fn bounty(&self, captain: PirateCaptain) -> u32 {
+        let infamy = self.pirates.get(captain).infamy();
+        captain
+            .info()
+            .bounty
+            .saturating_add(infamy.saturating_mul(self.rules.bounty_per_infamy))
+    }
+
+    /// every pirate ship sunk or taken, with gold recovered from the wreckage of those not
+    /// already plundered by boarding, and a bounty
+    /// if they sailed under a named captain
+    fn pirate_victory(&mut self, info: PirateEncounterInfo) -> PirateEncounterState {
+        PirateEncounterState::Victory {
+            gold_recovered: match info.total_pirates.saturating_sub(info.boarded) {
+                0 => 0,
+                wrecked => self.rng.gen_gold_recovered_from_pirate_encounter(
+                    wrecked,
+                    (
+                        self.rules.gold_per_pirate_victory_min,
+                        self.rules.gold_per_pirate_victory_max,
+                    ),
+                ),
+            },
+            bounty: info.captain.map(|captain| Bounty {
+                captain,
+                gold: self.bounty(captain),
+            }),
+        }
+    }
+
+    /// with some pirate ships sunk or taken, the fight is won or the rest attack
+    fn after_pirates_sunk(
+        &mut self,
+        info: PirateEncounterInfo,
+        sunk: u8,
+    ) -> Result<(), StateError> {
+        let cur_pirates = info.cur_pirates.saturating_sub(sunk);
+        let state = if cur_pirates == 0 {
+            self.pirate_victory(info)
+        } else {
+            PirateEncounterState::pirates_attack(
+                PirateEncounterInfo {
+                    cur_pirates,
+                    ..info
+                },
+                &mut self.rng,
+            )?
+        };
+        self.mode = Mode::GameEvent(LocationEvent::PirateEncounter(state));
+        Ok(())
+    }
+
+    pub(crate) fn proceed_broadside_result(&mut self) -> Result<(), StateError> {
+        if let Mode::GameEvent(LocationEvent::PirateEncounter(
+            PirateEncounterState::BroadsideResult { info, sunk },
+        )) = self.mode
+        {
+            self.after_pirates_sunk(info, sunk)
+        } else {
+            Err(StateError::InvalidMode(self.mode.clone()))
+        }
+    }
+
+    pub(crate) fn proceed_board_result(&mut self) -> Result<(), StateError> {
+        if let Mode::GameEvent(LocationEvent::PirateEncounter(
+            PirateEncounterState::BoardResult { info, plunder },
+        )) = self.mode
+        {
+            if plunder.is_some() {
+                let info = PirateEncounterInfo {
+                    boarded: info.boarded.saturating_add(1),
+                    ..info
+                };
+                self.after_pirates_sunk(info, 1)
+            } else {
+                self.after_pirates_sunk(info, 0)
+            }
+        } else {
+            Err(StateError::InvalidMode(self.mode.clone()))
+        }
+    }
+
+    pub(crate) fn proceed_bribe_result(&mut self) -> Result<(), StateError> {
+        if let Mode::GameEvent(LocationEvent::PirateEncounter(
+            PirateEncounterState::BribeResult { info, accepted, .. },
+        )) = self.mode
+        {
+            if accepted {
+                self.next_event();
+            } else {
+                self.mode = Mode::GameEvent(LocationEvent::PirateEncounter(
+                    PirateEncounterState::pirates_attack(info, &mut self.rng)?,
+                ));
+            }
+            Ok(())
+        } else {
+            Err(StateError::InvalidMode(self.mode.clone()))
+        }
+    }
+
+    pub(crate) fn proceed_surrendered(&mut self) -> Result<(), StateError> {
+        if let Mode::GameEvent(LocationEvent::PirateEncounter(
+            PirateEncounterState::Surrendered { .. },
+        )) = self.mode
+        {
+            self.next_event();
+            Ok(())
+        } else {
+            Err(StateError::InvalidMode(self.mode.clone()))
+        }
+    }
+
+    pub(crate) fn proceed_dump_result(&mut self) -> Result<(), StateError> {
+        if let Mode::GameEvent(LocationEvent::PirateEncounter(PirateEncounterState::DumpResult {
+            info,
+            escaped,
+            ..
+        })) = self.mode
+        {
+            if escaped {
+                self.record(LedgerEvent::EscapedPirates);
+                self.next_event();
+            } else {
+                self.mode = Mode::GameEvent(LocationEvent::PirateEncounter(
+                    PirateEncounterState::pirates_attack(info, &mut self.rng)?,
+                ));
+            }
+            Ok(())
+        } else {
+            Err(StateError::InvalidMode(self.mode.clone()))
+        }
+    }
+
     pub(crate) fn proceed_pirate_run_failure(&mut self) -> Result<(), StateError> {
         if let Mode::GameEvent(LocationEvent::PirateEncounter(PirateEncounterState::RunFailure {
             info,
@@ -695,13 +2124,20 @@ impl GameState {
                         health,
                         cur_pirates,
                         total_pirates,
+                        captain,
+                        boarded,
                     },
                 damage_this_attack,
             },
         )) = self.mode
         {
             let health = health.saturating_sub(damage_this_attack);
+            // the damage stays with the ship after the fight
+            self.hull_damage = self.ship_class.info().hull.saturating_sub(health);
             if health == 0 {
+                if let Some(captain) = captain {
+                    self.pirates.get_mut(captain).wins += 1;
+                }
                 self.mode = Mode::GameEvent(LocationEvent::PirateEncounter(
                     PirateEncounterState::Destroyed,
                 ));
@@ -712,6 +2148,8 @@ impl GameState {
                             health,
                             cur_pirates,
                             total_pirates,
+                            captain,
+                            boarded,
                         },
                     },
                 ));
@@ -727,9 +2165,17 @@ impl GameState {
             self.mode
         {
             // player loses their inventory and half of their gold to the pirates
+            let goods = self.inventory.total_amount();
+            let gold = self.gold.0 / 2;
             self.inventory = Inventory::default();
+            self.inventory_cost = CostBasis::default();
+            self.inventory_lots = Lots::default();
             self.gold = Saturating(self.gold.0.div_ceil(2));
-            self.mode = Mode::ViewingInventory;
+            // what's left of the ship limps into port, barely afloat, and the crew with it
+            self.hull_damage = self.ship_class.info().hull.saturating_sub(1);
+            self.crew.lower_morale(self.rules.defeat_morale_loss);
+            self.record(LedgerEvent::LostToPirates { goods, gold });
+            self.next_event();
             Ok(())
         } else {
             Err(StateError::InvalidMode(self.mode.clone()))
@@ -740,7 +2186,8 @@ impl GameState {
         if let Mode::GameEvent(LocationEvent::PirateEncounter(PirateEncounterState::RunSuccess)) =
             self.mode
         {
-            self.mode = Mode::ViewingInventory;
+            self.record(LedgerEvent::EscapedPirates);
+            self.next_event();
             Ok(())
         } else {
             Err(StateError::InvalidMode(self.mode.clone()))
@@ -760,12 +2207,8 @@ impl GameState {
                     .saturating_sub(if did_kill_a_pirate { 1 } else { 0 });
             if cur_pirates == 0 {
                 // player recovers some gold from wreckage
-                let gold_recovered = self
-                    .rng
-                    .gen_gold_recovered_from_pirate_encounter(info.total_pirates);
-                self.mode = Mode::GameEvent(LocationEvent::PirateEncounter(
-                    PirateEncounterState::Victory { gold_recovered },
-                ));
+                let victory = self.pirate_victory(info);
+                self.mode = Mode::GameEvent(LocationEvent::PirateEncounter(victory));
             } else {
                 self.mode = Mode::GameEvent(LocationEvent::PirateEncounter(
                     PirateEncounterState::pirates_attack(
@@ -773,6 +2216,8 @@ impl GameState {
                             health: info.health,
                             cur_pirates,
                             total_pirates: info.cur_pirates,
+                            captain: info.captain,
+                            boarded: 0,
                         },
                         &mut self.rng,
                     )?,
@@ -787,10 +2232,18 @@ impl GameState {
     pub(crate) fn proceed_pirate_encounter_victory(&mut self) -> Result<(), StateError> {
         if let Mode::GameEvent(LocationEvent::PirateEncounter(PirateEncounterState::Victory {
             gold_recovered,
+            bounty,
         })) = self.mode
         {
             self.gold += gold_recovered;
-            self.mode = Mode::ViewingInventory;
+            self.record(LedgerEvent::DefeatedPirates { gold_recovered });
+            // the captain went down with their fleet, and the port pays for the news
+            if let Some(Bounty { captain, gold }) = bounty {
+                self.pirates.get_mut(captain).sunk = true;
+                self.gold += gold;
+                self.record(LedgerEvent::PirateBounty { captain, gold });
+            }
+            self.next_event();
             Ok(())
         } else {
             Err(StateError::InvalidMode(self.mode.clone()))
@@ -828,12 +2281,30 @@ impl GameState {
         } else if let Mode::PayDebt(None) = &self.mode {
             self.mode = Mode::ViewingInventory;
             Ok(())
+        } else if let Mode::Borrow(None) = &self.mode {
+            self.mode = Mode::ViewingInventory;
+            Ok(())
+        } else if let Mode::HireCrew(None) = &self.mode {
+            self.mode = Mode::ViewingInventory;
+            Ok(())
         } else if let Mode::BankDeposit(None) = &self.mode {
             self.mode = Mode::ViewingInventory;
             Ok(())
         } else if let Mode::BankWithdraw(None) = &self.mode {
             self.mode = Mode::ViewingInventory;
             Ok(())
+        } else if let Mode::CaptainsLog(_) = &self.mode {
+            self.mode = Mode::ViewingInventory;
+            Ok(())
+        } else if let Mode::PriceHistory(_) = &self.mode {
+            self.mode = Mode::ViewingInventory;
+            Ok(())
+        } else if let Mode::MarketRumors = &self.mode {
+            self.mode = Mode::ViewingInventory;
+            Ok(())
+        } else if let Mode::Contracts | Mode::Shipyard = &self.mode {
+            self.mode = Mode::ViewingInventory;
+            Ok(())
         } else {
             Err(StateError::InvalidMode(self.mode.clone()))
         }
@@ -847,13 +2318,18 @@ impl GameState {
         if self.gold.0 >= price {
             self.gold -= Saturating(price);
             self.hold_size += more_hold;
+            self.record(LedgerEvent::BoughtHoldSpace { price, more_hold });
             self.acknowledge_event()?;
         }
         Ok(())
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+fn unseeded_rng() -> Box<dyn MerchantRng> {
+    Box::new(StdRng::from_entropy())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum GoodsStolenResult {
     NothingStolen,
     WasStolen { good: Good, amount: u32 },
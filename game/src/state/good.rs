@@ -1,8 +1,17 @@
-use std::fmt::{self, Display};
+use std::{
+    fmt::{self, Display},
+    sync::{PoisonError, RwLock},
+};
 
 use rand::{rngs::StdRng, seq::SliceRandom, RngCore};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+use super::{goods_map::GoodsMap, Rules};
+
+/// The goods there are to trade, in the order of the [CATALOG]. The catalog gives each good the
+/// name it's saved under and its defaults, while its name, key, price tier and spread in play
+/// come from the [Rules]. Adding a good means adding a variant here along with its entry there.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
 pub enum Good {
     Tea,
     Coffee,
@@ -10,45 +19,200 @@ pub enum Good {
     Tobacco,
     Rum,
     Cotton,
+    Spices,
+    Silk,
+    Indigo,
+    Porcelain,
+}
+
+/// What there is to know about a good: what it's called, the key that picks it
+/// and where its prices sit, unless the rules say otherwise.
+#[derive(Debug)]
+pub struct GoodInfo {
+    pub good: Good,
+    pub name: &'static str,
+    /// the letter that picks the good, alongside its number in the catalog
+    pub key: char,
+    /// the good's average price, as a multiple of the average price of the cheapest good
+    pub price_tier: f64,
+    /// how far the high price of the good is above its low price,
+    /// eg. 5.0 means the high price is 6x the low price
+    pub price_spread: f64,
 }
 
+/// Every good there is, in the order they're listed and numbered in.
+/// The keys are the first letter of each name not already taken. Only the first ten goods have
+/// number keys as well, so every good needs a letter. The screens fit up to twelve goods.
+/// The names here are the ones goods are saved under, whatever the rules call them.
+pub const CATALOG: &[GoodInfo] = &[
+    GoodInfo {
+        good: Good::Tea,
+        name: "Tea",
+        key: 't',
+        price_tier: 334.15,
+        price_spread: 0.75,
+    },
+    GoodInfo {
+        good: Good::Coffee,
+        name: "Coffee",
+        key: 'c',
+        price_tier: 185.64,
+        price_spread: 1.0,
+    },
+    GoodInfo {
+        good: Good::Sugar,
+        name: "Sugar",
+        key: 's',
+        price_tier: 71.4,
+        price_spread: 1.5,
+    },
+    GoodInfo {
+        good: Good::Tobacco,
+        name: "Tobacco",
+        key: 'a',
+        price_tier: 21.0,
+        price_spread: 2.0,
+    },
+    GoodInfo {
+        good: Good::Rum,
+        name: "Rum",
+        key: 'r',
+        price_tier: 5.0,
+        price_spread: 3.0,
+    },
+    GoodInfo {
+        good: Good::Cotton,
+        name: "Cotton",
+        key: 'o',
+        price_tier: 1.0,
+        price_spread: 5.0,
+    },
+    GoodInfo {
+        good: Good::Spices,
+        name: "Spices",
+        key: 'p',
+        price_tier: 120.0,
+        price_spread: 1.25,
+    },
+    GoodInfo {
+        good: Good::Silk,
+        name: "Silk",
+        key: 'i',
+        price_tier: 260.0,
+        price_spread: 0.9,
+    },
+    GoodInfo {
+        good: Good::Indigo,
+        name: "Indigo",
+        key: 'n',
+        price_tier: 12.0,
+        price_spread: 2.5,
+    },
+    GoodInfo {
+        good: Good::Porcelain,
+        name: "Porcelain",
+        key: 'e',
+        price_tier: 400.0,
+        price_spread: 0.6,
+    },
+];
+
+/// the names and keys of the goods in the game being played, if its rules give them any
+static NAMES_AND_KEYS: RwLock<Option<(GoodsMap<String>, GoodsMap<char>)>> = RwLock::new(None);
+
 impl Display for Good {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let string = match self {
-            Good::Tea => "Tea",
-            Good::Coffee => "Coffee",
-            Good::Sugar => "Sugar",
-            Good::Tobacco => "Tobacco",
-            Good::Rum => "Rum",
-            Good::Cotton => "Cotton",
-        };
-        write!(f, "{}", string)
+        write!(f, "{}", self.name())
     }
 }
 
-static VARIANTS: &[Good] = &[
-    Good::Tea,
-    Good::Coffee,
-    Good::Sugar,
-    Good::Tobacco,
-    Good::Rum,
-    Good::Cotton,
-];
-
 impl Good {
-    pub fn random(rng: &mut StdRng) -> Good {
-        match rng.next_u32() % 6 {
-            0 => Good::Tea,
-            1 => Good::Coffee,
-            2 => Good::Sugar,
-            3 => Good::Tobacco,
-            4 => Good::Rum,
-            _ => Good::Cotton,
+    /// how many goods there are in the catalog
+    pub const COUNT: usize = CATALOG.len();
+
+    /// the longest name the screens have room for
+    pub const MAX_NAME_LENGTH: usize = 9;
+
+    pub fn info(&self) -> &'static GoodInfo {
+        &CATALOG[self.index()]
+    }
+
+    /// goes by the names and keys the given rules give the goods from now on.
+    /// called as each game starts, since goods are shown all over without the rules to hand
+    pub fn use_names_and_keys(rules: &Rules) {
+        *NAMES_AND_KEYS
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) =
+            Some((rules.good_names.clone(), rules.good_keys.clone()));
+    }
+
+    /// what the good is called in the game being played
+    pub fn name(&self) -> String {
+        match &*NAMES_AND_KEYS.read().unwrap_or_else(PoisonError::into_inner) {
+            Some((names, _)) => names.get_good(self).clone(),
+            None => self.info().name.to_owned(),
+        }
+    }
+
+    /// the letter that picks the good in the game being played
+    pub fn key(&self) -> char {
+        match &*NAMES_AND_KEYS.read().unwrap_or_else(PoisonError::into_inner) {
+            Some((_, keys)) => *keys.get_good(self),
+            None => self.info().key,
         }
     }
 
+    /// where the good is in the catalog
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    /// the number key that picks the good, for the first ten goods in the catalog.
+    /// the rest are picked by their letter alone
+    pub fn number_key(&self) -> Option<u8> {
+        match self.index() {
+            index @ 0..=8 => Some(index as u8 + 1),
+            9 => Some(0),
+            _ => None,
+        }
+    }
+
+    /// the good picked by a key, either its number or its letter
+    pub fn from_key(c: char) -> Option<Good> {
+        Self::variants_iter()
+            .find(|good| {
+                good.key() == c
+                    || good
+                        .number_key()
+                        .and_then(|n| char::from_digit(n.into(), 10))
+                        == Some(c)
+            })
+            .copied()
+    }
+
+    /// the length of the longest name of a good, for lining up lists of goods
+    pub fn longest_name() -> usize {
+        Self::variants_iter()
+            .map(|good| good.name().len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn random(rng: &mut StdRng) -> Good {
+        CATALOG[rng.next_u32() as usize % Self::COUNT].good
+    }
+
     pub fn variants() -> &'static [Good] {
-        VARIANTS
+        static VARIANTS: [Good; Good::COUNT] = {
+            let mut variants = [Good::Tea; Good::COUNT];
+            let mut i = 0;
+            while i < Good::COUNT {
+                variants[i] = CATALOG[i].good;
+                i += 1;
+            }
+            variants
+        };
+        &VARIANTS
     }
 
     pub fn variants_iter() -> impl Iterator<Item = &'static Good> {
@@ -61,3 +225,31 @@ impl Good {
         variants
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn catalog() {
+        for (index, info) in CATALOG.iter().enumerate() {
+            // goods are looked up in the catalog by their position in it
+            assert_eq!(info.good.index(), index);
+            // the letter is underlined in the name, and 'b' is always back
+            assert!(info.name.to_ascii_lowercase().contains(info.key));
+            assert_ne!(info.key, 'b');
+            assert_eq!(Good::from_key(info.key), Some(info.good));
+        }
+        let keys = CATALOG.iter().map(|info| info.key).collect::<Vec<char>>();
+        assert!(keys
+            .iter()
+            .enumerate()
+            .all(|(i, key)| !keys[..i].contains(key)));
+        assert_eq!(Good::from_key('1'), Some(Good::Tea));
+        assert_eq!(Good::from_key('0'), Some(Good::Porcelain));
+        assert_eq!(Good::Porcelain.to_string(), "Porcelain");
+        assert_eq!(Good::longest_name(), 9);
+    }
+}
@@ -1,88 +1,54 @@
-use std::{collections::HashMap, fmt};
+use std::{
+    collections::HashMap,
+    fmt,
+    marker::PhantomData,
+    ops::{Index, IndexMut},
+};
+
+use serde::{
+    de::{MapAccess, Visitor},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
 
 use super::Good;
 
-/// A collection of generic information about each Good type there is.
-pub struct GoodsMap<T> {
-    pub tea: T,
-    pub coffee: T,
-    pub sugar: T,
-    pub tobacco: T,
-    pub rum: T,
-    pub cotton: T,
-}
-
-impl<T: Clone> Clone for GoodsMap<T> {
-    fn clone(&self) -> Self {
-        Self {
-            tea: self.tea.clone(),
-            coffee: self.coffee.clone(),
-            sugar: self.sugar.clone(),
-            tobacco: self.tobacco.clone(),
-            rum: self.rum.clone(),
-            cotton: self.cotton.clone(),
-        }
-    }
-}
+/// A collection of generic information about each Good type there is, in the order of the catalog.
+/// Written out as a map from the lowercase name of each good, and any good left out when
+/// reading one in gets the default.
+#[derive(Clone, PartialEq)]
+pub struct GoodsMap<T>([T; Good::COUNT]);
 
 impl<T: fmt::Debug> fmt::Debug for GoodsMap<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("GoodsMap")
-            .field("tea", &self.tea)
-            .field("coffee", &self.coffee)
-            .field("sugar", &self.sugar)
-            .field("tobacco", &self.tobacco)
-            .field("rum", &self.rum)
-            .field("cotton", &self.cotton)
-            .finish()
+        f.debug_map().entries(self.iter()).finish()
     }
 }
 
 impl<T: Default> Default for GoodsMap<T> {
     fn default() -> Self {
-        Self {
-            tea: Default::default(),
-            coffee: Default::default(),
-            sugar: Default::default(),
-            tobacco: Default::default(),
-            rum: Default::default(),
-            cotton: Default::default(),
-        }
+        Self::from_fn(|_| T::default())
     }
 }
 
-impl<T: PartialEq> PartialEq for GoodsMap<T> {
-    fn eq(&self, other: &Self) -> bool {
-        self.tea == other.tea
-            && self.coffee == other.coffee
-            && self.sugar == other.sugar
-            && self.tobacco == other.tobacco
-            && self.rum == other.rum
-            && self.cotton == other.cotton
+impl<T> GoodsMap<T> {
+    pub fn from_fn<F>(f: F) -> GoodsMap<T>
+    where
+        F: FnMut(Good) -> T,
+    {
+        let mut goods = Good::variants_iter().copied();
+        let mut f = f;
+        GoodsMap(std::array::from_fn(|_| {
+            f(goods.next().expect("there is a good for every entry"))
+        }))
     }
-}
 
-impl<T> GoodsMap<T> {
     pub fn get_good(&self, good_type: &Good) -> &T {
-        match good_type {
-            Good::Tea => &self.tea,
-            Good::Coffee => &self.coffee,
-            Good::Sugar => &self.sugar,
-            Good::Tobacco => &self.tobacco,
-            Good::Rum => &self.rum,
-            Good::Cotton => &self.cotton,
-        }
+        &self.0[good_type.index()]
     }
 
     pub fn get_good_mut(&mut self, good_type: &Good) -> &mut T {
-        match good_type {
-            Good::Tea => &mut self.tea,
-            Good::Coffee => &mut self.coffee,
-            Good::Sugar => &mut self.sugar,
-            Good::Tobacco => &mut self.tobacco,
-            Good::Rum => &mut self.rum,
-            Good::Cotton => &mut self.cotton,
-        }
+        &mut self.0[good_type.index()]
     }
 
     pub(crate) fn iter(&self) -> std::vec::IntoIter<(Good, &T)> {
@@ -93,14 +59,21 @@ impl<T> GoodsMap<T> {
     where
         F: Fn(&T) -> T,
     {
-        GoodsMap {
-            tea: f(&self.tea),
-            coffee: f(&self.coffee),
-            sugar: f(&self.sugar),
-            tobacco: f(&self.tobacco),
-            rum: f(&self.rum),
-            cotton: f(&self.cotton),
-        }
+        GoodsMap(std::array::from_fn(|i| f(&self.0[i])))
+    }
+}
+
+impl<T> Index<Good> for GoodsMap<T> {
+    type Output = T;
+
+    fn index(&self, good: Good) -> &T {
+        self.get_good(&good)
+    }
+}
+
+impl<T> IndexMut<Good> for GoodsMap<T> {
+    fn index_mut(&mut self, good: Good) -> &mut T {
+        self.get_good_mut(&good)
     }
 }
 
@@ -109,14 +82,10 @@ impl<'a, T> IntoIterator for &'a GoodsMap<T> {
     type IntoIter = std::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
-        let v = vec![
-            (Good::Tea, &self.tea),
-            (Good::Coffee, &self.coffee),
-            (Good::Sugar, &self.sugar),
-            (Good::Tobacco, &self.tobacco),
-            (Good::Rum, &self.rum),
-            (Good::Cotton, &self.cotton),
-        ];
+        let v = Good::variants_iter()
+            .copied()
+            .zip(self.0.iter())
+            .collect::<Vec<_>>();
         v.into_iter()
     }
 }
@@ -126,47 +95,92 @@ impl<T> IntoIterator for GoodsMap<T> {
     type IntoIter = std::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
-        let v = vec![
-            (Good::Tea, self.tea),
-            (Good::Coffee, self.coffee),
-            (Good::Sugar, self.sugar),
-            (Good::Tobacco, self.tobacco),
-            (Good::Rum, self.rum),
-            (Good::Cotton, self.cotton),
-        ];
+        let v = Good::variants_iter()
+            .copied()
+            .zip(self.0)
+            .collect::<Vec<_>>();
         v.into_iter()
     }
 }
 
-impl <T> From<HashMap<Good, T>> for GoodsMap<T> {
+impl<T: Default> From<HashMap<Good, T>> for GoodsMap<T> {
     fn from(mut value: HashMap<Good, T>) -> Self {
-        GoodsMap {
-            tea: value
-                .remove(&Good::Tea)
-                .expect("expectation failed: tea present in hashmap"),
-            coffee: value
-                .remove(&Good::Coffee)
-                .expect("expectation failed: coffee present in hashmap"),
-            sugar: value
-                .remove(&Good::Sugar)
-                .expect("expectation failed: sugar present in hashmap"),
-            tobacco: value
-                .remove(&Good::Tobacco)
-                .expect("expectation failed: tobacco present in hashmap"),
-            rum: value
-                .remove(&Good::Rum)
-                .expect("expectation failed: rum present in hashmap"),
-            cotton: value
-                .remove(&Good::Cotton)
-                .expect("expectation failed: cotton present in hashmap"),
-        }
+        GoodsMap::from_fn(|good| value.remove(&good).unwrap_or_default())
+    }
+}
+
+/// eg. `Inventory::from([(Good::Tea, 10)])`, where every good left out gets the default
+impl<T: Default, const N: usize> From<[(Good, T); N]> for GoodsMap<T> {
+    fn from(value: [(Good, T); N]) -> Self {
+        value.into_iter().collect()
     }
 }
 
-impl <T> FromIterator<(Good, T)> for GoodsMap<T> {
+impl<T: Default> FromIterator<(Good, T)> for GoodsMap<T> {
     fn from_iter<U: IntoIterator<Item = (Good, T)>>(iter: U) -> Self {
-        iter.into_iter()
-            .collect::<HashMap<Good, T>>()
-            .into()
+        iter.into_iter().collect::<HashMap<Good, T>>().into()
+    }
+}
+
+impl<T: Serialize> Serialize for GoodsMap<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(Good::COUNT))?;
+        for (good, value) in self {
+            map.serialize_entry(&good.info().name.to_ascii_lowercase(), value)?;
+        }
+        map.end()
     }
-}
\ No newline at end of file
+}
+
+impl<'de, T: Deserialize<'de> + Default> Deserialize<'de> for GoodsMap<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct GoodsMapVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de> + Default> Visitor<'de> for GoodsMapVisitor<T> {
+            type Value = GoodsMap<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a map from goods to values")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+                let mut goods = GoodsMap::default();
+                while let Some(name) = access.next_key::<String>()? {
+                    let good = Good::variants_iter()
+                        .find(|good| good.info().name.eq_ignore_ascii_case(&name))
+                        .ok_or_else(|| {
+                            serde::de::Error::custom(format!("unknown good `{}`", name))
+                        })?;
+                    goods[*good] = access.next_value()?;
+                }
+                Ok(goods)
+            }
+        }
+
+        deserializer.deserialize_map(GoodsMapVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn serde() {
+        let goods = GoodsMap::from([(Good::Tea, 3), (Good::Porcelain, 1)]);
+        let json = serde_json::to_string(&goods).unwrap();
+        assert!(json.starts_with(r#"{"tea":3,"coffee":0,"#));
+        assert_eq!(serde_json::from_str::<GoodsMap<u32>>(&json).unwrap(), goods);
+        // goods left out, eg. those added to the catalog since, are the default
+        assert_eq!(
+            serde_json::from_str::<GoodsMap<u32>>(r#"{"tea":3,"porcelain":1}"#).unwrap(),
+            goods
+        );
+        assert!(serde_json::from_str::<GoodsMap<u32>>(r#"{"gold":3}"#)
+            .unwrap_err()
+            .to_string()
+            .contains("unknown good `gold`"));
+    }
+}
@@ -4,11 +4,11 @@ pub type Inventory = GoodsMap<u32>;
 
 impl std::fmt::Display for Inventory {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{{tea:{},coffee:{},sugar:{},tobacco:{},rum:{},cotton:{}}}",
-            self.tea, self.coffee, self.sugar, self.tobacco, self.rum, self.cotton
-        )
+        let goods = self
+            .iter()
+            .map(|(good, amount)| format!("{}:{}", good.info().name.to_ascii_lowercase(), amount))
+            .collect::<Vec<String>>();
+        write!(f, "{{{}}}", goods.join(","))
     }
 }
 
@@ -36,12 +36,9 @@ impl Inventory {
     /// computes the net worth of the amount of goods in this inventory according to the provided [PriceRanges].
     pub(crate) fn net_worth(&self, price_config: &PriceRanges) -> i32 {
         let avg_prices = price_config.avg_prices();
-        ((self.tea * avg_prices.tea)
-            + (self.coffee * avg_prices.coffee)
-            + (self.sugar * avg_prices.sugar)
-            + (self.tobacco * avg_prices.tobacco)
-            + (self.rum * avg_prices.rum)
-            + (self.cotton * avg_prices.cotton)) as i32
+        self.iter()
+            .map(|(good, amount)| amount * avg_prices[good])
+            .sum::<u32>() as i32
     }
 
     #[allow(dead_code)]
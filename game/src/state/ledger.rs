@@ -0,0 +1,410 @@
+use std::{
+    fmt::{self, Display},
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use chrono::Month;
+use serde::{Deserialize, Serialize};
+
+use super::{Good, Location, PirateCaptain, ShipClass};
+
+/// where the Captain's Log is exported to from the game
+pub const LEDGER_EXPORT_PATH: &str = "merchant_ledger.csv";
+
+/// something that happened to the player's gold, goods or ship
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum LedgerEvent {
+    Bought {
+        good: Good,
+        amount: u32,
+        price: u32,
+    },
+    Sold {
+        good: Good,
+        amount: u32,
+        price: u32,
+    },
+    /// the duty charged on a good bought or sold
+    DutyPaid {
+        good: Good,
+        duty: u32,
+    },
+    Stashed {
+        good: Good,
+        amount: u32,
+    },
+    Unstashed {
+        good: Good,
+        amount: u32,
+    },
+    BankDeposit(u32),
+    BankWithdrawal(u32),
+    /// a run on the bank lost part of the deposit
+    BankRun {
+        lost: u32,
+    },
+    DebtPayment(u32),
+    /// the rent paid on warehouses away from home
+    WarehouseRent(u32),
+    /// goods stolen from, spoiled in or seized along with a warehouse
+    WarehouseLoss {
+        good: Good,
+        amount: u32,
+    },
+    Borrowed(u32),
+    /// goods delivered from the hold for a contract
+    ContractDelivered {
+        good: Good,
+        amount: u32,
+        reward: u32,
+    },
+    /// the penalty for missing a contract's deadline
+    ContractMissed {
+        penalty: u32,
+    },
+    /// debt collectors took gold, and perhaps every good in the hold, toward an overdue loan
+    DebtCollected {
+        gold: u32,
+        goods: u32,
+    },
+    BoughtCannon {
+        price: u32,
+    },
+    BoughtHoldSpace {
+        price: u32,
+        more_hold: u32,
+    },
+    GoodsStolen {
+        good: Good,
+        amount: u32,
+    },
+    FoundGoods {
+        good: Good,
+        amount: u32,
+    },
+    DefeatedPirates {
+        gold_recovered: u32,
+    },
+    /// the port authorities paid for sinking a pirate captain's fleet
+    PirateBounty {
+        captain: PirateCaptain,
+        gold: u32,
+    },
+    EscapedPirates,
+    /// a pirate ship taken by a boarding party, and the gold found aboard
+    BoardedPirates {
+        plunder: u32,
+    },
+    /// gold, or goods from the hold, the pirates took to leave
+    BribedPirates {
+        gold: u32,
+        goods: u32,
+    },
+    /// goods from the hold handed over to the pirates on surrendering
+    SurrenderedCargo {
+        goods: u32,
+    },
+    /// goods thrown overboard to outrun the pirates
+    DumpedCargo {
+        goods: u32,
+    },
+    /// the ship was destroyed, losing every good in the hold and some gold
+    LostToPirates {
+        goods: u32,
+        gold: u32,
+    },
+    /// contraband taken by customs officers
+    Confiscated {
+        good: Good,
+        amount: u32,
+    },
+    CustomsFine(u32),
+    CustomsBribe(u32),
+    /// a shipwright mended some points of damage to the hull, for the given price in all
+    RepairedHull {
+        points: u8,
+        price: u32,
+    },
+    /// a new ship bought at a shipyard, less what was given for the old one
+    TradedInShip {
+        class: ShipClass,
+        price: u32,
+        trade_in: u32,
+    },
+    /// sailors signed on, for the given fee each
+    HiredCrew {
+        sailors: u32,
+        fee: u32,
+    },
+    /// the crew's wages for a voyage, and what of them went unpaid
+    Wages {
+        paid: u32,
+        unpaid: u32,
+    },
+    /// sailors who slipped away on reaching port
+    Deserted {
+        sailors: u32,
+    },
+    /// the crew rose up, and the given number of them left with some of the gold
+    Mutiny {
+        sailors: u32,
+        gold: u32,
+    },
+    /// the upkeep of the ship for a voyage, and what of it couldn't be paid and was added to the debt
+    Upkeep {
+        paid: u32,
+        borrowed: u32,
+    },
+}
+
+impl Display for LedgerEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let string = match self {
+            LedgerEvent::Bought { .. } => "Bought",
+            LedgerEvent::Sold { .. } => "Sold",
+            LedgerEvent::DutyPaid { .. } => "Paid duty",
+            LedgerEvent::Stashed { .. } => "Stashed",
+            LedgerEvent::Unstashed { .. } => "Took from stash",
+            LedgerEvent::BankDeposit(_) => "Deposited in bank",
+            LedgerEvent::BankWithdrawal(_) => "Withdrew from bank",
+            LedgerEvent::BankRun { .. } => "Bank run",
+            LedgerEvent::DebtPayment(_) => "Paid down debt",
+            LedgerEvent::WarehouseRent(_) => "Warehouse rent",
+            LedgerEvent::WarehouseLoss { .. } => "Warehouse loss",
+            LedgerEvent::Borrowed(_) => "Borrowed",
+            LedgerEvent::ContractDelivered { .. } => "Contract delivered",
+            LedgerEvent::ContractMissed { .. } => "Contract missed",
+            LedgerEvent::DebtCollected { .. } => "Debt collected",
+            LedgerEvent::BoughtCannon { .. } => "Bought a cannon",
+            LedgerEvent::BoughtHoldSpace { .. } => "Bought hold space",
+            LedgerEvent::GoodsStolen { .. } => "Goods stolen",
+            LedgerEvent::FoundGoods { .. } => "Found goods",
+            LedgerEvent::DefeatedPirates { .. } => "Defeated pirates",
+            LedgerEvent::PirateBounty { .. } => "Pirate bounty",
+            LedgerEvent::EscapedPirates => "Escaped pirates",
+            LedgerEvent::BoardedPirates { .. } => "Boarded pirates",
+            LedgerEvent::BribedPirates { .. } => "Bribed pirates",
+            LedgerEvent::SurrenderedCargo { .. } => "Surrendered cargo",
+            LedgerEvent::DumpedCargo { .. } => "Dumped cargo",
+            LedgerEvent::LostToPirates { .. } => "Lost to pirates",
+            LedgerEvent::Confiscated { .. } => "Seized by customs",
+            LedgerEvent::CustomsFine(_) => "Customs fine",
+            LedgerEvent::CustomsBribe(_) => "Bribed customs",
+            LedgerEvent::RepairedHull { .. } => "Repaired hull",
+            LedgerEvent::TradedInShip { .. } => "Traded in ship",
+            LedgerEvent::Upkeep { .. } => "Ship upkeep",
+            LedgerEvent::HiredCrew { .. } => "Hired crew",
+            LedgerEvent::Wages { .. } => "Crew wages",
+            LedgerEvent::Deserted { .. } => "Crew deserted",
+            LedgerEvent::Mutiny { .. } => "Mutiny",
+        };
+        write!(f, "{}", string)
+    }
+}
+
+impl LedgerEvent {
+    pub fn good(&self) -> Option<Good> {
+        match self {
+            LedgerEvent::Bought { good, .. }
+            | LedgerEvent::Sold { good, .. }
+            | LedgerEvent::DutyPaid { good, .. }
+            | LedgerEvent::Stashed { good, .. }
+            | LedgerEvent::Unstashed { good, .. }
+            | LedgerEvent::GoodsStolen { good, .. }
+            | LedgerEvent::WarehouseLoss { good, .. }
+            | LedgerEvent::ContractDelivered { good, .. }
+            | LedgerEvent::Confiscated { good, .. }
+            | LedgerEvent::FoundGoods { good, .. } => Some(*good),
+            _ => None,
+        }
+    }
+
+    /// how many goods, or how much hold space, changed hands, or how much of the hull was mended.
+    /// for a bank run, how much of the deposit was lost
+    pub fn quantity(&self) -> Option<u32> {
+        match self {
+            LedgerEvent::Bought { amount, .. }
+            | LedgerEvent::Sold { amount, .. }
+            | LedgerEvent::Stashed { amount, .. }
+            | LedgerEvent::Unstashed { amount, .. }
+            | LedgerEvent::GoodsStolen { amount, .. }
+            | LedgerEvent::WarehouseLoss { amount, .. }
+            | LedgerEvent::ContractDelivered { amount, .. }
+            | LedgerEvent::Confiscated { amount, .. }
+            | LedgerEvent::FoundGoods { amount, .. } => Some(*amount),
+            LedgerEvent::BoughtCannon { .. } => Some(1),
+            LedgerEvent::BoughtHoldSpace { more_hold, .. } => Some(*more_hold),
+            LedgerEvent::LostToPirates { goods, .. }
+            | LedgerEvent::SurrenderedCargo { goods }
+            | LedgerEvent::DumpedCargo { goods } => Some(*goods),
+            LedgerEvent::BribedPirates { goods, .. } if *goods > 0 => Some(*goods),
+            LedgerEvent::DebtCollected { goods, .. } if *goods > 0 => Some(*goods),
+            LedgerEvent::BankRun { lost } => Some(*lost),
+            LedgerEvent::RepairedHull { points, .. } => Some((*points).into()),
+            LedgerEvent::HiredCrew { sailors, .. }
+            | LedgerEvent::Deserted { sailors }
+            | LedgerEvent::Mutiny { sailors, .. } => Some(*sailors),
+            _ => None,
+        }
+    }
+
+    pub fn unit_price(&self) -> Option<u32> {
+        match self {
+            LedgerEvent::Bought { price, .. }
+            | LedgerEvent::Sold { price, .. }
+            | LedgerEvent::BoughtCannon { price }
+            | LedgerEvent::TradedInShip { price, .. }
+            | LedgerEvent::HiredCrew { fee: price, .. } => Some(*price),
+            _ => None,
+        }
+    }
+
+    /// how much gold the player gained or lost
+    pub fn gold_change(&self) -> i64 {
+        match *self {
+            LedgerEvent::Bought { amount, price, .. } => -(amount as i64 * price as i64),
+            LedgerEvent::Sold { amount, price, .. } => amount as i64 * price as i64,
+            LedgerEvent::BankDeposit(amount)
+            | LedgerEvent::DebtPayment(amount)
+            | LedgerEvent::WarehouseRent(amount)
+            | LedgerEvent::CustomsFine(amount)
+            | LedgerEvent::CustomsBribe(amount) => -(amount as i64),
+            LedgerEvent::DutyPaid { duty, .. } => -(duty as i64),
+            LedgerEvent::BankWithdrawal(amount) | LedgerEvent::Borrowed(amount) => amount as i64,
+            LedgerEvent::BoughtCannon { price }
+            | LedgerEvent::BoughtHoldSpace { price, .. }
+            | LedgerEvent::RepairedHull { price, .. } => -(price as i64),
+            LedgerEvent::DefeatedPirates { gold_recovered } => gold_recovered as i64,
+            LedgerEvent::PirateBounty { gold, .. } => gold as i64,
+            LedgerEvent::BoardedPirates { plunder } => plunder as i64,
+            LedgerEvent::BribedPirates { gold, .. } => -(gold as i64),
+            LedgerEvent::TradedInShip {
+                price, trade_in, ..
+            } => trade_in as i64 - price as i64,
+            LedgerEvent::Upkeep { paid, .. } | LedgerEvent::Wages { paid, .. } => -(paid as i64),
+            LedgerEvent::HiredCrew { sailors, fee } => -(sailors as i64 * fee as i64),
+            LedgerEvent::Mutiny { gold, .. } => -(gold as i64),
+            LedgerEvent::ContractDelivered { reward, .. } => reward as i64,
+            LedgerEvent::ContractMissed { penalty } => -(penalty as i64),
+            LedgerEvent::LostToPirates { gold, .. } | LedgerEvent::DebtCollected { gold, .. } => {
+                -(gold as i64)
+            }
+            LedgerEvent::Stashed { .. }
+            | LedgerEvent::Unstashed { .. }
+            | LedgerEvent::GoodsStolen { .. }
+            | LedgerEvent::FoundGoods { .. }
+            | LedgerEvent::WarehouseLoss { .. }
+            | LedgerEvent::Confiscated { .. }
+            | LedgerEvent::BankRun { .. }
+            | LedgerEvent::SurrenderedCargo { .. }
+            | LedgerEvent::DumpedCargo { .. }
+            | LedgerEvent::Deserted { .. }
+            | LedgerEvent::EscapedPirates => 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LedgerEntry {
+    pub date: (u16, Month),
+    pub location: Location,
+    pub event: LedgerEvent,
+    /// the player's gold after the event
+    pub gold: u32,
+}
+
+/// every transaction and event of a game, oldest first
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Ledger {
+    pub entries: Vec<LedgerEntry>,
+}
+
+const CSV_HEADER: &str = "year,month,location,event,good,quantity,unit_price,gold_change,gold";
+
+/// quotes a CSV field if it needs it
+fn csv_field(field: String) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field
+    }
+}
+
+fn optional<T: Display>(value: Option<T>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+impl Ledger {
+    pub fn record(&mut self, entry: LedgerEntry) {
+        self.entries.push(entry);
+    }
+
+    pub fn write_csv(&self, mut writer: impl Write) -> io::Result<()> {
+        writeln!(writer, "{}", CSV_HEADER)?;
+        for entry in &self.entries {
+            let fields = [
+                entry.date.0.to_string(),
+                entry.date.1.name().to_owned(),
+                entry.location.to_string(),
+                entry.event.to_string(),
+                optional(entry.event.good()),
+                optional(entry.event.quantity()),
+                optional(entry.event.unit_price()),
+                entry.event.gold_change().to_string(),
+                entry.gold.to_string(),
+            ];
+            writeln!(writer, "{}", fields.map(csv_field).join(","))?;
+        }
+        Ok(())
+    }
+
+    pub fn export_csv(&self, path: &Path) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_csv(&mut writer)?;
+        writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn csv() -> io::Result<()> {
+        let mut ledger = Ledger::default();
+        ledger.record(LedgerEntry {
+            date: (1782, Month::March),
+            location: Location::London,
+            event: LedgerEvent::Bought {
+                good: Good::Tea,
+                amount: 10,
+                price: 20,
+            },
+            gold: 300,
+        });
+        ledger.record(LedgerEntry {
+            date: (1782, Month::April),
+            location: Location::CapeTown,
+            event: LedgerEvent::LostToPirates {
+                goods: 10,
+                gold: 150,
+            },
+            gold: 150,
+        });
+        let mut csv = vec![];
+        ledger.write_csv(&mut csv)?;
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            r"year,month,location,event,good,quantity,unit_price,gold_change,gold
+1782,March,London,Bought,Tea,10,20,-200,300
+1782,April,Cape Town,Lost to pirates,,10,,-150,150
+"
+        );
+        assert_eq!(csv_field("a, \"b\"".to_owned()), "\"a, \"\"b\"\"\"");
+        Ok(())
+    }
+}
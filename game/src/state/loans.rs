@@ -0,0 +1,246 @@
+use chrono::Month;
+use serde::{Deserialize, Serialize};
+
+use super::{add_months, months_between, Location, LocationMap};
+
+/// The terms a port's lender offers.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Lender {
+    /// how much a loan grows each month, eg. 0.1 is 10%
+    pub interest_rate: f64,
+    /// the most the lender will have lent out to the player at once
+    pub cap: u32,
+    /// how many months after borrowing the loan has to be repaid
+    pub term_months: u16,
+}
+
+/// What the player owes one port's lender.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Loan {
+    pub balance: u32,
+    /// how much the balance grows each month, eg. 0.1 is 10%
+    pub interest_rate: f64,
+    /// when the loan has to be repaid by, if ever.
+    /// the debt the game starts with never comes due.
+    pub due: Option<(u16, Month)>,
+}
+
+impl Loan {
+    /// how much more could be borrowed from the lender
+    pub fn available(&self, lender: &Lender) -> u32 {
+        lender.cap.saturating_sub(self.balance)
+    }
+
+    /// borrows more on the lender's terms. the term starts with the first borrowing, and
+    /// borrowing more before it's repaid doesn't put off the due date.
+    pub fn borrow(&mut self, amount: u32, lender: &Lender, date: (u16, Month)) {
+        let due = self
+            .due
+            .filter(|_| self.balance > 0)
+            .unwrap_or_else(|| add_months(date, lender.term_months));
+        self.balance = self.balance.saturating_add(amount);
+        self.interest_rate = lender.interest_rate;
+        self.due = Some(due);
+    }
+
+    pub fn repay(&mut self, amount: u32) {
+        self.balance = self.balance.saturating_sub(amount);
+        if self.balance == 0 {
+            self.due = None;
+        }
+    }
+
+    /// adds a month of interest to the balance
+    pub fn accrue_interest(&mut self) {
+        // the interest is computed on its own, since eg. 1.0 + 0.15 can't be
+        // represented exactly and would round a whole debt down by one
+        let interest = f64::from(self.balance) * self.interest_rate;
+        self.balance = self.balance.saturating_add(interest.floor() as u32);
+    }
+
+    /// how many months past its due date the loan is, if it is overdue
+    pub fn months_overdue(&self, date: (u16, Month)) -> Option<u32> {
+        let due = self.due.filter(|_| self.balance > 0)?;
+        let months = months_between(due, date);
+        (months > 0).then_some(months as u32)
+    }
+}
+
+/// What debt collectors take when a loan is overdue.
+/// The longer it is overdue, the more they take.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DebtCollection {
+    pub gold: u32,
+    /// whether every good in the hold is taken as well
+    pub seizes_cargo: bool,
+}
+
+impl DebtCollection {
+    pub fn new(months_overdue: u32, gold: u32, balance: u32) -> Self {
+        let (gold, seizes_cargo) = match months_overdue {
+            0..=2 => (gold / 4, false),
+            3..=5 => (gold / 2, false),
+            _ => (gold, true),
+        };
+        DebtCollection {
+            gold: gold.min(balance),
+            seizes_cargo,
+        }
+    }
+}
+
+/// what the player owes the lender of each port
+pub type Loans = LocationMap<Loan>;
+
+impl Loans {
+    /// the loans owed to nobody but the home port's lender
+    pub fn starting(home_port: &Location, balance: u32, interest_rate: f64) -> Self {
+        let mut loans = Loans::default();
+        *loans.get_mut(home_port) = Loan {
+            balance,
+            interest_rate,
+            due: None,
+        };
+        loans
+    }
+
+    /// the loan that is furthest overdue, if any are
+    pub fn most_overdue(&self, date: (u16, Month)) -> Option<(Location, u32)> {
+        Location::variants()
+            .iter()
+            .filter_map(|location| {
+                let months = self.get(location).months_overdue(date)?;
+                Some((*location, months))
+            })
+            .max_by_key(|(_, months)| *months)
+    }
+
+    /// the ports whose lenders are owed anything, the soonest due first and the debt that never
+    /// comes due last
+    pub fn owed_by_due_date(&self) -> Vec<Location> {
+        let mut owed = Location::variants()
+            .iter()
+            .filter(|location| self.get(location).balance > 0)
+            .copied()
+            .collect::<Vec<Location>>();
+        owed.sort_by_key(|location| {
+            let due = self.get(location).due;
+            (
+                due.is_none(),
+                due.map(|(year, month)| (year, month.number_from_month())),
+            )
+        });
+        owed
+    }
+
+    /// pays down the loans the soonest due first, wherever they were borrowed
+    pub fn repay(&mut self, amount: u32) {
+        let mut left = amount;
+        for location in self.owed_by_due_date() {
+            let loan = self.get_mut(&location);
+            let paid = left.min(loan.balance);
+            loan.repay(paid);
+            left -= paid;
+        }
+    }
+
+    pub fn total(&self) -> u32 {
+        Location::variants().iter().fold(0u32, |total, location| {
+            total.saturating_add(self.get(location).balance)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn borrow_and_repay() {
+        let lender = Lender {
+            interest_rate: 0.05,
+            cap: 1000,
+            term_months: 6,
+        };
+        let mut loan = Loan::default();
+        assert_eq!(loan.available(&lender), 1000);
+        loan.borrow(800, &lender, (1782, Month::September));
+        assert_eq!(loan.available(&lender), 200);
+        assert_eq!(loan.due, Some((1783, Month::March)));
+        loan.accrue_interest();
+        assert_eq!(loan.balance, 840);
+        assert_eq!(loan.months_overdue((1783, Month::March)), None);
+        assert_eq!(loan.months_overdue((1783, Month::May)), Some(2));
+        // borrowing more doesn't put off an overdue loan
+        loan.borrow(1, &lender, (1783, Month::May));
+        assert_eq!(loan.due, Some((1783, Month::March)));
+        assert_eq!(loan.months_overdue((1783, Month::May)), Some(2));
+        loan.repay(841);
+        assert_eq!(loan.due, None);
+        assert_eq!(loan.months_overdue((1783, Month::May)), None);
+        // once repaid, a new loan starts a new term
+        loan.borrow(100, &lender, (1783, Month::May));
+        assert_eq!(loan.due, Some((1783, Month::November)));
+        // and the debt that never comes due gets a term of its own once added to
+        let mut loans = Loans::starting(&Location::London, 1500, 0.1);
+        loans[Location::London].borrow(100, &lender, (1783, Month::May));
+        assert_eq!(loans[Location::London].due, Some((1783, Month::November)));
+    }
+
+    #[test]
+    fn loans() {
+        let mut loans = Loans::starting(&Location::London, 1500, 0.1);
+        loans[Location::Venice].balance = 250;
+        loans[Location::Venice].due = Some((1782, Month::May));
+        loans[Location::Lisbon].balance = 100;
+        loans[Location::Lisbon].due = Some((1782, Month::April));
+        assert_eq!(loans.total(), 1850);
+        assert_eq!(
+            loans.owed_by_due_date(),
+            vec![Location::Lisbon, Location::Venice, Location::London]
+        );
+        assert_eq!(loans.most_overdue((1782, Month::April)), None);
+        assert_eq!(
+            loans.most_overdue((1782, Month::June)),
+            Some((Location::Lisbon, 2))
+        );
+    }
+
+    #[test]
+    fn repay_soonest_due_first() {
+        let mut loans = Loans::starting(&Location::London, 1500, 0.1);
+        loans[Location::Venice].balance = 250;
+        loans[Location::Venice].due = Some((1783, Month::January));
+        loans[Location::Lisbon].balance = 100;
+        loans[Location::Lisbon].due = Some((1782, Month::December));
+        loans.repay(300);
+        assert_eq!(loans[Location::Lisbon], Loan::default());
+        assert_eq!(loans[Location::Venice].balance, 50);
+        assert_eq!(loans[Location::London].balance, 1500);
+        loans.repay(100);
+        assert_eq!(loans[Location::Venice].due, None);
+        assert_eq!(loans[Location::London].balance, 1450);
+    }
+
+    #[test]
+    fn debt_collection_escalates() {
+        assert_eq!(
+            DebtCollection::new(1, 1000, 5000),
+            DebtCollection {
+                gold: 250,
+                seizes_cargo: false
+            }
+        );
+        assert_eq!(DebtCollection::new(3, 1000, 5000).gold, 500);
+        assert_eq!(DebtCollection::new(4, 1000, 300).gold, 300);
+        assert_eq!(
+            DebtCollection::new(6, 1000, 5000),
+            DebtCollection {
+                gold: 1000,
+                seizes_cargo: true
+            }
+        );
+    }
+}
@@ -1,6 +1,10 @@
 use std::fmt::{self, Display};
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+use serde::{Deserialize, Serialize};
+
+/// The ports there are to sail to, in the order of [PORTS]. The ports are a table compiled into
+/// the game, so adding a port means adding a variant here along with its entry there.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
 pub enum Location {
     London,
     Savannah,
@@ -8,33 +12,235 @@ pub enum Location {
     Amsterdam,
     CapeTown,
     Venice,
+    Kingston,
+    Havana,
+    Elmina,
+    Luanda,
+    Batavia,
+    Malacca,
+}
+
+/// What there is to know about a port: what it's called, the key that picks it and where it is.
+#[derive(Debug)]
+pub struct PortInfo {
+    pub location: Location,
+    pub name: &'static str,
+    /// the letter that picks the port, alongside its number
+    pub key: char,
+    /// longitude and latitude, in degrees
+    pub position: (f64, f64),
+    /// whether the player's family can hail from the port
+    pub home_port: bool,
+    /// whether there's a shipyard to trade in the player's ship for another
+    pub shipyard: bool,
 }
 
+/// Every port there is, in the order they're listed and numbered in.
+/// The sea lanes between them are in [super::world::SEA_LANES]. Unlike the goods, nothing here
+/// comes from the rules.
+pub const PORTS: &[PortInfo] = &[
+    PortInfo {
+        location: Location::London,
+        name: "London",
+        key: 'l',
+        position: (-0.1, 51.5),
+        home_port: true,
+        shipyard: false,
+    },
+    PortInfo {
+        location: Location::Savannah,
+        name: "Savannah",
+        key: 's',
+        position: (-81.1, 32.1),
+        home_port: true,
+        shipyard: false,
+    },
+    PortInfo {
+        location: Location::Lisbon,
+        name: "Lisbon",
+        key: 'i',
+        position: (-9.1, 38.7),
+        home_port: true,
+        shipyard: false,
+    },
+    PortInfo {
+        location: Location::Amsterdam,
+        name: "Amsterdam",
+        key: 'a',
+        position: (4.9, 52.4),
+        home_port: true,
+        shipyard: true,
+    },
+    PortInfo {
+        location: Location::CapeTown,
+        name: "Cape Town",
+        key: 'c',
+        position: (18.4, -33.9),
+        home_port: true,
+        shipyard: false,
+    },
+    PortInfo {
+        location: Location::Venice,
+        name: "Venice",
+        key: 'v',
+        position: (12.3, 45.4),
+        home_port: true,
+        shipyard: true,
+    },
+    // the Caribbean
+    PortInfo {
+        location: Location::Kingston,
+        name: "Kingston",
+        key: 'k',
+        position: (-76.8, 18.0),
+        home_port: false,
+        shipyard: true,
+    },
+    PortInfo {
+        location: Location::Havana,
+        name: "Havana",
+        key: 'h',
+        position: (-82.4, 23.1),
+        home_port: false,
+        shipyard: false,
+    },
+    // West Africa
+    PortInfo {
+        location: Location::Elmina,
+        name: "Elmina",
+        key: 'e',
+        position: (-1.3, 5.1),
+        home_port: false,
+        shipyard: false,
+    },
+    PortInfo {
+        location: Location::Luanda,
+        name: "Luanda",
+        key: 'u',
+        position: (13.2, -8.8),
+        home_port: false,
+        shipyard: false,
+    },
+    // the East Indies
+    PortInfo {
+        location: Location::Batavia,
+        name: "Batavia",
+        key: 't',
+        position: (106.8, -6.2),
+        home_port: false,
+        shipyard: true,
+    },
+    PortInfo {
+        location: Location::Malacca,
+        name: "Malacca",
+        key: 'm',
+        position: (102.2, 2.2),
+        home_port: false,
+        shipyard: false,
+    },
+];
+
 impl Display for Location {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let string = match self {
-            Location::London => "London",
-            Location::Savannah => "Savannah",
-            Location::Lisbon => "Lisbon",
-            Location::Amsterdam => "Amsterdam",
-            Location::CapeTown => "Cape Town",
-            Location::Venice => "Venice",
-        };
-        write!(f, "{}", string)
+        write!(f, "{}", self.info().name)
     }
 }
 
-static VARIANTS: &[Location] = &[
-    Location::London,
-    Location::Savannah,
-    Location::Lisbon,
-    Location::Amsterdam,
-    Location::CapeTown,
-    Location::Venice,
-];
-
 impl Location {
+    /// how many ports there are
+    pub const COUNT: usize = PORTS.len();
+
+    pub fn info(&self) -> &'static PortInfo {
+        &PORTS[self.index()]
+    }
+
+    /// where the port is in [PORTS]
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    /// the name of the port as a single lowercase word, eg. "capetown"
+    pub fn id(&self) -> String {
+        self.info().name.to_ascii_lowercase().replace(' ', "")
+    }
+
+    /// the number key that picks the port, for the first ten ports
+    pub fn number_key(&self) -> Option<u8> {
+        match self.index() {
+            index @ 0..=8 => Some(index as u8 + 1),
+            9 => Some(0),
+            _ => None,
+        }
+    }
+
+    /// the port picked by a key, either its number or its letter
+    pub fn from_key(c: char) -> Option<Location> {
+        Self::variants_iter()
+            .find(|location| {
+                location.info().key == c
+                    || location
+                        .number_key()
+                        .and_then(|n| char::from_digit(n.into(), 10))
+                        == Some(c)
+            })
+            .copied()
+    }
+
+    /// the length of the longest port name, for lining up lists of ports
+    pub fn longest_name() -> usize {
+        PORTS.iter().map(|info| info.name.len()).max().unwrap_or(0)
+    }
+
     pub fn variants() -> &'static [Location] {
-        VARIANTS
+        static VARIANTS: [Location; Location::COUNT] = {
+            let mut variants = [Location::London; Location::COUNT];
+            let mut i = 0;
+            while i < Location::COUNT {
+                variants[i] = PORTS[i].location;
+                i += 1;
+            }
+            variants
+        };
+        &VARIANTS
+    }
+
+    pub fn variants_iter() -> impl Iterator<Item = &'static Location> {
+        Self::variants().iter()
+    }
+
+    /// the ports the player's family can hail from
+    pub fn home_ports() -> Vec<Location> {
+        Self::variants_iter()
+            .filter(|location| location.info().home_port)
+            .copied()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn ports() {
+        for (index, info) in PORTS.iter().enumerate() {
+            // ports are looked up by their position in the list
+            assert_eq!(info.location.index(), index);
+            // the letter is underlined in the name, and 'b' is always back
+            assert!(info.name.to_ascii_lowercase().contains(info.key));
+            assert_ne!(info.key, 'b');
+            assert_eq!(Location::from_key(info.key), Some(info.location));
+        }
+        let keys = PORTS.iter().map(|info| info.key).collect::<Vec<char>>();
+        assert!(keys
+            .iter()
+            .enumerate()
+            .all(|(i, key)| !keys[..i].contains(key)));
+        assert_eq!(Location::from_key('1'), Some(Location::London));
+        assert_eq!(Location::from_key('0'), Some(Location::Luanda));
+        assert_eq!(Location::CapeTown.to_string(), "Cape Town");
+        assert_eq!(Location::CapeTown.id(), "capetown");
     }
 }
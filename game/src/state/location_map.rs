@@ -1,65 +1,81 @@
-use std::{collections::HashMap, fmt};
+use std::{
+    collections::HashMap,
+    fmt,
+    marker::PhantomData,
+    ops::{Index, IndexMut},
+};
+
+use serde::{
+    de::{MapAccess, Visitor},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
 
 use super::Location;
 
-/// A collection of generic information about each Location type there is.
-pub struct LocationMap<T> {
-    pub london: T,
-    pub savannah: T,
-    pub lisbon: T,
-    pub amsterdam: T,
-    pub capetown: T,
-    pub venice: T,
+/// A collection of generic information about each Location type there is, in the order of the ports.
+/// Written out as a map from the id of each port, every one of which has to be there when reading one in.
+#[derive(Clone, PartialEq)]
+pub struct LocationMap<T>([T; Location::COUNT]);
+
+impl<T: fmt::Debug> fmt::Debug for LocationMap<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
 }
 
-impl<T: Clone> Clone for LocationMap<T> {
-    fn clone(&self) -> Self {
-        Self {
-            london: self.london.clone(),
-            savannah: self.savannah.clone(),
-            lisbon: self.lisbon.clone(),
-            amsterdam: self.amsterdam.clone(),
-            capetown: self.capetown.clone(),
-            venice: self.venice.clone(),
-        }
+impl<T: Default> Default for LocationMap<T> {
+    fn default() -> Self {
+        Self::from_fn(|_| T::default())
     }
 }
 
-impl<T: fmt::Debug> fmt::Debug for LocationMap<T> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("LocationMap")
-            .field("london", &self.london)
-            .field("savannah", &self.savannah)
-            .field("lisbon", &self.lisbon)
-            .field("amsterdam", &self.amsterdam)
-            .field("capetown", &self.capetown)
-            .field("venice", &self.venice)
-            .finish()
+impl<T> LocationMap<T> {
+    pub fn from_fn<F>(f: F) -> LocationMap<T>
+    where
+        F: FnMut(Location) -> T,
+    {
+        let mut locations = Location::variants_iter().copied();
+        let mut f = f;
+        LocationMap(std::array::from_fn(|_| {
+            f(locations.next().expect("there is a port for every entry"))
+        }))
+    }
+
+    pub fn get(&self, location: &Location) -> &T {
+        &self.0[location.index()]
+    }
+
+    pub fn get_mut(&mut self, location: &Location) -> &mut T {
+        &mut self.0[location.index()]
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Location, &T)> {
+        Location::variants_iter().copied().zip(self.0.iter())
+    }
+}
+
+impl<T> Index<Location> for LocationMap<T> {
+    type Output = T;
+
+    fn index(&self, location: Location) -> &T {
+        self.get(&location)
+    }
+}
+
+impl<T> IndexMut<Location> for LocationMap<T> {
+    fn index_mut(&mut self, location: Location) -> &mut T {
+        self.get_mut(&location)
     }
 }
 
 impl<T> From<HashMap<Location, T>> for LocationMap<T> {
     fn from(mut value: HashMap<Location, T>) -> Self {
-        LocationMap {
-            london: value
-                .remove(&Location::London)
-                .expect("expectation failed: london present in hashmap"),
-            savannah: value
-                .remove(&Location::Savannah)
-                .expect("expectation failed: savannah present in hashmap"),
-            lisbon: value
-                .remove(&Location::Lisbon)
-                .expect("expectation failed: lisbon present in hashmap"),
-            amsterdam: value
-                .remove(&Location::Amsterdam)
-                .expect("expectation failed: amsterdam present in hashmap"),
-            capetown: value
-                .remove(&Location::CapeTown)
-                .expect("expectation failed: capetown present in hashmap"),
-            venice: value
-                .remove(&Location::Venice)
-                .expect("expectation failed: venice present in hashmap"),
-        }
+        LocationMap::from_fn(|location| {
+            value
+                .remove(&location)
+                .unwrap_or_else(|| panic!("expectation failed: {} present in hashmap", location))
+        })
     }
 }
 
@@ -69,15 +85,82 @@ impl<T> FromIterator<(Location, T)> for LocationMap<T> {
     }
 }
 
-impl<T> LocationMap<T> {
-    pub fn get(&self, location: &Location) -> &T {
-        match location {
-            Location::London => &self.london,
-            Location::Savannah => &self.savannah,
-            Location::Lisbon => &self.lisbon,
-            Location::Amsterdam => &self.amsterdam,
-            Location::CapeTown => &self.capetown,
-            Location::Venice => &self.venice,
+impl<T: Serialize> Serialize for LocationMap<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(Location::COUNT))?;
+        for (location, value) in self.iter() {
+            map.serialize_entry(&location.id(), value)?;
         }
+        map.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for LocationMap<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct LocationMapVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>> Visitor<'de> for LocationMapVisitor<T> {
+            type Value = LocationMap<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a map from ports to values")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+                let mut locations = LocationMap::from_fn(|_| None);
+                while let Some(id) = access.next_key::<String>()? {
+                    let location = Location::variants_iter()
+                        .find(|location| location.id() == id)
+                        .ok_or_else(|| {
+                            serde::de::Error::custom(format!("unknown port `{}`", id))
+                        })?;
+                    locations[*location] = Some(access.next_value()?);
+                }
+                if let Some((missing, _)) = locations.iter().find(|(_, value)| value.is_none()) {
+                    return Err(serde::de::Error::custom(format!(
+                        "missing port `{}`",
+                        missing.id()
+                    )));
+                }
+                Ok(LocationMap(locations.0.map(|value| {
+                    value.expect("every port was checked to be there")
+                })))
+            }
+        }
+
+        deserializer.deserialize_map(LocationMapVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn serde() {
+        let mut locations = LocationMap::<u32>::default();
+        locations[Location::CapeTown] = 3;
+        let json = serde_json::to_string(&locations).unwrap();
+        assert!(
+            json.starts_with(r#"{"london":0,"savannah":0,"lisbon":0,"amsterdam":0,"capetown":3,"#)
+        );
+        assert_eq!(
+            serde_json::from_str::<LocationMap<u32>>(&json).unwrap(),
+            locations
+        );
+        assert!(
+            serde_json::from_str::<LocationMap<u32>>(r#"{"capetown":3}"#)
+                .unwrap_err()
+                .to_string()
+                .contains("missing port `london`")
+        );
+        assert!(
+            serde_json::from_str::<LocationMap<u32>>(r#"{"atlantis":3}"#)
+                .unwrap_err()
+                .to_string()
+                .contains("unknown port `atlantis`")
+        );
     }
 }
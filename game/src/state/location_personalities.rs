@@ -1,6 +1,9 @@
 use rand::{distributions::WeightedIndex, prelude::Distribution, rngs::StdRng};
+use serde::{Deserialize, Serialize};
 
-use super::{location_map::LocationMap, Location, PriceRanges};
+use super::{
+    location_map::LocationMap, ContractTerms, Customs, Good, Lender, Location, PriceRanges,
+};
 
 #[derive(Debug, Clone, Copy)]
 pub enum EventPossibility {
@@ -13,9 +16,11 @@ pub enum EventPossibility {
     PirateEncounter,
     CanBuyHoldSpace,
     NoEffect,
+    CustomsInspection,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct EventWeights {
     pub no_event: u8,
     pub cheap_good: u8,
@@ -26,11 +31,13 @@ pub struct EventWeights {
     pub pirate_encounter: u8,
     pub can_buy_more_hold_space: u8,
     pub no_effect: u8,
+    #[serde(default)]
+    pub customs_inspection: u8,
 }
 
 impl EventWeights {
     pub fn generate_random_event(&self, rng: &mut StdRng) -> EventPossibility {
-        const POSSIBILITIES: [EventPossibility; 9] = [
+        const POSSIBILITIES: [EventPossibility; 10] = [
             EventPossibility::NoEvent,
             EventPossibility::CheapGood,
             EventPossibility::ExpensiveGood,
@@ -40,13 +47,14 @@ impl EventWeights {
             EventPossibility::PirateEncounter,
             EventPossibility::CanBuyHoldSpace,
             EventPossibility::NoEffect,
+            EventPossibility::CustomsInspection,
         ];
         let weights = self.weights();
         let dist = WeightedIndex::new(weights).expect("Unable to create WeightedIndex");
         POSSIBILITIES[dist.sample(rng)]
     }
 
-    pub fn weights(&self) -> [u8; 9] {
+    pub fn weights(&self) -> [u8; 10] {
         [
             self.no_event,
             self.cheap_good,
@@ -57,19 +65,33 @@ impl EventWeights {
             self.pirate_encounter,
             self.can_buy_more_hold_space,
             self.no_effect,
+            self.customs_inspection,
         ]
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LocationPersonality {
     pub price_ranges: PriceRanges,
     pub event_weights: EventWeights,
+    pub lender: Lender,
+    /// what the port's merchants offer for deliveries
+    #[serde(default)]
+    pub contracts: ContractTerms,
+    /// the goods whose prices can go lower here than in other ports
+    #[serde(default)]
+    pub cheap_goods: Vec<Good>,
+    /// the goods whose prices can go higher here than in other ports
+    #[serde(default)]
+    pub expensive_goods: Vec<Good>,
+    /// the duties charged and goods forbidden here
+    #[serde(default)]
+    pub customs: Customs,
 }
 
 pub type LocationPersonalities = LocationMap<LocationPersonality>;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LocationConfig {
     pub home_port: Location,
     pub overall_price_ranges: PriceRanges,
@@ -1,12 +1,17 @@
+use serde::{Deserialize, Serialize};
+
 use super::{
-    Inventory, Location, LocationConfig, LocationEvent, LocationMap, LocationPersonality,
-    MerchantRng,
+    ContractOffer, Inventory, Location, LocationConfig, LocationEvent, LocationMap,
+    LocationPersonality, MerchantRng,
 };
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct LocationInfo {
     pub prices: Inventory,
     pub event: Option<LocationEvent>,
+    /// the contracts on offer on the port's board
+    #[serde(default)]
+    pub contracts: Vec<ContractOffer>,
 }
 
 impl LocationInfo {
@@ -14,6 +19,7 @@ impl LocationInfo {
         Self {
             prices: Inventory::default(),
             event: None,
+            contracts: vec![],
         }
     }
 }
@@ -26,14 +32,7 @@ impl LocationInfos {
         config: &LocationConfig,
         player_net_worth: i32,
     ) -> LocationInfos {
-        let mut res = LocationInfos {
-            london: LocationInfo::empty(),
-            savannah: LocationInfo::empty(),
-            lisbon: LocationInfo::empty(),
-            amsterdam: LocationInfo::empty(),
-            capetown: LocationInfo::empty(),
-            venice: LocationInfo::empty(),
-        };
+        let mut res = LocationInfos::from_fn(|_| LocationInfo::empty());
         for location in Location::variants() {
             // for new games, don't put an event in home base
             res.generate_location(
@@ -55,31 +54,18 @@ impl LocationInfos {
         allow_events: bool,
         player_net_worth: i32,
     ) -> &LocationInfo {
-        let new_location_info = rng.gen_location_info(allow_events, personality, player_net_worth);
+        let new_location_info =
+            rng.gen_location_info(allow_events, location, personality, player_net_worth);
         let location_info = self.location_info_mut(location);
         *location_info = new_location_info;
         location_info
     }
 
     pub fn location_info(&self, location: &Location) -> &LocationInfo {
-        match location {
-            Location::London => &self.london,
-            Location::Savannah => &self.savannah,
-            Location::Lisbon => &self.lisbon,
-            Location::Amsterdam => &self.amsterdam,
-            Location::CapeTown => &self.capetown,
-            Location::Venice => &self.venice,
-        }
+        self.get(location)
     }
 
     pub fn location_info_mut(&mut self, location: &Location) -> &mut LocationInfo {
-        match location {
-            Location::London => &mut self.london,
-            Location::Savannah => &mut self.savannah,
-            Location::Lisbon => &mut self.lisbon,
-            Location::Amsterdam => &mut self.amsterdam,
-            Location::CapeTown => &mut self.capetown,
-            Location::Venice => &mut self.venice,
-        }
+        self.get_mut(location)
     }
 }
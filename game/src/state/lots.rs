@@ -0,0 +1,195 @@
+use chrono::Month;
+use serde::{Deserialize, Serialize};
+
+use super::{goods_map::GoodsMap, months_between, Good, Inventory, Rules};
+
+/// some of a good acquired in the same month, which has aged the same
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Lot {
+    pub amount: u32,
+    pub acquired: (u16, Month),
+}
+
+/// whether the goods held are, on the whole, getting worse or better with age
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Aging {
+    #[default]
+    Fresh,
+    Spoiling,
+    Maturing,
+}
+
+impl Aging {
+    /// drawn right after the amount of a good
+    pub fn marker(&self) -> &'static str {
+        match self {
+            Aging::Fresh => "",
+            Aging::Spoiling => "-",
+            Aging::Maturing => "+",
+        }
+    }
+}
+
+/// When the goods in an [Inventory] were acquired, oldest first, so that they can age.
+/// Goods held from before lots were kept count as fresh, and are the first to go.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Lots(GoodsMap<Vec<Lot>>);
+
+impl Lots {
+    fn tracked(&self, good: &Good) -> u32 {
+        self.0.get_good(good).iter().map(|lot| lot.amount).sum()
+    }
+
+    /// adds goods acquired on the given date, keeping the lots in date order
+    pub fn add(&mut self, good: &Good, amount: u32, acquired: (u16, Month)) {
+        if amount == 0 {
+            return;
+        }
+        let lots = self.0.get_good_mut(good);
+        let index = lots
+            .iter()
+            .position(|lot| months_between(acquired, lot.acquired) >= 0)
+            .unwrap_or(lots.len());
+        match lots.get_mut(index) {
+            Some(lot) if lot.acquired == acquired => lot.amount += amount,
+            _ => lots.insert(index, Lot { amount, acquired }),
+        }
+    }
+
+    /// adds lots taken from elsewhere, eg. when moving goods from the hold to the stash
+    pub fn add_lots(&mut self, good: &Good, lots: Vec<Lot>) {
+        for lot in lots {
+            self.add(good, lot.amount, lot.acquired);
+        }
+    }
+
+    /// removes `amount` of the `held` goods, oldest first, returning the lots they were taken from
+    pub fn remove(&mut self, good: &Good, amount: u32, held: u32) -> Vec<Lot> {
+        let untracked = held.saturating_sub(self.tracked(good));
+        let mut remaining = amount.saturating_sub(untracked);
+        let lots = self.0.get_good_mut(good);
+        let mut removed = vec![];
+        while remaining > 0 && !lots.is_empty() {
+            let taken = remaining.min(lots[0].amount);
+            lots[0].amount -= taken;
+            remaining -= taken;
+            removed.push(Lot {
+                amount: taken,
+                acquired: lots[0].acquired,
+            });
+            if lots[0].amount == 0 {
+                lots.remove(0);
+            }
+        }
+        removed
+    }
+
+    /// the average quality of the next `amount` of the `held` goods to go, on the given date,
+    /// where 1.0 is as good as when they were acquired
+    pub fn quality(
+        &self,
+        good: &Good,
+        amount: u32,
+        held: u32,
+        date: (u16, Month),
+        rules: &Rules,
+    ) -> f64 {
+        let amount = amount.min(held);
+        if amount == 0 {
+            return 1.0;
+        }
+        let untracked = held.saturating_sub(self.tracked(good)).min(amount);
+        let mut total = untracked as f64;
+        let mut remaining = amount - untracked;
+        for lot in self.0.get_good(good) {
+            if remaining == 0 {
+                break;
+            }
+            let taken = remaining.min(lot.amount);
+            total += taken as f64 * rules.quality_after(good, months_between(lot.acquired, date));
+            remaining -= taken;
+        }
+        total / amount as f64
+    }
+
+    /// how each of the goods in the inventory is aging on the given date
+    pub fn aging(
+        &self,
+        inventory: &Inventory,
+        date: (u16, Month),
+        rules: &Rules,
+    ) -> GoodsMap<Aging> {
+        inventory
+            .iter()
+            .map(|(good, held)| {
+                let quality = self.quality(&good, *held, *held, date, rules);
+                let aging = if quality < 1.0 {
+                    Aging::Spoiling
+                } else if quality > 1.0 {
+                    Aging::Maturing
+                } else {
+                    Aging::Fresh
+                };
+                (good, aging)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn oldest_goods_go_first() {
+        let mut lots = Lots::default();
+        lots.add(&Good::Tea, 10, (1782, Month::May));
+        lots.add(&Good::Tea, 5, (1782, Month::March));
+        lots.add(&Good::Tea, 5, (1782, Month::May));
+        assert_eq!(
+            lots.remove(&Good::Tea, 8, 20),
+            vec![
+                Lot {
+                    amount: 5,
+                    acquired: (1782, Month::March)
+                },
+                Lot {
+                    amount: 3,
+                    acquired: (1782, Month::May)
+                }
+            ]
+        );
+        assert_eq!(lots.tracked(&Good::Tea), 12);
+        // goods held from before lots were kept go before any others
+        assert_eq!(lots.remove(&Good::Tea, 4, 16), vec![]);
+        assert_eq!(lots.tracked(&Good::Tea), 12);
+    }
+
+    #[test]
+    fn quality() {
+        let rules = Rules::default();
+        let mut lots = Lots::default();
+        lots.add(&Good::Tea, 10, (1782, Month::March));
+        lots.add(&Good::Tea, 10, (1782, Month::July));
+        let date = (1782, Month::July);
+        // four months at 5% a month, then fresh
+        assert_eq!(lots.quality(&Good::Tea, 10, 20, date, &rules), 0.8);
+        assert_eq!(lots.quality(&Good::Tea, 20, 20, date, &rules), 0.9);
+        // tea doesn't keep forever
+        let much_later = (1790, Month::July);
+        assert_eq!(
+            lots.quality(&Good::Tea, 20, 20, much_later, &rules),
+            rules.min_quality
+        );
+        lots.add(&Good::Rum, 10, (1782, Month::March));
+        lots.add(&Good::Sugar, 10, (1782, Month::March));
+        let inventory = Inventory::from([(Good::Tea, 20), (Good::Sugar, 10), (Good::Rum, 10)]);
+        let aging = lots.aging(&inventory, date, &rules);
+        assert_eq!(aging[Good::Tea], Aging::Spoiling);
+        assert_eq!(aging[Good::Sugar], Aging::Fresh);
+        assert_eq!(aging[Good::Rum], Aging::Maturing);
+        assert_eq!(aging[Good::Cotton], Aging::Fresh);
+    }
+}
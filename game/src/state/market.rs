@@ -0,0 +1,163 @@
+use chrono::Month;
+use serde::{Deserialize, Serialize};
+
+use super::{goods_map::GoodsMap, months_between, Good, Inventory, LocationMap};
+
+/// the highest price that can be shown
+const MAX_PRICE: u32 = 9999;
+/// pressure closer to nothing than this is forgotten
+const CALM_PRESSURE: f64 = 0.005;
+
+/// the price of a good after `amount` of it has been bought (or sold, when negative)
+/// in a market where each unit moves the price by `impact`
+pub fn price_after_trade(price: u32, amount: i64, impact: f64) -> u32 {
+    let price = price as f64 * (amount as f64 * impact).exp();
+    (price.round() as u32).clamp(1, MAX_PRICE)
+}
+
+/// what each unit of `amount` of a good costs (or fetches, when negative) on average, each unit
+/// being priced after the market has moved for it. Buys are rounded up and sales down, so buying
+/// and then selling the same goods in one port never comes out ahead
+pub fn average_trade_price(price: u32, amount: i64, impact: f64) -> u32 {
+    if amount == 0 || impact == 0.0 {
+        return price;
+    }
+    let units = amount.unsigned_abs();
+    let direction = amount.signum();
+    let first = price_after_trade(price, direction, impact);
+    let last = price_after_trade(price, amount, impact);
+    let total = if units <= u64::from(first.abs_diff(last)) {
+        // few enough units to price one at a time
+        (1..=units)
+            .map(|unit| u64::from(price_after_trade(price, unit as i64 * direction, impact)))
+            .sum()
+    } else {
+        // otherwise add the trade up one price at a time, from the first unit to go at each,
+        // so it never takes more steps than there are prices
+        let first_unit_at = |at: u32| {
+            let unit = if direction > 0 {
+                (((f64::from(at) - 0.5) / f64::from(price)).ln() / impact).ceil()
+            } else {
+                ((f64::from(price) / (f64::from(at) + 0.5)).ln() / impact).floor() + 1.0
+            };
+            unit.clamp(1.0, units as f64 + 1.0) as u64
+        };
+        let mut total = 0;
+        let mut at = first;
+        let mut from_unit = 1;
+        while at != last {
+            let next = at.saturating_add_signed(direction as i32);
+            let to_unit = first_unit_at(next);
+            total += u64::from(at) * (to_unit - from_unit);
+            at = next;
+            from_unit = to_unit;
+        }
+        total + u64::from(last) * (units + 1 - from_unit)
+    };
+    let average = if amount > 0 {
+        total.div_ceil(units)
+    } else {
+        total / units
+    };
+    average as u32
+}
+
+/// How far the player's trading has pushed the prices of a port away from its usual range.
+/// Pressure is kept on a log scale, so buying then selling the same amount leaves none behind.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Market {
+    pub pressure: GoodsMap<f64>,
+    /// when the pressure was last added to or eased
+    pub updated: Option<(u16, Month)>,
+}
+
+pub type Markets = LocationMap<Market>;
+
+impl Market {
+    pub fn is_calm(&self) -> bool {
+        self.pressure.iter().all(|(_, pressure)| *pressure == 0.0)
+    }
+
+    /// records `amount` of a good being bought (or sold, when negative)
+    pub fn trade(&mut self, good: &Good, amount: i64, impact: f64, date: (u16, Month)) {
+        *self.pressure.get_good_mut(good) += amount as f64 * impact;
+        self.updated = Some(date);
+    }
+
+    /// how many months the pressure has had to ease
+    pub fn months_since_update(&self, date: (u16, Month)) -> u32 {
+        self.updated
+            .map_or(0, |updated| months_between(updated, date).max(0) as u32)
+    }
+
+    /// keeps only the given share of the pressure, and pushes the prices of the port by what is left
+    pub fn settle(&mut self, remaining: f64, prices: &mut Inventory, date: (u16, Month)) {
+        for good in Good::variants_iter() {
+            let pressure = self.pressure.get_good_mut(good);
+            *pressure *= remaining.clamp(0.0, 1.0);
+            if pressure.abs() < CALM_PRESSURE {
+                *pressure = 0.0;
+            }
+            if *pressure != 0.0 {
+                let price = prices.get_good_mut(good);
+                *price = price_after_trade(*price, 1, *pressure);
+            }
+        }
+        self.updated = Some(date);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn trades_move_prices() {
+        assert_eq!(price_after_trade(100, 0, 0.002), 100);
+        assert_eq!(price_after_trade(100, 100, 0.002), 122);
+        assert_eq!(price_after_trade(100, -100, 0.002), 82);
+        assert_eq!(price_after_trade(2, -10000, 0.002), 1);
+        assert_eq!(price_after_trade(9000, 1000, 0.002), 9999);
+    }
+
+    #[test]
+    fn trades_are_priced_unit_by_unit() {
+        assert_eq!(average_trade_price(100, 0, 0.002), 100);
+        assert_eq!(average_trade_price(100, 1, 0.002), 100);
+        assert_eq!(average_trade_price(100, 100, 0.002), 111);
+        assert_eq!(average_trade_price(100, -100, 0.002), 90);
+        assert_eq!(average_trade_price(5000, 10, 0.002), 5056);
+        assert_eq!(average_trade_price(5000, -10, 0.002), 4945);
+        // prices stop at their limits
+        assert_eq!(average_trade_price(9000, 1000, 0.002), 9973);
+        assert_eq!(average_trade_price(2, -1000, 0.002), 1);
+        // however small the impact and large the trade, it's priced in no more steps than there
+        // are prices
+        assert_eq!(average_trade_price(100, 1_000_000_000, 1e-12), 100);
+        // the round trip costs what the market moved
+        let bought = 111 * 100;
+        let sold = average_trade_price(price_after_trade(100, 100, 0.002), -100, 0.002) * 100;
+        assert!(sold < bought);
+    }
+
+    #[test]
+    fn pressure_eases() {
+        let mut market = Market::default();
+        assert!(market.is_calm());
+        market.trade(&Good::Rum, 100, 0.002, (1782, Month::March));
+        market.trade(&Good::Tea, -50, 0.002, (1782, Month::March));
+        assert!(!market.is_calm());
+        assert_eq!(market.months_since_update((1782, Month::June)), 3);
+        let mut prices = Inventory::from([(Good::Tea, 1000), (Good::Rum, 100)]);
+        market.settle(0.5, &mut prices, (1782, Month::June));
+        assert_eq!(prices[Good::Rum], 111);
+        assert_eq!(prices[Good::Tea], 951);
+        assert_eq!(prices[Good::Cotton], 0);
+        // once the pressure has mostly eased the market is calm again
+        market.trade(&Good::Tea, 25, 0.002, (1782, Month::June));
+        market.settle(0.01, &mut prices, (1782, Month::July));
+        assert!(market.is_calm());
+    }
+}
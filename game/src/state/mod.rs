@@ -1,16 +1,41 @@
-mod constants;
+mod contracts;
+mod cost_basis;
+mod crew;
+mod customs;
+mod difficulty;
 mod error;
 mod game_state;
 mod good;
 mod goods_map;
 mod inventory;
+mod ledger;
+mod loans;
 mod location;
 mod location_map;
 mod location_personalities;
 mod locations;
+mod lots;
+mod market;
+mod pirate_captain;
+mod price_history;
 mod price_ranges;
 mod rng;
+mod rules;
+mod ship_class;
+mod warehouse;
+mod world;
 
+pub use self::contracts::Contract;
+pub use self::contracts::ContractOffer;
+pub use self::contracts::ContractOutcome;
+pub use self::contracts::ContractTerms;
+pub use self::cost_basis::CostBasis;
+pub use self::crew::Crew;
+pub use self::customs::duty;
+pub use self::customs::Customs;
+pub use self::customs::CustomsInspection;
+pub use self::customs::Duty;
+pub use self::difficulty::Difficulty;
 pub use self::error::StateError;
 pub use self::game_state::GameState;
 pub use self::game_state::GoodsStolenResult;
@@ -18,13 +43,23 @@ pub use self::game_state::Initialization;
 pub use self::game_state::LocationEvent;
 pub use self::game_state::Mode;
 pub use self::game_state::NoEffectEvent;
+pub use self::game_state::PirateBribe;
 #[cfg(test)]
 pub use self::game_state::PirateEncounterInfo;
 pub use self::game_state::PirateEncounterState;
 pub use self::game_state::Transaction;
-pub use self::game_state::CANNON_COST;
 pub use self::good::Good;
+pub use self::goods_map::GoodsMap;
 pub use self::inventory::Inventory;
+pub use self::ledger::Ledger;
+pub use self::ledger::LedgerEntry;
+pub use self::ledger::LedgerEvent;
+pub use self::ledger::LEDGER_EXPORT_PATH;
+pub use self::loans::DebtCollection;
+pub use self::loans::Lender;
+#[cfg(test)]
+pub use self::loans::Loan;
+pub use self::loans::Loans;
 pub use self::location::Location;
 pub use self::location_map::LocationMap;
 #[cfg(test)]
@@ -33,5 +68,23 @@ pub use self::location_personalities::LocationConfig;
 pub use self::location_personalities::LocationPersonality;
 pub use self::locations::LocationInfo;
 pub use self::locations::LocationInfos;
+pub use self::lots::Aging;
+pub use self::lots::Lots;
+pub use self::market::Markets;
+pub use self::market::{average_trade_price, price_after_trade};
+pub use self::pirate_captain::Bounty;
+pub use self::pirate_captain::PirateCaptain;
+pub use self::pirate_captain::PirateRecord;
+pub use self::pirate_captain::PirateRoster;
+pub use self::price_history::PriceHistory;
+pub use self::price_history::{add_months, months_between};
 pub use self::price_ranges::PriceRanges;
 pub use self::rng::MerchantRng;
+pub use self::rules::Rules;
+pub use self::rules::RulesError;
+pub use self::ship_class::ShipClass;
+pub use self::warehouse::Warehouse;
+pub use self::warehouse::WarehouseLoss;
+pub use self::warehouse::WarehouseMishap;
+pub use self::warehouse::Warehouses;
+pub use self::world::travel_months_at;
@@ -0,0 +1,206 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::Location;
+
+/// The pirate captains at large, in the order of [PIRATE_CAPTAINS].
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+pub enum PirateCaptain {
+    IronMeg,
+    GentlemanFlint,
+    RedMorgan,
+    SaltEyeHendrick,
+    WidowChing,
+}
+
+/// What there is to know about a pirate captain: what they're called, how many ships they bring
+/// and where they hunt.
+#[derive(Debug)]
+pub struct PirateCaptainInfo {
+    pub captain: PirateCaptain,
+    pub name: &'static str,
+    /// how many more ships than other pirates their fleet can have
+    pub strength: u8,
+    /// the ports whose waters they prowl
+    pub home_waters: &'static [Location],
+    /// what the port authorities pay for sinking them, before their infamy adds to it
+    pub bounty: u32,
+}
+
+/// Every pirate captain there is, from weakest to strongest.
+pub const PIRATE_CAPTAINS: &[PirateCaptainInfo] = &[
+    PirateCaptainInfo {
+        captain: PirateCaptain::IronMeg,
+        name: "Iron Meg",
+        strength: 0,
+        home_waters: &[Location::London, Location::Amsterdam, Location::Lisbon],
+        bounty: 600,
+    },
+    PirateCaptainInfo {
+        captain: PirateCaptain::GentlemanFlint,
+        name: "Gentleman Flint",
+        strength: 1,
+        home_waters: &[Location::Lisbon, Location::Venice],
+        bounty: 900,
+    },
+    PirateCaptainInfo {
+        captain: PirateCaptain::RedMorgan,
+        name: "Red Morgan",
+        strength: 1,
+        home_waters: &[Location::Savannah, Location::Kingston, Location::Havana],
+        bounty: 1000,
+    },
+    PirateCaptainInfo {
+        captain: PirateCaptain::SaltEyeHendrick,
+        name: "Salt-Eye Hendrick",
+        strength: 2,
+        home_waters: &[Location::Elmina, Location::Luanda, Location::CapeTown],
+        bounty: 1500,
+    },
+    // the terror of the eastern straits
+    PirateCaptainInfo {
+        captain: PirateCaptain::WidowChing,
+        name: "the Widow Ching",
+        strength: 3,
+        home_waters: &[Location::Batavia, Location::Malacca],
+        bounty: 2500,
+    },
+];
+
+impl Display for PirateCaptain {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.info().name)
+    }
+}
+
+impl PirateCaptain {
+    pub fn info(&self) -> &'static PirateCaptainInfo {
+        &PIRATE_CAPTAINS[*self as usize]
+    }
+
+    pub fn variants_iter() -> impl Iterator<Item = PirateCaptain> {
+        PIRATE_CAPTAINS.iter().map(|info| info.captain)
+    }
+}
+
+/// How a captain's name is spoken of in port.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Reputation {
+    LittleKnown,
+    Feared,
+    Notorious,
+    Infamous,
+}
+
+impl Display for Reputation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let string = match self {
+            Reputation::LittleKnown => "little-known",
+            Reputation::Feared => "feared",
+            Reputation::Notorious => "notorious",
+            Reputation::Infamous => "infamous",
+        };
+        write!(f, "{}", string)
+    }
+}
+
+/// What a captain remembers of their meetings with the player.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub struct PirateRecord {
+    pub encounters: u32,
+    /// how many times the player ran from them, which they boast of in every port
+    pub runs: u32,
+    /// how many times they overran the player's ship
+    pub wins: u32,
+    /// whether the player has sunk their fleet, and with it the captain
+    pub sunk: bool,
+}
+
+impl PirateRecord {
+    pub fn infamy(&self) -> u32 {
+        self.runs + self.wins
+    }
+
+    pub fn reputation(&self) -> Reputation {
+        match self.infamy() {
+            0 => Reputation::LittleKnown,
+            1..=2 => Reputation::Feared,
+            3..=5 => Reputation::Notorious,
+            _ => Reputation::Infamous,
+        }
+    }
+}
+
+/// The bounty paid for sinking a captain's fleet.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub struct Bounty {
+    pub captain: PirateCaptain,
+    pub gold: u32,
+}
+
+/// What every captain remembers of the player, over the whole of the game.
+#[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize)]
+pub struct PirateRoster(HashMap<PirateCaptain, PirateRecord>);
+
+impl PirateRoster {
+    pub fn get(&self, captain: PirateCaptain) -> PirateRecord {
+        self.0.get(&captain).copied().unwrap_or_default()
+    }
+
+    pub fn get_mut(&mut self, captain: PirateCaptain) -> &mut PirateRecord {
+        self.0.entry(captain).or_default()
+    }
+
+    /// the captains still afloat who hunt the waters of the given port
+    pub fn at_large(&self, location: &Location) -> Vec<PirateCaptain> {
+        PirateCaptain::variants_iter()
+            .filter(|captain| captain.info().home_waters.contains(location))
+            .filter(|captain| !self.get(*captain).sunk)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn captains() {
+        for (index, info) in PIRATE_CAPTAINS.iter().enumerate() {
+            // captains are looked up by their position in the list
+            assert_eq!(info.captain as usize, index);
+            assert!(!info.home_waters.is_empty());
+        }
+    }
+
+    #[test]
+    fn roster() {
+        let mut roster = PirateRoster::default();
+        assert_eq!(
+            roster.at_large(&Location::Lisbon),
+            vec![PirateCaptain::IronMeg, PirateCaptain::GentlemanFlint]
+        );
+        assert_eq!(
+            roster.get(PirateCaptain::IronMeg).reputation(),
+            Reputation::LittleKnown
+        );
+        let record = roster.get_mut(PirateCaptain::IronMeg);
+        record.runs = 2;
+        record.wins = 1;
+        assert_eq!(
+            roster.get(PirateCaptain::IronMeg).reputation(),
+            Reputation::Notorious
+        );
+        roster.get_mut(PirateCaptain::GentlemanFlint).sunk = true;
+        assert_eq!(
+            roster.at_large(&Location::Lisbon),
+            vec![PirateCaptain::IronMeg]
+        );
+    }
+}
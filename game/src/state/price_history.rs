@@ -0,0 +1,103 @@
+use chrono::Month;
+use serde::{Deserialize, Serialize};
+
+use super::{Good, Inventory, Location, LocationMap};
+
+/// the prices the player saw on arriving in a port
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PriceSighting {
+    pub date: (u16, Month),
+    pub prices: Inventory,
+}
+
+/// every price the player has seen, per port and in date order
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PriceHistory(LocationMap<Vec<PriceSighting>>);
+
+/// how many months after `start` the given date is
+pub fn months_between(start: (u16, Month), date: (u16, Month)) -> i32 {
+    (date.0 as i32 - start.0 as i32) * 12 + date.1.number_from_month() as i32
+        - start.1.number_from_month() as i32
+}
+
+/// the date the given number of months after `date`
+pub fn add_months(date: (u16, Month), months: u16) -> (u16, Month) {
+    let months_into_year = date.1.number_from_month() as u16 - 1 + months;
+    let month = Month::try_from((months_into_year % 12 + 1) as u8).expect("months are 1-12");
+    (date.0 + months_into_year / 12, month)
+}
+
+impl PriceHistory {
+    /// records the prices seen in a port, replacing any seen there earlier the same month
+    pub fn record(&mut self, date: (u16, Month), location: &Location, prices: &Inventory) {
+        let sightings = self.0.get_mut(location);
+        if let Some(last) = sightings.last() {
+            if last.date == date {
+                sightings.pop();
+            }
+        }
+        sightings.push(PriceSighting {
+            date,
+            prices: prices.clone(),
+        });
+    }
+
+    pub fn sightings(&self, location: &Location) -> &[PriceSighting] {
+        self.0.get(location)
+    }
+
+    /// the prices of a good seen in a port, with the date each was seen
+    pub fn prices_of<'a>(
+        &'a self,
+        good: &'a Good,
+        location: &Location,
+    ) -> impl Iterator<Item = ((u16, Month), u32)> + 'a {
+        self.sightings(location)
+            .iter()
+            .map(|sighting| (sighting.date, *sighting.prices.get_good(good)))
+    }
+
+    /// the lowest and highest price of a good seen in any port
+    pub fn range_of(&self, good: &Good) -> Option<(u32, u32)> {
+        Location::variants()
+            .iter()
+            .flat_map(|location| self.prices_of(good, location))
+            .fold(None, |range, (_, price)| match range {
+                None => Some((price, price)),
+                Some((low, high)) => Some((low.min(price), high.max(price))),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn prices(tea: u32) -> Inventory {
+        Inventory::from([(Good::Tea, tea)])
+    }
+
+    #[test]
+    fn records_sightings() {
+        let mut history = PriceHistory::default();
+        assert_eq!(history.range_of(&Good::Tea), None);
+        history.record((1782, Month::March), &Location::London, &prices(30));
+        history.record((1782, Month::April), &Location::Venice, &prices(50));
+        history.record((1782, Month::May), &Location::London, &prices(20));
+        // seeing a port again in the same month replaces what was seen before
+        history.record((1782, Month::May), &Location::London, &prices(25));
+        assert_eq!(
+            history
+                .prices_of(&Good::Tea, &Location::London)
+                .collect::<Vec<_>>(),
+            vec![((1782, Month::March), 30), ((1782, Month::May), 25)]
+        );
+        assert_eq!(history.range_of(&Good::Tea), Some((25, 50)));
+        assert_eq!(
+            months_between((1782, Month::March), (1783, Month::February)),
+            11
+        );
+    }
+}
@@ -8,9 +8,8 @@ pub type PriceRanges = GoodsMap<(u32, u32)>;
 impl std::fmt::Display for PriceRanges {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut prev_avg: Option<f32> = None;
-        for (good, (low, high)) in self.into_iter().rev() {
-            let low = *low;
-            let high = *high;
+        for good in self.goods_by_price() {
+            let (low, high) = self[good];
             let spread = (high as f32 - low as f32) / low as f32;
             let spread = format!("{:.0}", 100.0 * spread);
             let avg = (high as f32 + low as f32) / 2.0;
@@ -32,55 +31,43 @@ impl std::fmt::Display for PriceRanges {
 
 impl PriceRanges {
     pub fn randomized_inventory(&self, rng: &mut StdRng) -> Inventory {
-        let mut gen = |(low, high): (u32, u32)| -> u32 { rng.next_u32() % (high - low) + low };
-        Inventory {
-            tea: gen(self.tea),
-            coffee: gen(self.coffee),
-            sugar: gen(self.sugar),
-            tobacco: gen(self.tobacco),
-            rum: gen(self.rum),
-            cotton: gen(self.cotton),
-        }
+        self.iter()
+            .map(|(good, (low, high))| (good, rng.next_u32() % (high - low) + low))
+            .collect()
     }
 
     pub fn avg_prices(&self) -> Inventory {
-        Inventory {
-            tea: (self.tea.0 + self.tea.1).div_ceil(2),
-            coffee: (self.coffee.0 + self.coffee.1).div_ceil(2),
-            sugar: (self.sugar.0 + self.sugar.1).div_ceil(2),
-            tobacco: (self.tobacco.0 + self.tobacco.1).div_ceil(2),
-            rum: (self.rum.0 + self.rum.1).div_ceil(2),
-            cotton: (self.cotton.0 + self.cotton.1).div_ceil(2),
-        }
+        self.iter()
+            .map(|(good, (low, high))| (good, (low + high).div_ceil(2)))
+            .collect()
+    }
+
+    /// the goods from the cheapest to the most expensive, on average
+    pub fn goods_by_price(&self) -> Vec<Good> {
+        let avg_prices = self.avg_prices();
+        let mut goods = Good::variants().to_vec();
+        goods.sort_by_key(|good| avg_prices[*good]);
+        goods
     }
 
-    pub(crate) fn from_start_price_and_spreads(
-        cotton_low: u32,
-        spreads: [f32; 6],
-        avg_proportions: [f32; 5],
+    /// the price ranges of every good, where the cheapest good (the one with the lowest tier)
+    /// can go as low as `lowest_price`
+    pub(crate) fn from_tiers_and_spreads(
+        lowest_price: u32,
+        tiers: &GoodsMap<f64>,
+        spreads: &GoodsMap<f64>,
     ) -> Self {
-        let cotton_high = (cotton_low as f32 * (1.0 + spreads[0])).ceil() as u32;
-        let cotton_avg = (cotton_low + cotton_high) as f32 / 2.0;
-        let mut last = cotton_avg;
-        let mut averages = vec![cotton_avg];
-        for avg_proportion in avg_proportions {
-            let new_avg = last * (avg_proportion);
-            averages.push(new_avg);
-            last = new_avg;
-        }
-        const GOODS: [Good; 6] = [
-            Good::Cotton,
-            Good::Rum,
-            Good::Tobacco,
-            Good::Sugar,
-            Good::Coffee,
-            Good::Tea,
-        ];
-        averages
-            .into_iter()
-            .zip(spreads)
-            .zip(GOODS)
-            .map(|((avg, spread), good)| {
+        let (cheapest, lowest_tier) = tiers
+            .iter()
+            .min_by(|a, b| a.1.total_cmp(b.1))
+            .expect("goods maps are always nonempty");
+        let cheapest_high = (lowest_price as f64 * (1.0 + spreads[cheapest])).ceil() as u32;
+        let cheapest_avg = (lowest_price + cheapest_high) as f64 / 2.0;
+        tiers
+            .iter()
+            .map(|(good, tier)| {
+                let avg = cheapest_avg * tier / lowest_tier;
+                let spread = spreads[good];
                 let high = (2.0 * avg * (spread + 1.0)) / (spread + 2.0);
                 let low = high / (spread + 1.0);
                 (good, (low.round() as u32, high.round() as u32))
@@ -88,22 +75,24 @@ impl PriceRanges {
             .collect::<PriceRanges>()
     }
 
-    pub fn generate_subsection(&self, cheap: Option<Good>, expensive: Option<Good>) -> Self {
+    /// the narrower price ranges of a port, where its cheap goods can go lower
+    /// and its expensive goods higher than the rest
+    pub fn generate_subsection(&self, cheap: &[Good], expensive: &[Good]) -> Self {
         self.iter()
             .map(|(good, (overall_low, overall_high))| {
-                if cheap.is_none() && expensive.is_none() {
+                if cheap.is_empty() && expensive.is_empty() {
                     // this is a "boring" location, read: home port
                     let low = ((((overall_high - overall_low) as f64) * 0.4) + *overall_low as f64)
                         .ceil() as u32;
                     let high = ((((overall_high - overall_low) as f64) * 0.6) + *overall_low as f64)
                         .ceil() as u32;
                     (good, (low, high))
-                } else if cheap.map(|x| x == good).unwrap_or(false) {
+                } else if cheap.contains(&good) {
                     let low = *overall_low;
                     let high = ((((overall_high - overall_low) as f64) * 0.6) + *overall_low as f64)
                         .ceil() as u32;
                     (good, (low, high))
-                } else if expensive.map(|x| x == good).unwrap_or(false) {
+                } else if expensive.contains(&good) {
                     let low = ((((overall_high - overall_low) as f64) * 0.4) + *overall_low as f64)
                         .ceil() as u32;
                     let high = *overall_high;
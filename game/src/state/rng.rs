@@ -1,19 +1,20 @@
+use std::cmp::min;
+
 use rand::{
     distributions::{Distribution, WeightedIndex},
     rngs::StdRng,
+    seq::SliceRandom,
     Rng, RngCore,
 };
 use tracing::{debug, instrument};
 
-use crate::state::goods_map::GoodsMap;
-
 use super::{
-    constants::{GOLD_PER_PIRATE_VICTORY_MAX, GOLD_PER_PIRATE_VICTORY_MIN},
     game_state::{LocationEvent, NoEffectEvent, PirateEncounterState},
     location_personalities::{
-        EventPossibility, EventWeights, LocationConfig, LocationPersonalities, LocationPersonality,
+        EventPossibility, LocationConfig, LocationPersonalities, LocationPersonality,
     },
-    LocationInfo,
+    travel_months_at, ContractOffer, ContractTerms, Customs, Duty, Lender, LocationInfo,
+    PirateCaptain, Rules, ShipClass, WarehouseMishap,
 };
 use super::{Good, PriceRanges};
 use super::{Inventory, Location};
@@ -21,26 +22,72 @@ use super::{Inventory, Location};
 /// A trait that abstracts the pieces of logic that need to use some kind of random number generation.
 /// Allows injecting a mocked (deterministic) implementation in testing.
 pub trait MerchantRng {
-    fn gen_gold_recovered_from_pirate_encounter(&mut self, total_pirates: u8) -> u32;
+    fn gen_gold_recovered_from_pirate_encounter(
+        &mut self,
+        total_pirates: u8,
+        gold_per_pirate: (u32, u32),
+    ) -> u32;
     fn gen_damage_from_pirates(&mut self, cur_pirates: u8) -> u8;
     fn gen_did_kill_a_pirate(&mut self, cannons: u8) -> bool;
-    fn gen_run_success(&mut self, cur_pirates: u8) -> bool;
-    fn gen_num_pirates_encountered(&mut self) -> u8;
+    /// whether the ship outruns the pirates, which a short-handed crew makes less likely.
+    /// manning is the share of the ship's berths filled
+    fn gen_run_success(&mut self, cur_pirates: u8, manning: f64) -> bool;
+    /// how many pirate ships a broadside from every cannon at once sinks, at most two
+    fn gen_ships_sunk_by_broadside(&mut self, cannons: u8, cur_pirates: u8) -> u8;
+    /// whether a boarding party takes the nearest pirate ship
+    fn gen_boarding_success(&mut self, cur_pirates: u8, manning: f64) -> bool;
+    /// whether the pirates take gold to leave
+    fn gen_gold_bribe_accepted(&mut self, cur_pirates: u8) -> bool;
+    /// whether the pirates take cargo to leave, which they'd have to sell on
+    fn gen_cargo_bribe_accepted(&mut self, cur_pirates: u8) -> bool;
+    /// the share of the hold the pirates take from a ship that surrenders
+    fn gen_share_of_hold_surrendered(&mut self, cur_pirates: u8) -> f64;
+    /// whether a ship lightened by dumping cargo gets away
+    fn gen_run_success_after_dumping(&mut self, cur_pirates: u8, manning: f64) -> bool;
+    /// how many of the sailors desert, the more the further morale has fallen
+    /// (discontent, from 0.0 to 1.0)
+    fn gen_deserters(&mut self, sailors: u32, discontent: f64) -> u32;
+    /// whether the crew mutinies, the more likely the further morale has fallen
+    fn gen_mutiny(&mut self, discontent: f64) -> bool;
+    fn gen_num_pirates_encountered(&mut self, range: (u8, u8)) -> u8;
+    /// which of the captains hunting these waters leads the pirates, if any of them does
+    fn gen_pirate_captain(
+        &mut self,
+        captains: &[PirateCaptain],
+        chance: f64,
+    ) -> Option<PirateCaptain>;
     fn gen_good_stolen(&mut self, goods_with_inventory: &[(Good, u32)]) -> (Good, u32);
+    /// the prices, event and contract board of a port on arriving there
     fn gen_location_info(
         &mut self,
         allow_events: bool,
+        location: &Location,
         location_personality: &LocationPersonality,
         player_net_worth: i32,
     ) -> LocationInfo;
-    fn gen_location_config(&mut self, starting_gold: u32) -> LocationConfig;
+    fn gen_location_config(&mut self, rules: &Rules) -> LocationConfig;
+    /// how much of the pressure the player's trading put on a market is left after some months
+    fn gen_market_pressure_remaining(&mut self, months: u32, recovery: f64) -> f64;
+    /// whether there is a run on the bank, and if so the share of the deposit lost
+    fn gen_bank_run(&mut self, chance: f64, max_loss: f64) -> Option<f64>;
+    /// whether thieves or spoilage take some of the goods in a warehouse, and if so which and how many
+    fn gen_warehouse_mishap(
+        &mut self,
+        goods_with_inventory: &[(Good, u32)],
+        theft_chance: f64,
+        spoilage_chance: f64,
+    ) -> Option<(WarehouseMishap, Good, u32)>;
+    fn gen_seed(&mut self) -> u64;
 }
 
 impl MerchantRng for StdRng {
-    fn gen_gold_recovered_from_pirate_encounter(&mut self, total_pirates: u8) -> u32 {
+    fn gen_gold_recovered_from_pirate_encounter(
+        &mut self,
+        total_pirates: u8,
+        (min_per_pirate, max_per_pirate): (u32, u32),
+    ) -> u32 {
         self.gen_range(
-            (GOLD_PER_PIRATE_VICTORY_MIN * (total_pirates as u32))
-                ..(GOLD_PER_PIRATE_VICTORY_MAX * (total_pirates as u32)),
+            (min_per_pirate * (total_pirates as u32))..(max_per_pirate * (total_pirates as u32)),
         )
     }
 
@@ -66,14 +113,75 @@ impl MerchantRng for StdRng {
         kill_a_pirate_possibilities[dist.sample(self)]
     }
 
-    fn gen_run_success(&mut self, cur_pirates: u8) -> bool {
-        let run_success_chance = logarithmic_decay(cur_pirates as u32, 0.5);
+    fn gen_run_success(&mut self, cur_pirates: u8, manning: f64) -> bool {
+        let run_success_chance = logarithmic_decay(cur_pirates as u32, 0.5) * short_handed(manning);
         let random_value: f64 = self.gen();
         random_value < run_success_chance
     }
 
-    fn gen_num_pirates_encountered(&mut self) -> u8 {
-        self.gen_range(2..=4)
+    fn gen_ships_sunk_by_broadside(&mut self, cannons: u8, cur_pirates: u8) -> u8 {
+        // a broadside misses more often than steady fire, but with enough cannons
+        // can sink two ships at once
+        let sunk_possibilities = [0, 1, 2];
+        let weights = [4, cannons, cannons / 2];
+        let dist = WeightedIndex::new(weights).unwrap();
+        min(sunk_possibilities[dist.sample(self)], cur_pirates)
+    }
+
+    fn gen_boarding_success(&mut self, cur_pirates: u8, manning: f64) -> bool {
+        // the other pirates come to the aid of the one boarded,
+        // and every sailor left behind is one fewer in the boarding party
+        let boarding_success_chance = logarithmic_decay(cur_pirates as u32, 0.8) * manning;
+        self.gen_bool(boarding_success_chance.clamp(0.0, 1.0))
+    }
+
+    fn gen_gold_bribe_accepted(&mut self, cur_pirates: u8) -> bool {
+        // the more captains there are to share it, the less each of them thinks of it
+        let accepted_chance = 0.9 - 0.15 * cur_pirates as f64;
+        self.gen_bool(accepted_chance.clamp(0.1, 1.0))
+    }
+
+    fn gen_cargo_bribe_accepted(&mut self, cur_pirates: u8) -> bool {
+        let accepted_chance = 0.75 - 0.15 * cur_pirates as f64;
+        self.gen_bool(accepted_chance.clamp(0.05, 1.0))
+    }
+
+    fn gen_share_of_hold_surrendered(&mut self, cur_pirates: u8) -> f64 {
+        // more pirates take more of it
+        let share = 0.3 + 0.1 * cur_pirates as f64 + self.gen_range(0.0..0.1);
+        share.min(1.0)
+    }
+
+    fn gen_run_success_after_dumping(&mut self, cur_pirates: u8, manning: f64) -> bool {
+        // a lighter ship is harder to catch
+        let run_success_chance = logarithmic_decay(cur_pirates as u32, 0.3) * short_handed(manning);
+        let random_value: f64 = self.gen();
+        random_value < run_success_chance
+    }
+
+    fn gen_deserters(&mut self, sailors: u32, discontent: f64) -> u32 {
+        // up to half the crew, when morale is at its lowest
+        let most = (f64::from(sailors) * discontent / 2.0).ceil() as u32;
+        self.gen_range(0..=most.min(sailors))
+    }
+
+    fn gen_mutiny(&mut self, discontent: f64) -> bool {
+        self.gen_bool(discontent.clamp(0.0, 1.0))
+    }
+
+    fn gen_num_pirates_encountered(&mut self, (min, max): (u8, u8)) -> u8 {
+        self.gen_range(min..=max)
+    }
+
+    fn gen_pirate_captain(
+        &mut self,
+        captains: &[PirateCaptain],
+        chance: f64,
+    ) -> Option<PirateCaptain> {
+        if captains.is_empty() || !self.gen_bool(chance.clamp(0.0, 1.0)) {
+            return None;
+        }
+        captains.choose(self).copied()
     }
 
     fn gen_good_stolen(&mut self, goods_with_inventory: &[(Good, u32)]) -> (Good, u32) {
@@ -93,6 +201,7 @@ impl MerchantRng for StdRng {
     fn gen_location_info(
         &mut self,
         allow_events: bool,
+        location: &Location,
         location_personality: &LocationPersonality,
         player_net_worth: i32,
     ) -> LocationInfo {
@@ -132,80 +241,91 @@ impl MerchantRng for StdRng {
                     let no_effect_event = no_effect_event_possibilities[dist.sample(self)];
                     Some(LocationEvent::NoEffect(no_effect_event))
                 }
+                EventPossibility::CustomsInspection => Some(LocationEvent::CustomsInspection),
             };
         };
+        location_info.contracts = gen_contracts(self, location, location_personality);
         location_info
     }
 
-    fn gen_location_config(&mut self, starting_gold: u32) -> LocationConfig {
+    fn gen_location_config(&mut self, rules: &Rules) -> LocationConfig {
         // this dictates the widest range possible for the prices of each good
         // locations will have ranges within these
-        let overall_price_ranges = PriceRanges::from_start_price_and_spreads(
-            starting_gold / 100, // the lowest price for cotton can be bought 100x at game start
-            [5.0, 3.0, 2.0, 1.5, 1.0, 0.75],
-            [5.0, 4.2, 3.4, 2.6, 1.8],
-        );
-        let home_port = match self.gen_range(0..=5) {
-            0 => Location::London,
-            1 => Location::Savannah,
-            2 => Location::Lisbon,
-            3 => Location::Amsterdam,
-            4 => Location::CapeTown,
-            _ => Location::Venice,
+        let overall_price_ranges = rules.overall_price_ranges();
+        let home_ports = Location::home_ports();
+        let home_port = home_ports[self.gen_range(0..home_ports.len())];
+        // every good is cheap in one port away from home and expensive in another,
+        // dealt out to the ports in a random order
+        let goods = Good::variants_random_order(self);
+        let ports = Location::variants()
+            .iter()
+            .filter(|location| *location != &home_port)
+            .collect::<Vec<&Location>>();
+        let dealt_to = |location: &Location, shift: usize| {
+            goods
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| ports[(i + shift) % ports.len()] == location)
+                .map(|(_, good)| *good)
+                .collect::<Vec<Good>>()
         };
-        let mut visited_cheap_goods: Vec<Good> = Vec::new();
-        let mut visited_expensive_goods: Vec<Good> = Vec::new();
         let personalities = Location::variants()
             .iter()
             .map(|location| {
                 let location_personality = if location == &home_port {
                     // home port should be "boring"
-                    let price_ranges = overall_price_ranges.generate_subsection(None, None);
-                    let event_weights = EventWeights {
-                        no_event: 6,
-                        cheap_good: 1,
-                        expensive_good: 1,
-                        find_goods: 0,
-                        stolen_goods: 1,
-                        can_buy_cannon: 1,
-                        pirate_encounter: 1,
-                        can_buy_more_hold_space: 0,
-                        no_effect: 3,
+                    let price_ranges = overall_price_ranges.generate_subsection(&[], &[]);
+                    let event_weights = rules.home_port_event_weights.clone();
+                    let lender = Lender {
+                        interest_rate: rules.debt_interest_rate,
+                        cap: rules
+                            .starting_gold
+                            .saturating_mul(rules.starting_debt_multiplier)
+                            .saturating_mul(2),
+                        term_months: rules.loan_term_months_max,
                     };
+                    let contracts = contract_terms(rules, rules.contract_premium_min);
                     LocationPersonality {
                         price_ranges,
                         event_weights,
+                        lender,
+                        contracts,
+                        cheap_goods: vec![],
+                        expensive_goods: vec![],
+                        customs: Customs::default(),
                     }
                 } else {
-                    // need to generate cheap and expensive goods
-                    // that haven't been seen before
-                    // and also aren't equal to each other
-                    let cheap = Good::variants_iter()
-                        .find(|x| !visited_cheap_goods.contains(x))
-                        .copied()
-                        .unwrap();
-                    let expensive = Good::variants_iter()
-                        .find(|x| !visited_expensive_goods.contains(x) && cheap != **x)
-                        .copied()
-                        .unwrap();
-                    visited_cheap_goods.push(cheap);
-                    visited_expensive_goods.push(expensive);
+                    // a good is never both cheap and expensive in the same port
+                    let cheap_goods = dealt_to(location, 0);
+                    let expensive_goods = dealt_to(location, 1);
                     let price_ranges =
-                        overall_price_ranges.generate_subsection(Some(cheap), Some(expensive));
-                    let event_weights = EventWeights {
-                        no_event: 6,
-                        cheap_good: 1,
-                        expensive_good: 1,
-                        find_goods: 1,
-                        stolen_goods: 1,
-                        can_buy_cannon: 1,
-                        pirate_encounter: 1,
-                        can_buy_more_hold_space: 1,
-                        no_effect: 1,
+                        overall_price_ranges.generate_subsection(&cheap_goods, &expensive_goods);
+                    let event_weights = rules.port_event_weights.clone();
+                    let lender = Lender {
+                        interest_rate: self
+                            .gen_range(rules.loan_interest_rate_min..=rules.loan_interest_rate_max),
+                        cap: rules.starting_gold.saturating_mul(self.gen_range(
+                            rules.loan_cap_multiplier_min..=rules.loan_cap_multiplier_max,
+                        )),
+                        term_months: self
+                            .gen_range(rules.loan_term_months_min..=rules.loan_term_months_max),
                     };
+                    // to the hundredth, which survives being saved and loaded again
+                    let premium = (self
+                        .gen_range(rules.contract_premium_min..=rules.contract_premium_max)
+                        * 100.0)
+                        .round()
+                        / 100.0;
+                    let contracts = contract_terms(rules, premium);
+                    let customs = gen_customs(self, rules, &cheap_goods, &expensive_goods);
                     LocationPersonality {
                         price_ranges,
                         event_weights,
+                        lender,
+                        contracts,
+                        cheap_goods,
+                        expensive_goods,
+                        customs,
                     }
                 };
                 (*location, location_personality)
@@ -217,6 +337,130 @@ impl MerchantRng for StdRng {
             personalities,
         }
     }
+
+    fn gen_market_pressure_remaining(&mut self, months: u32, recovery: f64) -> f64 {
+        // markets recover at roughly the given rate, some months quicker than others
+        let remaining = (1.0 - recovery).powi(months as i32);
+        let jitter: f64 = self.gen_range(0.8..1.2);
+        (remaining * jitter).min(1.0)
+    }
+
+    fn gen_bank_run(&mut self, chance: f64, max_loss: f64) -> Option<f64> {
+        if !self.gen_bool(chance) {
+            return None;
+        }
+        // some runs are weathered without losing anything, only freezing withdrawals
+        Some(self.gen_range(0.0..=max_loss))
+    }
+
+    fn gen_warehouse_mishap(
+        &mut self,
+        goods_with_inventory: &[(Good, u32)],
+        theft_chance: f64,
+        spoilage_chance: f64,
+    ) -> Option<(WarehouseMishap, Good, u32)> {
+        let mishap = if self.gen_bool(theft_chance) {
+            WarehouseMishap::Theft
+        } else if self.gen_bool(spoilage_chance) {
+            WarehouseMishap::Spoilage
+        } else {
+            return None;
+        };
+        // some of one good goes missing, as with goods stolen from the hold
+        let (good, amount) = self.gen_good_stolen(goods_with_inventory);
+        Some((mishap, good, amount))
+    }
+
+    fn gen_seed(&mut self) -> u64 {
+        self.next_u64()
+    }
+}
+
+/// the contracts a port's merchants offer, with the given premium over usual prices
+fn contract_terms(rules: &Rules, premium: f64) -> ContractTerms {
+    ContractTerms {
+        offers: rules.contracts_offered,
+        amount: (rules.contract_amount_min, rules.contract_amount_max),
+        months: (rules.contract_months_min, rules.contract_months_max),
+        premium,
+    }
+}
+
+/// a port taxes the goods it sells cheaply on their way out and the goods it sells dear
+/// on their way in, and may forbid one of the rest
+fn gen_customs(
+    rng: &mut StdRng,
+    rules: &Rules,
+    cheap_goods: &[Good],
+    expensive_goods: &[Good],
+) -> Customs {
+    let mut duties = |goods: &[Good]| {
+        goods
+            .iter()
+            .map(|good| Duty {
+                good: *good,
+                // to the hundredth, a whole percentage
+                rate: (rng.gen_range(rules.duty_rate_min..=rules.duty_rate_max) * 100.0).round()
+                    / 100.0,
+            })
+            .collect::<Vec<Duty>>()
+    };
+    let export_duties = duties(cheap_goods);
+    let import_duties = duties(expensive_goods);
+    let others = Good::variants_iter()
+        .filter(|good| !cheap_goods.contains(good) && !expensive_goods.contains(good))
+        .copied()
+        .collect::<Vec<Good>>();
+    let contraband = if rng.gen_bool(rules.contraband_chance) {
+        others.choose(rng).copied().into_iter().collect()
+    } else {
+        vec![]
+    };
+    Customs {
+        import_duties,
+        export_duties,
+        contraband,
+    }
+}
+
+/// fills a port's contract board with deliveries to the other ports
+fn gen_contracts(
+    rng: &mut StdRng,
+    issuer: &Location,
+    personality: &LocationPersonality,
+) -> Vec<ContractOffer> {
+    let terms = &personality.contracts;
+    let destinations = Location::variants()
+        .iter()
+        .filter(|location| *location != issuer)
+        .copied()
+        .collect::<Vec<Location>>();
+    (0..terms.offers)
+        .filter_map(|_| {
+            let destination = *destinations.choose(rng)?;
+            let good = Good::random(rng);
+            let amount = rng.gen_range(terms.amount.0..=terms.amount.1);
+            // there's always time enough to make the voyage, whatever ship is sailing it
+            let months = rng
+                .gen_range(terms.months.0..=terms.months.1)
+                .max(travel_months_at(
+                    issuer,
+                    &destination,
+                    ShipClass::slowest_speed(),
+                ));
+            // the merchants pay over what the good usually goes for in their port
+            let (low, high) = *personality.price_ranges.get_good(&good);
+            let usual_price = f64::from(low + high) / 2.0;
+            let reward = (f64::from(amount) * usual_price * terms.premium).round() as u32;
+            Some(ContractOffer {
+                good,
+                amount,
+                destination,
+                months,
+                reward,
+            })
+        })
+        .collect()
 }
 
 #[instrument(level = "debug", skip_all)]
@@ -228,16 +472,10 @@ fn gen_cheap_good(
     // generate a low price for a random good
     // that's some amount lower than the lowest price the good can be
     let good = Good::random(rng);
-    const MULTIPLES: GoodsMap<f32> = GoodsMap {
-        tea: 0.85,
-        coffee: 0.8,
-        sugar: 0.75,
-        tobacco: 0.7,
-        rum: 0.65,
-        cotton: 0.6,
-    };
+    // cheaper goods can fall further
+    let multiple = 0.85 - 0.25 * (1.0 - price_rank(price_config, &good));
     let normal_low = price_config.get_good(&good).0;
-    let mut lowest_low = (MULTIPLES.get_good(&good) * (normal_low as f32)).round() as u32;
+    let mut lowest_low = (multiple * (normal_low as f32)).round() as u32;
     if lowest_low == 0 {
         lowest_low += 1;
     };
@@ -257,16 +495,10 @@ fn gen_expensive_good(
     // generate a high price for a random good
     // that's some amount high than the highest price the good can be
     let good = Good::random(rng);
-    const MULTIPLES: GoodsMap<f32> = GoodsMap {
-        tea: 1.15,
-        coffee: 1.2,
-        sugar: 1.25,
-        tobacco: 1.3,
-        rum: 1.35,
-        cotton: 1.4,
-    };
+    // cheaper goods can climb further
+    let multiple = 1.15 + 0.25 * (1.0 - price_rank(price_config, &good));
     let normal_high = price_config.get_good(&good).1;
-    let mut highest_high = (MULTIPLES.get_good(&good) * (normal_high as f32)).round() as u32;
+    let mut highest_high = (multiple * (normal_high as f32)).round() as u32;
     if highest_high > 9999 {
         highest_high = 9999;
     };
@@ -302,40 +534,55 @@ fn gen_find_goods(
     } else {
         player_net_worth as u32
     };
-    let comparison_distances = Inventory {
-        tea: comparison_prices.tea.abs_diff(net_worth),
-        coffee: comparison_prices.coffee.abs_diff(net_worth),
-        sugar: comparison_prices.sugar.abs_diff(net_worth),
-        tobacco: comparison_prices.tobacco.abs_diff(net_worth),
-        rum: comparison_prices.rum.abs_diff(net_worth),
-        cotton: comparison_prices.cotton.abs_diff(net_worth),
-    };
+    let comparison_distances = comparison_prices
+        .iter()
+        .map(|(good, price)| (good, price.abs_diff(net_worth)))
+        .collect::<Inventory>();
     debug!("comparison_distances: {}", comparison_distances);
     let min_good = comparison_distances.min_good();
     debug!("closest comparison good (phase of game): {}", min_good);
-    let weights: [u8; 6] = match min_good {
-        Good::Tea => [3, 2, 1, 1, 1, 1], // 33% chance tea, 22% coffee, 11% sugar tobacco rum cotton
-        Good::Coffee => [2, 3, 2, 1, 1, 1],
-        Good::Sugar => [1, 2, 3, 2, 1, 1],
-        Good::Tobacco => [0, 1, 2, 3, 2, 1],
-        Good::Rum => [0, 0, 1, 2, 3, 2],
-        Good::Cotton => [0, 0, 0, 1, 2, 3], // 0% chance tea coffee sugar, 17% tobacco, 33% rum, 50% cotton
-    };
+    // the goods a little cheaper or pricier than the phase's good are found less often,
+    // and goods much pricier than it not at all.
+    // eg. in the phase of the most expensive good there's a 33% chance of finding it,
+    //     22% the next most expensive, and 11% each of the rest
+    let goods = price_config.goods_by_price();
+    let phase = goods
+        .iter()
+        .position(|good| *good == min_good)
+        .expect("every good has a price") as i32;
+    let weights = (0..goods.len() as i32)
+        .map(|i| {
+            let pricier_by = i - phase;
+            if pricier_by <= 0 {
+                (3 + pricier_by).max(1) as u8
+            } else {
+                (3 - pricier_by).max(0) as u8
+            }
+        })
+        .collect::<Vec<u8>>();
     debug!("weights: {:?}", weights);
     let dist = WeightedIndex::new(weights).unwrap();
-    const GOODS_SLICE: [Good; 6] = [
-        Good::Tea,
-        Good::Coffee,
-        Good::Sugar,
-        Good::Tobacco,
-        Good::Rum,
-        Good::Cotton,
-    ];
-    let good = GOODS_SLICE[dist.sample(rng)];
+    let good = goods[dist.sample(rng)];
     let amount = (rng.next_u32() % 10) + 1;
     LocationEvent::FindGoods(good, amount)
 }
 
+/// where a good's average price falls among the others, from 0.0 for the cheapest to 1.0 for the
+/// most expensive
+fn price_rank(price_config: &PriceRanges, good: &Good) -> f32 {
+    let goods = price_config.goods_by_price();
+    let rank = goods
+        .iter()
+        .position(|x| x == good)
+        .expect("every good has a price");
+    rank as f32 / (goods.len() - 1).max(1) as f32
+}
+
+/// a ship with every berth filled sails as well as it can, and one with none filled half as well
+fn short_handed(manning: f64) -> f64 {
+    0.5 + 0.5 * manning.clamp(0.0, 1.0)
+}
+
 fn logarithmic_decay(count: u32, decay_factor: f64) -> f64 {
     let initial_probability: f64 = 1.0; // 100%
     let decayed = initial_probability - decay_factor * (count as f64 + 1.0).ln();
@@ -353,7 +600,7 @@ mod tests {
     #[test]
     fn gen_gold_recovered_from_pirate_encounter() {
         assert_eq!(
-            StdRng::seed_from_u64(42).gen_gold_recovered_from_pirate_encounter(3),
+            StdRng::seed_from_u64(42).gen_gold_recovered_from_pirate_encounter(3, (500, 2000)),
             2100
         );
     }
@@ -370,12 +617,104 @@ mod tests {
 
     #[test]
     fn gen_run_success() {
-        assert!(StdRng::seed_from_u64(42).gen_run_success(3));
+        assert!(StdRng::seed_from_u64(42).gen_run_success(3, 1.0));
+    }
+
+    #[test]
+    fn gen_ships_sunk_by_broadside() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..20 {
+            assert_eq!(rng.gen_ships_sunk_by_broadside(0, 3), 0);
+            assert!(rng.gen_ships_sunk_by_broadside(16, 3) <= 2);
+            assert!(rng.gen_ships_sunk_by_broadside(16, 1) <= 1);
+        }
+    }
+
+    #[test]
+    fn gen_boarding_success() {
+        assert!(StdRng::seed_from_u64(42).gen_boarding_success(1, 1.0));
+        assert!(!StdRng::seed_from_u64(42).gen_boarding_success(1, 0.0));
+    }
+
+    #[test]
+    fn gen_bribe_accepted() {
+        assert!(StdRng::seed_from_u64(42).gen_gold_bribe_accepted(2));
+        assert!(!StdRng::seed_from_u64(42).gen_cargo_bribe_accepted(4));
+    }
+
+    #[test]
+    fn gen_share_of_hold_surrendered() {
+        let share = StdRng::seed_from_u64(42).gen_share_of_hold_surrendered(2);
+        assert!((0.5..0.6).contains(&share));
+    }
+
+    #[test]
+    fn gen_run_success_after_dumping() {
+        assert!(StdRng::seed_from_u64(42).gen_run_success_after_dumping(3, 1.0));
+    }
+
+    #[test]
+    fn gen_deserters() {
+        let mut rng = StdRng::seed_from_u64(42);
+        assert_eq!(rng.gen_deserters(12, 0.0), 0);
+        for _ in 0..20 {
+            assert!(rng.gen_deserters(12, 1.0) <= 6);
+        }
+    }
+
+    #[test]
+    fn gen_mutiny() {
+        let mut rng = StdRng::seed_from_u64(42);
+        assert!(!rng.gen_mutiny(0.0));
+        assert!(rng.gen_mutiny(1.0));
     }
 
     #[test]
     fn gen_num_pirates_encountered() {
-        assert_eq!(StdRng::seed_from_u64(42).gen_num_pirates_encountered(), 2);
+        assert_eq!(
+            StdRng::seed_from_u64(42).gen_num_pirates_encountered((2, 4)),
+            2
+        );
+    }
+
+    #[test]
+    fn gen_pirate_captain() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let captains = [PirateCaptain::IronMeg, PirateCaptain::GentlemanFlint];
+        assert_eq!(rng.gen_pirate_captain(&captains, 0.0), None);
+        assert_eq!(rng.gen_pirate_captain(&[], 1.0), None);
+        assert!(rng
+            .gen_pirate_captain(&captains, 1.0)
+            .is_some_and(|captain| captains.contains(&captain)));
+    }
+
+    #[test]
+    fn gen_market_pressure_remaining() {
+        let remaining = StdRng::seed_from_u64(42).gen_market_pressure_remaining(2, 0.3);
+        assert!((0.49 * 0.8..0.49 * 1.2).contains(&remaining));
+    }
+
+    #[test]
+    fn gen_bank_run() {
+        let mut rng = StdRng::seed_from_u64(42);
+        assert_eq!(rng.gen_bank_run(0.0, 0.5), None);
+        let lost = rng.gen_bank_run(1.0, 0.5);
+        assert!(lost.is_some_and(|lost| (0.0..=0.5).contains(&lost)));
+    }
+
+    #[test]
+    fn gen_warehouse_mishap() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let goods = [(Good::Rum, 10)];
+        assert_eq!(rng.gen_warehouse_mishap(&goods, 0.0, 0.0), None);
+        let (mishap, good, amount) = rng.gen_warehouse_mishap(&goods, 0.0, 1.0).unwrap();
+        assert_eq!((mishap, good), (WarehouseMishap::Spoilage, Good::Rum));
+        assert!((1..10).contains(&amount));
+    }
+
+    #[test]
+    fn gen_seed() {
+        assert_eq!(StdRng::seed_from_u64(42).gen_seed(), 9713269763989775522);
     }
 
     #[test]
@@ -401,15 +740,20 @@ mod tests {
         assert_eq!(
             StdRng::seed_from_u64(42).gen_location_info(
                 true,
+                &Location::London,
                 &LocationPersonality {
-                    price_ranges: PriceRanges {
-                        tea: (4253, 7442),
-                        coffee: (2166, 4332),
-                        sugar: (714, 1785),
-                        tobacco: (184, 551),
-                        rum: (35, 140),
-                        cotton: (5, 30)
-                    },
+                    price_ranges: PriceRanges::from([
+                        (Good::Tea, (4253, 7442)),
+                        (Good::Coffee, (2166, 4332)),
+                        (Good::Sugar, (714, 1785)),
+                        (Good::Tobacco, (184, 551)),
+                        (Good::Rum, (35, 140)),
+                        (Good::Cotton, (5, 30)),
+                        (Good::Spices, (1200, 2700)),
+                        (Good::Silk, (3000, 5700)),
+                        (Good::Indigo, (100, 350)),
+                        (Good::Porcelain, (5000, 8000)),
+                    ]),
                     event_weights: EventWeights {
                         no_event: 1,
                         cheap_good: 1,
@@ -419,22 +763,83 @@ mod tests {
                         can_buy_cannon: 1,
                         pirate_encounter: 1,
                         can_buy_more_hold_space: 1,
-                        no_effect: 1
-                    }
+                        no_effect: 1,
+                        customs_inspection: 0,
+                    },
+                    lender: Lender::default(),
+                    contracts: ContractTerms::default(),
+                    cheap_goods: vec![],
+                    expensive_goods: vec![],
+                    customs: Customs::default(),
                 },
                 10000
             ),
             LocationInfo {
-                prices: Inventory {
-                    tea: 4926,
-                    coffee: 2425,
-                    sugar: 1411,
-                    tobacco: 290,
-                    rum: 59,
-                    cotton: 15
-                },
-                event: Some(LocationEvent::NoEffect(NoEffectEvent::SunnyDay))
+                prices: Inventory::from([
+                    (Good::Tea, 4926),
+                    (Good::Coffee, 2425),
+                    (Good::Sugar, 1411),
+                    (Good::Tobacco, 290),
+                    (Good::Rum, 59),
+                    (Good::Cotton, 15),
+                    (Good::Spices, 2562),
+                    (Good::Silk, 4476),
+                    (Good::Indigo, 218),
+                    (Good::Porcelain, 5279)
+                ]),
+                event: Some(LocationEvent::CanBuyCannon),
+                contracts: vec![],
             }
         );
     }
+
+    #[test]
+    fn gen_contracts() {
+        let personality = LocationPersonality {
+            price_ranges: PriceRanges::from([
+                (Good::Tea, (4000, 8000)),
+                (Good::Coffee, (2000, 4000)),
+                (Good::Sugar, (700, 1700)),
+                (Good::Tobacco, (200, 600)),
+                (Good::Rum, (40, 140)),
+                (Good::Cotton, (5, 30)),
+                (Good::Spices, (1200, 2700)),
+                (Good::Silk, (3000, 5700)),
+                (Good::Indigo, (100, 350)),
+                (Good::Porcelain, (5000, 8000)),
+            ]),
+            event_weights: Rules::default().port_event_weights,
+            lender: Lender::default(),
+            contracts: ContractTerms {
+                offers: 5,
+                amount: (20, 20),
+                months: (3, 6),
+                premium: 1.5,
+            },
+            cheap_goods: vec![],
+            expensive_goods: vec![],
+            customs: Customs::default(),
+        };
+        let offers = super::gen_contracts(
+            &mut StdRng::seed_from_u64(42),
+            &Location::Venice,
+            &personality,
+        );
+        assert_eq!(offers.len(), 5);
+        for offer in offers {
+            assert_ne!(offer.destination, Location::Venice);
+            let slowest_voyage = travel_months_at(
+                &Location::Venice,
+                &offer.destination,
+                ShipClass::slowest_speed(),
+            );
+            assert!((3..=6.max(slowest_voyage)).contains(&offer.months));
+            assert!(offer.months >= slowest_voyage);
+            let (low, high) = *personality.price_ranges.get_good(&offer.good);
+            assert_eq!(
+                offer.reward,
+                (20.0 * f64::from(low + high) / 2.0 * 1.5) as u32
+            );
+        }
+    }
 }
@@ -0,0 +1,668 @@
+use std::{
+    fmt::{self, Display},
+    fs, io,
+    path::Path,
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    goods_map::GoodsMap, location_personalities::EventWeights, ship_class::SHIP_CLASSES, Good,
+    PriceRanges, ShipClass,
+};
+
+const DEFAULT_GAME_LENGTH_YEARS: u16 = 3;
+
+/// The balance constants of the game.
+/// Can be loaded from a TOML or JSON file, where any value left out keeps its default.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Rules {
+    /// how many years the game lasts
+    pub game_length_years: u16,
+    pub starting_gold: u32,
+    /// the starting debt, as a multiple of the starting gold
+    pub starting_debt_multiplier: u32,
+    /// how much the debt grows each month, eg. 0.1 is 10%
+    pub debt_interest_rate: f64,
+    /// the cannons on the sloop the player starts with
+    pub starting_cannons: u8,
+    pub cannon_cost: u32,
+    /// what a shipwright charges to mend each point of damage to the hull
+    pub repair_cost: u32,
+    /// at or below this much hull health the ship sails slower,
+    /// each voyage taking `slow_hull_months` longer
+    pub slow_hull_health: u8,
+    pub slow_hull_months: u16,
+    /// the sailors aboard the sloop the player starts with, and their morale from 0 to 100
+    pub starting_crew: u32,
+    pub starting_morale: u8,
+    /// the sailors needed to fire each cannon
+    pub crew_per_cannon: u32,
+    /// what each sailor is paid for every month at sea
+    pub crew_wage: u32,
+    /// what each new sailor is paid for signing on
+    pub hiring_fee: u32,
+    /// how much morale rises for every month the crew's wages are paid,
+    /// and falls for a voyage they aren't paid in full
+    pub paid_morale_gain: u8,
+    pub unpaid_morale_loss: u8,
+    /// how much morale falls when pirates overrun the ship
+    pub defeat_morale_loss: u8,
+    /// below this morale some sailors desert on reaching port
+    pub desertion_morale: u8,
+    /// below this morale the crew may mutiny on reaching port
+    pub mutiny_morale: u8,
+    /// the share of the sailors and of the gold that mutineers make off with
+    pub mutiny_loss: f64,
+    /// the lowest price the cheapest good (cotton) can have.
+    /// every other price is derived from this and the tiers and spreads below
+    pub lowest_price: u32,
+    /// each good's average price, as a multiple of the average price of the cheapest good
+    pub price_tiers: GoodsMap<f64>,
+    /// how far the high price of each good is above its low price,
+    /// eg. 5.0 means the high price is 6x the low price
+    pub price_spreads: GoodsMap<f64>,
+    /// what each good is called, in letters and spaces the screens have room for
+    pub good_names: GoodsMap<String>,
+    /// the letter that picks each good, which must be in its name and not be `b` (for back)
+    pub good_keys: GoodsMap<char>,
+    pub home_port_event_weights: EventWeights,
+    pub port_event_weights: EventWeights,
+    /// the fewest and most pirate ships that can be encountered at once
+    pub min_pirates_encountered: u8,
+    pub max_pirates_encountered: u8,
+    /// the gold recovered from the wreckage for each pirate ship in a won fight
+    pub gold_per_pirate_victory_min: u32,
+    pub gold_per_pirate_victory_max: u32,
+    /// the gold the pirates ask to leave, for each of their ships still afloat
+    pub pirate_bribe_gold: u32,
+    /// the share of every good in the hold offered to the pirates to leave, eg. 0.25 is a quarter
+    pub cargo_bribe_share: f64,
+    /// the share of every good in the hold thrown overboard to outrun the pirates
+    pub dump_cargo_share: f64,
+    /// the chance a pirate fleet sails under one of the named captains who hunt those waters
+    pub named_captain_chance: f64,
+    /// what each time a captain is run from or wins a fight adds to the bounty on them
+    pub bounty_per_infamy: u32,
+    /// how much each unit bought (or sold) in a port raises (or lowers) the price there,
+    /// eg. 0.002 means buying 100 units raises the price by about 22%
+    pub market_impact: f64,
+    /// how much of the price change from trading wears off each month, eg. 0.3 is 30%
+    pub market_recovery: f64,
+    /// the lowest and highest monthly interest rates of the lenders away from the home port.
+    /// the home port's lender charges the debt_interest_rate
+    pub loan_interest_rate_min: f64,
+    pub loan_interest_rate_max: f64,
+    /// the most each lender away from the home port will lend, as a multiple of the starting gold.
+    /// the home port's lender lends up to twice the starting debt
+    pub loan_cap_multiplier_min: u32,
+    pub loan_cap_multiplier_max: u32,
+    /// how many months loans have to be repaid within
+    pub loan_term_months_min: u16,
+    pub loan_term_months_max: u16,
+    /// how much money in the bank grows each month, eg. 0.02 is 2%
+    pub bank_interest_rate: f64,
+    /// the chance each month of a run on the bank, while there is money in it. it's rolled for
+    /// every month of a voyage, up to the first run, and the player hears of it on arriving
+    pub bank_run_chance: f64,
+    /// the largest share of the deposit a run on the bank can lose, eg. 0.5 is half
+    pub bank_run_max_loss: f64,
+    /// how many months withdrawals are frozen for after a run on the bank
+    pub bank_run_freeze_months: u16,
+    /// the monthly rent of a warehouse in a port away from home
+    pub warehouse_rent: u32,
+    /// the chance each month of thieves or spoilage taking some of the goods in a warehouse
+    pub warehouse_theft_chance: f64,
+    pub warehouse_spoilage_chance: f64,
+    /// how much the quality of each good changes for every month it's held,
+    /// eg. -0.05 loses 5% of its value a month, while 0.02 gains 2%
+    pub good_aging: GoodsMap<f64>,
+    /// the worst and best quality a good can age to, where 1.0 is as good as when it was acquired
+    pub min_quality: f64,
+    pub max_quality: f64,
+    /// how many contracts are on each port's board
+    pub contracts_offered: u8,
+    /// the least and most of a good a contract asks for
+    pub contract_amount_min: u32,
+    pub contract_amount_max: u32,
+    /// the fewest and most months there are to deliver a contract
+    pub contract_months_min: u16,
+    pub contract_months_max: u16,
+    /// the least and most more than a good's usual price that each port's merchants pay
+    /// for deliveries, eg. 1.5 is 50% more. the home port's merchants pay the least
+    pub contract_premium_min: f64,
+    pub contract_premium_max: f64,
+    /// the share of the reward paid as a penalty for missing a contract's deadline
+    pub contract_penalty: f64,
+    /// how many contracts can be taken on at once
+    pub max_active_contracts: u8,
+    /// the least and most of a good's price charged as duty by ports away from home,
+    /// on the goods they sell cheaply when bought there and the goods they sell dear when sold there
+    pub duty_rate_min: f64,
+    pub duty_rate_max: f64,
+    /// the chance each port away from home forbids a good, which customs inspections seize
+    pub contraband_chance: f64,
+    /// the fine for carrying contraband, and the bribe that avoids it and the seizure,
+    /// as shares of what the contraband is worth in the port
+    pub customs_fine: f64,
+    pub customs_bribe: f64,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules {
+            game_length_years: DEFAULT_GAME_LENGTH_YEARS,
+            starting_gold: 500,
+            starting_debt_multiplier: 3,
+            debt_interest_rate: 0.1,
+            starting_cannons: 1,
+            cannon_cost: 5000,
+            repair_cost: 200,
+            slow_hull_health: 2,
+            slow_hull_months: 1,
+            starting_crew: 12,
+            starting_morale: 75,
+            crew_per_cannon: 2,
+            crew_wage: 3,
+            hiring_fee: 20,
+            paid_morale_gain: 5,
+            unpaid_morale_loss: 20,
+            defeat_morale_loss: 25,
+            desertion_morale: 40,
+            mutiny_morale: 20,
+            mutiny_loss: 0.5,
+            lowest_price: 5,
+            price_tiers: GoodsMap::from_fn(|good| good.info().price_tier),
+            price_spreads: GoodsMap::from_fn(|good| good.info().price_spread),
+            good_names: GoodsMap::from_fn(|good| good.info().name.to_owned()),
+            good_keys: GoodsMap::from_fn(|good| good.info().key),
+            // home port should be "boring"
+            home_port_event_weights: EventWeights {
+                no_event: 6,
+                cheap_good: 1,
+                expensive_good: 1,
+                find_goods: 0,
+                stolen_goods: 1,
+                can_buy_cannon: 1,
+                pirate_encounter: 1,
+                can_buy_more_hold_space: 0,
+                no_effect: 3,
+                customs_inspection: 0,
+            },
+            port_event_weights: EventWeights {
+                no_event: 6,
+                cheap_good: 1,
+                expensive_good: 1,
+                find_goods: 1,
+                stolen_goods: 1,
+                can_buy_cannon: 1,
+                pirate_encounter: 1,
+                can_buy_more_hold_space: 1,
+                no_effect: 1,
+                customs_inspection: 1,
+            },
+            min_pirates_encountered: 2,
+            max_pirates_encountered: 4,
+            gold_per_pirate_victory_min: 500,
+            gold_per_pirate_victory_max: 2000,
+            pirate_bribe_gold: 600,
+            cargo_bribe_share: 0.25,
+            dump_cargo_share: 0.5,
+            named_captain_chance: 0.6,
+            bounty_per_infamy: 250,
+            market_impact: 0.002,
+            market_recovery: 0.3,
+            loan_interest_rate_min: 0.05,
+            loan_interest_rate_max: 0.2,
+            loan_cap_multiplier_min: 2,
+            loan_cap_multiplier_max: 10,
+            loan_term_months_min: 6,
+            loan_term_months_max: 12,
+            bank_interest_rate: 0.02,
+            bank_run_chance: 0.02,
+            bank_run_max_loss: 0.5,
+            bank_run_freeze_months: 3,
+            warehouse_rent: 100,
+            warehouse_theft_chance: 0.05,
+            warehouse_spoilage_chance: 0.05,
+            good_aging: GoodsMap::from([
+                (Good::Tea, -0.05),
+                (Good::Coffee, -0.03),
+                (Good::Rum, 0.02),
+            ]),
+            min_quality: 0.25,
+            max_quality: 1.5,
+            contracts_offered: 3,
+            contract_amount_min: 20,
+            contract_amount_max: 60,
+            contract_months_min: 3,
+            contract_months_max: 12,
+            contract_premium_min: 1.2,
+            contract_premium_max: 1.8,
+            contract_penalty: 0.25,
+            max_active_contracts: 2,
+            duty_rate_min: 0.05,
+            duty_rate_max: 0.2,
+            contraband_chance: 0.5,
+            customs_fine: 0.5,
+            customs_bribe: 0.3,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum RulesError {
+    Io(io::Error),
+    Parse(String),
+    Invalid(Vec<String>),
+}
+
+impl From<io::Error> for RulesError {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl Display for RulesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RulesError::Io(e) => write!(f, "unable to read rules: {}", e),
+            RulesError::Parse(e) => write!(f, "unable to parse rules: {}", e),
+            RulesError::Invalid(problems) => {
+                write!(f, "invalid rules:")?;
+                for problem in problems {
+                    write!(f, "\n  - {}", problem)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Rules {
+    /// reads and validates rules from a `.toml` or `.json` file
+    pub fn from_file(path: &Path) -> Result<Rules, RulesError> {
+        let contents = fs::read_to_string(path)?;
+        let rules: Rules = match path.extension().and_then(|e| e.to_str()) {
+            Some("toml") => toml::from_str(&contents).map_err(|e| RulesError::Parse(e.to_string())),
+            Some("json") => {
+                serde_json::from_str(&contents).map_err(|e| RulesError::Parse(e.to_string()))
+            }
+            _ => Err(RulesError::Parse(format!(
+                "{} should end in .toml or .json",
+                path.display()
+            ))),
+        }?;
+        rules.validate()?;
+        Ok(rules)
+    }
+
+    pub fn to_toml(&self) -> String {
+        toml::to_string(self).expect("Rules are always representable as TOML")
+    }
+
+    /// the widest range possible for the prices of each good.
+    /// locations will have ranges within these
+    pub fn overall_price_ranges(&self) -> PriceRanges {
+        PriceRanges::from_tiers_and_spreads(
+            self.lowest_price,
+            &self.price_tiers,
+            &self.price_spreads,
+        )
+    }
+
+    /// the quality of a good after it's been held for the given number of months
+    pub fn quality_after(&self, good: &Good, months: i32) -> f64 {
+        (1.0 + self.good_aging.get_good(good) * months.max(0) as f64)
+            .clamp(self.min_quality, self.max_quality)
+    }
+
+    /// checks that the rules make for a playable game, listing every problem found
+    pub fn validate(&self) -> Result<(), RulesError> {
+        let mut problems = vec![];
+        if self.game_length_years == 0 {
+            problems.push("game_length_years must be at least 1".to_owned());
+        }
+        if !(self.debt_interest_rate.is_finite() && self.debt_interest_rate >= 0.0) {
+            problems.push("debt_interest_rate must be zero or more".to_owned());
+        }
+        if !(self.market_impact.is_finite() && self.market_impact >= 0.0) {
+            problems.push("market_impact must be zero or more".to_owned());
+        }
+        if !(0.0..=1.0).contains(&self.market_recovery) {
+            problems.push("market_recovery must be between 0 and 1".to_owned());
+        }
+        let rates_valid = [self.loan_interest_rate_min, self.loan_interest_rate_max]
+            .iter()
+            .all(|rate| rate.is_finite() && *rate >= 0.0);
+        if !rates_valid || self.loan_interest_rate_min > self.loan_interest_rate_max {
+            problems.push(
+                "loan_interest_rate_min must be zero or more, and not more than loan_interest_rate_max"
+                    .to_owned(),
+            );
+        }
+        if self.loan_cap_multiplier_min > self.loan_cap_multiplier_max {
+            problems.push(
+                "loan_cap_multiplier_min must not be more than loan_cap_multiplier_max".to_owned(),
+            );
+        }
+        if self.loan_term_months_min == 0 || self.loan_term_months_min > self.loan_term_months_max {
+            problems.push(
+                "loan_term_months_min must be at least 1, and not more than loan_term_months_max"
+                    .to_owned(),
+            );
+        }
+        if !(self.bank_interest_rate.is_finite() && self.bank_interest_rate >= 0.0) {
+            problems.push("bank_interest_rate must be zero or more".to_owned());
+        }
+        for (name, value) in [
+            ("bank_run_chance", self.bank_run_chance),
+            ("bank_run_max_loss", self.bank_run_max_loss),
+            ("warehouse_theft_chance", self.warehouse_theft_chance),
+            ("warehouse_spoilage_chance", self.warehouse_spoilage_chance),
+            ("contract_penalty", self.contract_penalty),
+            ("contraband_chance", self.contraband_chance),
+            ("cargo_bribe_share", self.cargo_bribe_share),
+            ("dump_cargo_share", self.dump_cargo_share),
+            ("mutiny_loss", self.mutiny_loss),
+            ("named_captain_chance", self.named_captain_chance),
+        ] {
+            if !(0.0..=1.0).contains(&value) {
+                problems.push(format!("{} must be between 0 and 1", name));
+            }
+        }
+        if !Good::variants_iter().all(|good| self.good_aging.get_good(good).is_finite()) {
+            problems.push("good_aging must be a number for every good".to_owned());
+        }
+        if !(0.0..=1.0).contains(&self.min_quality) {
+            problems.push("min_quality must be between 0 and 1".to_owned());
+        }
+        if !(self.max_quality.is_finite() && self.max_quality >= 1.0) {
+            problems.push("max_quality must be at least 1".to_owned());
+        }
+        if self.contract_amount_min == 0 || self.contract_amount_min > self.contract_amount_max {
+            problems.push(
+                "contract_amount_min must be at least 1, and not more than contract_amount_max"
+                    .to_owned(),
+            );
+        }
+        if self.contract_months_min == 0 || self.contract_months_min > self.contract_months_max {
+            problems.push(
+                "contract_months_min must be at least 1, and not more than contract_months_max"
+                    .to_owned(),
+            );
+        }
+        let premiums_valid = [self.contract_premium_min, self.contract_premium_max]
+            .iter()
+            .all(|premium| premium.is_finite() && *premium >= 0.0);
+        if !premiums_valid || self.contract_premium_min > self.contract_premium_max {
+            problems.push(
+                "contract_premium_min must be zero or more, and not more than contract_premium_max"
+                    .to_owned(),
+            );
+        }
+        let duty_rates_valid = [self.duty_rate_min, self.duty_rate_max]
+            .iter()
+            .all(|rate| (0.0..=1.0).contains(rate));
+        if !duty_rates_valid || self.duty_rate_min > self.duty_rate_max {
+            problems.push(
+                "duty_rate_min and duty_rate_max must be between 0 and 1, with the min not more than the max"
+                    .to_owned(),
+            );
+        }
+        for (name, value) in [
+            ("customs_fine", self.customs_fine),
+            ("customs_bribe", self.customs_bribe),
+        ] {
+            if !(value.is_finite() && value >= 0.0) {
+                problems.push(format!("{} must be zero or more", name));
+            }
+        }
+        if self.starting_cannons > ShipClass::default().info().max_cannons {
+            problems.push(format!(
+                "starting_cannons must be at most {}, all a {} carries",
+                ShipClass::default().info().max_cannons,
+                ShipClass::default()
+            ));
+        }
+        if self.starting_crew > ShipClass::default().info().berths {
+            problems.push(format!(
+                "starting_crew must be at most {}, all a {} has berths for",
+                ShipClass::default().info().berths,
+                ShipClass::default()
+            ));
+        }
+        if self.starting_morale > 100 {
+            problems.push("starting_morale must be at most 100".to_owned());
+        }
+        if self.crew_per_cannon == 0 {
+            problems.push("crew_per_cannon must be at least 1".to_owned());
+        }
+        if self.mutiny_morale > self.desertion_morale || self.desertion_morale > 100 {
+            problems.push(
+                "mutiny_morale must not be more than desertion_morale, which must be at most 100"
+                    .to_owned(),
+            );
+        }
+        if SHIP_CLASSES
+            .iter()
+            .any(|info| self.slow_hull_health >= info.hull)
+        {
+            problems.push("slow_hull_health must be less than the hull of every ship".to_owned());
+        }
+        if self.min_pirates_encountered == 0 {
+            problems.push("min_pirates_encountered must be at least 1".to_owned());
+        }
+        if self.min_pirates_encountered > self.max_pirates_encountered {
+            problems.push(
+                "min_pirates_encountered must not be more than max_pirates_encountered".to_owned(),
+            );
+        }
+        if self.gold_per_pirate_victory_min >= self.gold_per_pirate_victory_max {
+            problems.push(
+                "gold_per_pirate_victory_min must be less than gold_per_pirate_victory_max"
+                    .to_owned(),
+            );
+        }
+        for (name, weights) in [
+            ("home_port_event_weights", &self.home_port_event_weights),
+            ("port_event_weights", &self.port_event_weights),
+        ] {
+            if weights.weights().iter().all(|weight| *weight == 0) {
+                problems.push(format!("{} must have at least one non-zero weight", name));
+            }
+        }
+        for (good, name) in self.good_names.iter() {
+            let fits = (1..=Good::MAX_NAME_LENGTH).contains(&name.len())
+                && name.chars().all(|c| c.is_ascii_alphabetic() || c == ' ');
+            if !fits {
+                problems.push(format!(
+                    "the name for {} must be 1 to {} letters or spaces",
+                    good.info().name,
+                    Good::MAX_NAME_LENGTH
+                ));
+            }
+            let key = *self.good_keys.get_good(&good);
+            if !key.is_ascii_lowercase() || key == 'b' || !name.to_ascii_lowercase().contains(key) {
+                problems.push(format!(
+                    "the key for {} must be a lowercase letter in its name other than b",
+                    good.info().name
+                ));
+            }
+        }
+        let names_unique = self.good_names.iter().all(|(good, name)| {
+            self.good_names
+                .iter()
+                .all(|(other, other_name)| other == good || !other_name.eq_ignore_ascii_case(name))
+        });
+        if !names_unique {
+            problems.push("good_names must all be different".to_owned());
+        }
+        let keys_unique = self.good_keys.iter().all(|(good, key)| {
+            self.good_keys
+                .iter()
+                .all(|(other, other_key)| other == good || other_key != key)
+        });
+        if !keys_unique {
+            problems.push("good_keys must all be different".to_owned());
+        }
+        let spreads_valid = self
+            .price_spreads
+            .iter()
+            .chain(self.price_tiers.iter())
+            .all(|(_, x)| x.is_finite() && *x > 0.0);
+        if !spreads_valid {
+            problems
+                .push("price_tiers and price_spreads must be above zero for every good".to_owned());
+        } else if self.lowest_price < 4 {
+            // cheap good events need room below the lowest price
+            problems.push("lowest_price must be at least 4".to_owned());
+        } else {
+            // the narrowest ranges are at the home port
+            let home_port_price_ranges = self.overall_price_ranges().generate_subsection(&[], &[]);
+            for good in Good::variants_iter() {
+                let (low, high) = *home_port_price_ranges.get_good(good);
+                if low >= high {
+                    problems.push(format!(
+                        "the price range for {} is too narrow ({}-{}), increase lowest_price or price_spreads",
+                        good, low, high
+                    ));
+                }
+                // prices are shown with at most 4 digits
+                let (_, overall_high) = *self.overall_price_ranges().get_good(good);
+                if overall_high >= 9999 {
+                    problems.push(format!(
+                        "the highest price for {} is too high ({}), decrease lowest_price, price_tiers or price_spreads",
+                        good, overall_high
+                    ));
+                }
+            }
+        }
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(RulesError::Invalid(problems))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn defaults_are_valid() {
+        assert!(Rules::default().validate().is_ok());
+        // the price scale used to be derived from the starting gold
+        let rules = Rules::default();
+        assert_eq!(rules.lowest_price, rules.starting_gold / 100);
+        // the debt used to grow by a factor of exactly 1.1
+        assert_eq!(1.0 + rules.debt_interest_rate, 1.1);
+    }
+
+    #[test]
+    fn partial_toml_keeps_defaults() -> Result<(), RulesError> {
+        let path = temp_dir().join("merchant_rules_partial.toml");
+        fs::write(&path, "starting_gold = 1000\ncannon_cost = 4000\n")?;
+        let rules = Rules::from_file(&path);
+        fs::remove_file(&path)?;
+        assert_eq!(
+            rules?,
+            Rules {
+                starting_gold: 1000,
+                cannon_cost: 4000,
+                ..Rules::default()
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip_toml_and_json() -> Result<(), RulesError> {
+        let rules = Rules {
+            repair_cost: 300,
+            ..Rules::default()
+        };
+        let toml_path = temp_dir().join("merchant_rules_round_trip.toml");
+        fs::write(&toml_path, rules.to_toml())?;
+        let from_toml = Rules::from_file(&toml_path);
+        fs::remove_file(&toml_path)?;
+        assert_eq!(from_toml?, rules);
+        let json_path = temp_dir().join("merchant_rules_round_trip.json");
+        fs::write(&json_path, serde_json::to_string(&rules).unwrap())?;
+        let from_json = Rules::from_file(&json_path);
+        fs::remove_file(&json_path)?;
+        assert_eq!(from_json?, rules);
+        Ok(())
+    }
+
+    #[test]
+    fn readable_errors() -> io::Result<()> {
+        let path = temp_dir().join("merchant_rules_typo.toml");
+        fs::write(&path, "starting_gould = 1000\n")?;
+        let error = Rules::from_file(&path).unwrap_err();
+        fs::remove_file(&path)?;
+        assert!(error.to_string().contains("unknown field `starting_gould`"));
+        let rules = Rules {
+            game_length_years: 0,
+            lowest_price: 1,
+            gold_per_pirate_victory_min: 3000,
+            ..Rules::default()
+        };
+        assert_eq!(
+            rules.validate().unwrap_err().to_string(),
+            r"invalid rules:
+  - game_length_years must be at least 1
+  - gold_per_pirate_victory_min must be less than gold_per_pirate_victory_max
+  - lowest_price must be at least 4"
+        );
+        let rules = Rules {
+            lowest_price: 10,
+            ..Rules::default()
+        };
+        assert_eq!(
+            rules.validate().unwrap_err().to_string(),
+            r"invalid rules:
+  - the highest price for Tea is too high (14885), decrease lowest_price, price_tiers or price_spreads
+  - the highest price for Silk is too high (11924), decrease lowest_price, price_tiers or price_spreads
+  - the highest price for Porcelain is too high (17231), decrease lowest_price, price_tiers or price_spreads"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn goods_renamed() -> Result<(), RulesError> {
+        let mut rules = Rules::default();
+        rules.good_names[Good::Rum] = "Grog".to_owned();
+        rules.good_keys[Good::Rum] = 'g';
+        assert!(rules.validate().is_ok());
+        // goods are still written out under their usual names
+        let toml = rules.to_toml();
+        assert!(toml.contains("rum = \"Grog\""));
+        assert!(toml.contains("rum = \"g\""));
+        let path = temp_dir().join("merchant_rules_goods_renamed.toml");
+        fs::write(&path, toml)?;
+        let from_toml = Rules::from_file(&path);
+        fs::remove_file(&path)?;
+        assert_eq!(from_toml?, rules);
+        let mut rules = Rules::default();
+        rules.good_names[Good::Rum] = "Rum of Jamaica".to_owned();
+        rules.good_names[Good::Sugar] = "tea".to_owned();
+        rules.good_keys[Good::Sugar] = 't';
+        rules.good_keys[Good::Cotton] = 'b';
+        assert_eq!(
+            rules.validate().unwrap_err().to_string(),
+            r"invalid rules:
+  - the name for Rum must be 1 to 9 letters or spaces
+  - the key for Cotton must be a lowercase letter in its name other than b
+  - good_names must all be different
+  - good_keys must all be different"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+use std::fmt::{self, Display};
+
+use serde::{Deserialize, Serialize};
+
+/// The kinds of ship there are to sail, in the order of [SHIP_CLASSES].
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+pub enum ShipClass {
+    /// the ship every player starts with
+    #[default]
+    Sloop,
+    Brig,
+    Frigate,
+}
+
+/// What there is to know about a class of ship: what it's called, the key that picks it
+/// at the shipyard and how it sails.
+#[derive(Debug)]
+pub struct ShipClassInfo {
+    pub class: ShipClass,
+    pub name: &'static str,
+    pub key: char,
+    /// what the shipyard asks for a new one, half of which it gives when trading one in
+    pub price: u32,
+    /// how many goods the hold carries, before any hold space bought along the way
+    pub hold: u32,
+    pub max_cannons: u8,
+    /// how many sailors the ship has room for
+    pub berths: u32,
+    /// how much damage the hull can take before the ship is lost
+    pub hull: u8,
+    /// how quickly the ship sails, eg. 1.25 covers a quarter more sea in a month than a sloop
+    pub speed: f64,
+    /// the gold paid to keep the ship for each month at sea
+    pub upkeep: u32,
+}
+
+/// Every class of ship there is, from smallest to largest.
+pub const SHIP_CLASSES: &[ShipClassInfo] = &[
+    ShipClassInfo {
+        class: ShipClass::Sloop,
+        name: "Sloop",
+        key: 's',
+        price: 1000,
+        hold: 100,
+        max_cannons: 5,
+        berths: 12,
+        hull: 5,
+        speed: 1.0,
+        upkeep: 0,
+    },
+    // slow, but carries the most
+    ShipClassInfo {
+        class: ShipClass::Brig,
+        name: "Brig",
+        key: 'r',
+        price: 8000,
+        hold: 250,
+        max_cannons: 8,
+        berths: 20,
+        hull: 8,
+        speed: 0.8,
+        upkeep: 40,
+    },
+    // a warship, quick and heavily armed
+    ShipClassInfo {
+        class: ShipClass::Frigate,
+        name: "Frigate",
+        key: 'f',
+        price: 24000,
+        hold: 200,
+        max_cannons: 16,
+        berths: 40,
+        hull: 12,
+        speed: 1.25,
+        upkeep: 120,
+    },
+];
+
+impl Display for ShipClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.info().name)
+    }
+}
+
+impl ShipClass {
+    pub fn info(&self) -> &'static ShipClassInfo {
+        &SHIP_CLASSES[*self as usize]
+    }
+
+    pub fn variants_iter() -> impl Iterator<Item = ShipClass> {
+        SHIP_CLASSES.iter().map(|info| info.class)
+    }
+
+    /// how fast the slowest class of ship sails
+    pub fn slowest_speed() -> f64 {
+        SHIP_CLASSES
+            .iter()
+            .map(|info| info.speed)
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    /// the class picked by a key at the shipyard
+    pub fn from_key(c: char) -> Option<ShipClass> {
+        Self::variants_iter().find(|class| class.info().key == c)
+    }
+
+    /// what the shipyard gives for a ship of this class with the given damage to its hull,
+    /// less what it'll cost them to mend it
+    pub fn trade_in_value(&self, hull_damage: u8, repair_cost: u32) -> u32 {
+        (self.info().price / 2).saturating_sub(u32::from(hull_damage) * repair_cost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn classes() {
+        for (index, info) in SHIP_CLASSES.iter().enumerate() {
+            // classes are looked up by their position in the list
+            assert_eq!(info.class as usize, index);
+            // the key is a letter of the name, and 'b' is always back
+            assert!(info.name.to_ascii_lowercase().contains(info.key));
+            assert_ne!(info.key, 'b');
+            assert_eq!(ShipClass::from_key(info.key), Some(info.class));
+        }
+        assert_eq!(ShipClass::default().info().upkeep, 0);
+        assert_eq!(ShipClass::slowest_speed(), 0.8);
+        assert_eq!(ShipClass::Brig.trade_in_value(0, 200), 4000);
+        assert_eq!(ShipClass::Brig.trade_in_value(3, 200), 3400);
+        assert_eq!(ShipClass::Sloop.trade_in_value(5, 200), 0);
+    }
+}
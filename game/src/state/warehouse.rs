@@ -0,0 +1,103 @@
+use std::fmt::{self, Display};
+
+use serde::{Deserialize, Serialize};
+
+use super::{CostBasis, Good, Inventory, Location, LocationMap, Lots};
+
+/// A warehouse rented in a port away from home, holding goods the way the home stash does.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Warehouse {
+    pub goods: Inventory,
+    /// what was paid for the goods in the warehouse
+    pub cost: CostBasis,
+    /// when the goods in the warehouse were acquired
+    #[serde(default)]
+    pub lots: Lots,
+}
+
+/// the warehouse rented in each port, if any
+pub type Warehouses = LocationMap<Option<Warehouse>>;
+
+impl Warehouses {
+    /// the ports a warehouse is rented in
+    pub fn rented(&self) -> Vec<Location> {
+        Location::variants()
+            .iter()
+            .filter(|location| self.get(location).is_some())
+            .copied()
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum WarehouseMishap {
+    Theft,
+    Spoilage,
+    /// the rent couldn't be paid, so the warehouse and everything in it was lost
+    Evicted,
+}
+
+/// goods lost from a warehouse while the player was away
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WarehouseLoss {
+    pub location: Location,
+    pub mishap: WarehouseMishap,
+    pub goods: Inventory,
+}
+
+impl Display for WarehouseLoss {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let goods = Good::variants_iter()
+            .filter(|good| *self.goods.get_good(good) > 0)
+            .map(|good| format!("{} {}", self.goods.get_good(good), good))
+            .collect::<Vec<String>>()
+            .join(", ");
+        match self.mishap {
+            WarehouseMishap::Theft => write!(f, "{}: thieves took {}", self.location, goods),
+            WarehouseMishap::Spoilage => write!(f, "{}: {} spoiled", self.location, goods),
+            WarehouseMishap::Evicted => {
+                write!(f, "{}: evicted for unpaid rent", self.location)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn describe_losses() {
+        let loss = |mishap| WarehouseLoss {
+            location: Location::CapeTown,
+            mishap,
+            goods: Inventory::from([(Good::Tea, 5), (Good::Rum, 10)]),
+        };
+        assert_eq!(
+            loss(WarehouseMishap::Theft).to_string(),
+            "Cape Town: thieves took 5 Tea, 10 Rum"
+        );
+        assert_eq!(
+            loss(WarehouseMishap::Spoilage).to_string(),
+            "Cape Town: 5 Tea, 10 Rum spoiled"
+        );
+        assert_eq!(
+            loss(WarehouseMishap::Evicted).to_string(),
+            "Cape Town: evicted for unpaid rent"
+        );
+    }
+
+    #[test]
+    fn rented() {
+        let mut warehouses = Warehouses::default();
+        assert_eq!(warehouses.rented(), vec![]);
+        warehouses[Location::Venice] = Some(Warehouse::default());
+        warehouses[Location::Lisbon] = Some(Warehouse::default());
+        assert_eq!(
+            warehouses.rented(),
+            vec![Location::Lisbon, Location::Venice]
+        );
+    }
+}
@@ -0,0 +1,121 @@
+use super::Location;
+
+/// The routes ships sail between ports, in either direction.
+/// A voyage between ports with no lane between them goes by way of others.
+/// Compiled in along with the [super::location::PORTS], so a new port needs its lanes added here.
+pub const SEA_LANES: &[(Location, Location)] = &[
+    (Location::London, Location::Amsterdam),
+    (Location::London, Location::Lisbon),
+    (Location::London, Location::Savannah),
+    (Location::Lisbon, Location::Venice),
+    (Location::Lisbon, Location::Kingston),
+    (Location::Lisbon, Location::Elmina),
+    (Location::Savannah, Location::Havana),
+    (Location::Savannah, Location::Kingston),
+    (Location::Havana, Location::Kingston),
+    (Location::Kingston, Location::Elmina),
+    (Location::Elmina, Location::Luanda),
+    (Location::Luanda, Location::CapeTown),
+    (Location::CapeTown, Location::Batavia),
+    (Location::Batavia, Location::Malacca),
+];
+
+/// how many degrees of sea a ship covers in a month
+const DEGREES_PER_MONTH: f64 = 45.0;
+
+/// how far apart two ports are as the crow flies, in degrees
+fn distance(from: &Location, to: &Location) -> f64 {
+    let (x1, y1) = from.info().position;
+    let (x2, y2) = to.info().position;
+    (x2 - x1).hypot(y2 - y1)
+}
+
+/// the ports a ship can sail to directly from the given one
+fn neighbours(location: &Location) -> impl Iterator<Item = Location> + '_ {
+    SEA_LANES.iter().filter_map(move |(a, b)| {
+        if a == location {
+            Some(*b)
+        } else if b == location {
+            Some(*a)
+        } else {
+            None
+        }
+    })
+}
+
+/// how far a ship sails between two ports along the shortest way through the sea lanes
+pub fn route_distance(from: &Location, to: &Location) -> f64 {
+    // the shortest distance found so far to each port, and whether it's final
+    let mut distances = [f64::INFINITY; Location::COUNT];
+    let mut done = [false; Location::COUNT];
+    distances[from.index()] = 0.0;
+    while let Some(current) = Location::variants_iter()
+        .filter(|location| !done[location.index()] && distances[location.index()].is_finite())
+        .min_by(|a, b| distances[a.index()].total_cmp(&distances[b.index()]))
+    {
+        if current == to {
+            break;
+        }
+        done[current.index()] = true;
+        for next in neighbours(current) {
+            let through_current = distances[current.index()] + distance(current, &next);
+            if through_current < distances[next.index()] {
+                distances[next.index()] = through_current;
+            }
+        }
+    }
+    distances[to.index()]
+}
+
+/// how many months it takes a ship of the given speed to sail between two ports, at least one
+pub fn travel_months_at(from: &Location, to: &Location, speed: f64) -> u16 {
+    (route_distance(from, to) / (DEGREES_PER_MONTH * speed))
+        .ceil()
+        .max(1.0) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::state::ShipClass;
+
+    #[test]
+    fn every_port_can_be_reached() {
+        for from in Location::variants_iter() {
+            for to in Location::variants_iter() {
+                let distance = route_distance(from, to);
+                assert!(distance.is_finite(), "{} to {}", from, to);
+                // the same way back, give or take the order the legs were added up in
+                assert!((distance - route_distance(to, from)).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn travel_times() {
+        let travel_months = |from, to| travel_months_at(from, to, ShipClass::Sloop.info().speed);
+        assert_eq!(travel_months(&Location::London, &Location::Amsterdam), 1);
+        assert_eq!(travel_months(&Location::London, &Location::Venice), 1);
+        assert_eq!(travel_months(&Location::London, &Location::Savannah), 2);
+        assert_eq!(travel_months(&Location::London, &Location::Kingston), 2);
+        assert_eq!(travel_months(&Location::London, &Location::CapeTown), 3);
+        assert_eq!(travel_months(&Location::London, &Location::Batavia), 5);
+        assert_eq!(travel_months(&Location::Savannah, &Location::Havana), 1);
+        assert_eq!(travel_months(&Location::Venice, &Location::Malacca), 5);
+        // quicker ships cover more sea in a month, but every voyage takes at least one
+        assert_eq!(
+            travel_months_at(&Location::London, &Location::Batavia, 1.25),
+            4
+        );
+        assert_eq!(
+            travel_months_at(&Location::London, &Location::Batavia, 0.8),
+            6
+        );
+        assert_eq!(
+            travel_months_at(&Location::London, &Location::Venice, 1.25),
+            1
+        );
+    }
+}
@@ -1,11 +1,16 @@
 use std::num::Saturating;
 
+use chrono::Month;
+use crossterm::event::KeyCode;
+
 use pretty_assertions::assert_eq;
 
 use crate::{
     engine::{UpdateResult, UpdateSignal},
     state::{
-        GameState, Good, LocationEvent, Mode, NoEffectEvent, PirateEncounterInfo, Transaction,
+        ContractOffer, Customs, Duty, GameState, Good, Inventory, Loan, Location, LocationEvent,
+        LocationInfo, Mode, NoEffectEvent, PirateCaptain, PirateEncounterInfo, PirateRecord,
+        Transaction,
     },
     test::{
         rng::{default_location_info, MockRng},
@@ -45,14 +50,14 @@ fn splash_screen_into_inventory() -> UpdateResult<()> {
 |                                                                                                 |
 |                                                                                                 |
 |                                                                                                 |
+|                                         (s) High scores                                         |
 |                                                                                                 |
-|                                                                                                 |
-|                                      Press any key to begin                                     |
+|                                   Press any other key to begin                                  |
 |                                                                                                 |
 |                                                                                                 |
 |                                                                                                 |
 |                                    ctrl-c to quit at any time                                   |
-|                                                                                                 |
+|                              ctrl-s to save and quit during a game                              |
 |                                                                                                 |
 ---------------------------------------------------------------------------------------------------
 ",
@@ -90,7 +95,7 @@ fn splash_screen_into_inventory() -> UpdateResult<()> {
 |                             Fair winds and following seas, captain.                             |
 )                                                                                                 (
 |                                                                                                 |
-(                                    Press any key to continue                                    )
+(                        Choose your difficulty: (e)asy, (n)ormal or (h)ard                       )
 |                                                                                                 |
 )                                                                                                 (
 |                                                                                                 |
@@ -99,7 +104,10 @@ fn splash_screen_into_inventory() -> UpdateResult<()> {
 "###,
         )
     );
+    // keys other than a difficulty are ignored
     e.charpress('a')?;
+    assert!(e.expect("Choose your difficulty"));
+    e.charpress('n')?;
     assert_eq!(
         e.get_current_formatted(),
         e.expect_full(
@@ -107,34 +115,34 @@ fn splash_screen_into_inventory() -> UpdateResult<()> {
 '~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~|=================|~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.
 )                                       | March      1782 |                                       (
 |                                       |=================|                                       |
-(     _____[LLL]______[LLL]____                                     |                             )
-|    /     [LLL]      [LLL]    \                        |          )_)                            |
-)   /___________________________\                      )_)        )___)         |                 (
-|    )=========================(                      )___)       )____)       )_)\               |
-(    '|I .--. I     Tea:    0 I|                      )____)     /)_____)      )__)\              )
-|     |I | +| I  Coffee:    0 I|                     )_____)    /)______)\    )___) \             |
-)     |I_|_+|_I   Sugar:    0 I|                    )______)  //)_______) \\ )_____) \\           (
-|    /_I______I Tobacco:    0 I_\             _____//___|___///_____|______\\\__|_____\\\__=====  |
-(     )========     Rum:    0 =(              \      Tea:    0 Coffee:    0  Sugar:    0  /       )
-|     |I .--. I  Cotton:    0 I|               \ Tobacco:    0    Rum:    0 Cotton:    0 /        |
-)     |I |<>| I               I|                \                                       /____     (
-|     |I |~ | I Bank:       0 I|       --------- \ Gold:     500 Hold:  100 Cannons: 1 //.../---  |
-(     |I |  | I Debt:    1500 I|          ^^^^^ ^^^^^^^^^^^^^^^^^^^^^   ^^^^^^^^^^  ^^^/.../      )
-|     |I_|__|_I_______________I|                ^^^^      ^^^    ^^^^^^^^^    ^^^^^  /..../       |
-)   ###(______)##################                        ^^^      ^^^^             /...../        (
-|    ##(________)   ~"^"^~   ##                                                  /....../         |
+(     _____[LLL]______[LLL]______                                   |                             )
+|    /     [LLL]      [LLL]      \                                 )_)\                           |
+)   /_____________________________\                               )___) \                         (
+|    )===========================(                                )____)  \                       |
+(    '|I .--. I                 I|                               /)_____)   \                     )
+|     |I | +| I                 I|                              /)______)\    \                   |
+)     |I_|_+|_I                 I|                            //)_______) \\    \\                (
+|    /_I______I                 I_\           ______________///_____|______\\\_____\\\_____=====  |
+(     )========                 =(            \                                           /       )
+|     |I .--. I                 I|             \                                         /        |
+)     |I |<>| I                 I|              \                                       /____     (
+|     |I |~ | I  Bank:       0  I|     --------- \ Gold:     500 Hold:  100 Cannons: 1 //.../---  |
+(     |I |  | I  Debt:    1500  I|        ^^^^^ ^^ Hull: 5/5 ^^^^^^^^   ^^^^^^^^^^  ^^^/.../      )
+|     |I_|__|_I_________________I|              ^^ Crew: 12, content ^^^^^    ^^^^^  /..../       |
+)   ###(______)####################                      ^^^      ^^^^             /...../        (
+|    ##(________)   ~"^"^~     ##                                                /....../         |
 ('~~.~~(_________)~'~~.~~'~~.~~'~~.~~'~~.~<------------->~.~~'~~.~~'~~.~~'~~.~~/......../~~'~~.~~')
 |      (__________)                       |   London    |                    /........./          |
 )                                         <------------->                                         (
 |                                                                                                 |
-(         (1) Buy                                    Captain, the prices of goods here are:       )
-|         (2) Sell                                                  Tea:    6                     |
-)         (3) Sail                                               Coffee:    5                     (
-|         (4) Stash deposit                                       Sugar:    4                     |
-(         (5) Stash withdraw                                    Tobacco:    3                     )
-|         (6) Bank deposit                                          Rum:    2                     |
-)         (7) Bank withdraw                                      Cotton:    1                     (
-|         (8) Pay down debt                                                                       |
+(         (1) Buy               (9) Captain's log   Captain, the prices of goods here are:        )
+|         (2) Sell              (0) Price history         Tea:    6           Cotton:    1        |
+)         (3) Sail              (r) Market rumors      Coffee:    5           Spices:    5        (
+|         (4) Stash deposit     (o) Borrow              Sugar:    4             Silk:    6        |
+(         (5) Stash withdraw    (c) Contracts         Tobacco:    3           Indigo:    2        )
+|         (6) Bank deposit                                Rum:    2        Porcelain:    7        |
+)         (7) Bank withdraw                                                                       (
+|         (8) Pay down debt     (n) Sign on crew                                                  |
 (                                                                                                 )
 .~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'
 "###,
@@ -143,13 +151,55 @@ fn splash_screen_into_inventory() -> UpdateResult<()> {
     Ok(())
 }
 
+#[test]
+fn splash_screen_continue_saved_game() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state_with_saved_game(
+        GameState::new(MockRng::new_with_default_locations().into()),
+        true,
+    )?;
+    assert!(e.expect("(c) Continue saved game"));
+    assert!(e.expect("Press any other key to begin a new game"));
+    assert_eq!(e.charpress('c')?, UpdateSignal::LoadSavedGame);
+    Ok(())
+}
+
+#[test]
+fn splash_screen_no_saved_game() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        GameState::new(MockRng::new_with_default_locations().into())
+    })?;
+    assert!(e.nexpect("(c) Continue saved game"));
+    assert_eq!(e.charpress('s')?, UpdateSignal::ShowHighScores);
+    assert!(e.expect("(s) High scores"));
+    assert_eq!(e.charpress('c')?, UpdateSignal::Continue);
+    assert!(e.expect("The year is 1782."));
+    Ok(())
+}
+
+#[test]
+fn save_and_quit() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        GameState::new(MockRng::new_with_default_locations().into())
+    })?;
+    // nothing to save before the game has begun
+    assert_eq!(e.ctrlpress('s')?, UpdateSignal::Continue);
+    assert!(e.expect("Choose your difficulty"));
+    assert_eq!(e.ctrlpress('s')?, UpdateSignal::Continue);
+    assert!(e.expect("Choose your difficulty"));
+    e.charpress('n')?;
+    assert!(e.expect("(1) Buy"));
+    assert_eq!(e.ctrlpress('s')?, UpdateSignal::SaveAndQuit);
+    assert_eq!(e.ctrlpress('c')?, UpdateSignal::Quit);
+    Ok(())
+}
+
 #[test]
 fn end_game_positive() -> UpdateResult<()> {
     let e = TestEngine::from_game_state({
         let mut state = GameState::new(MockRng::new_with_default_locations().into());
         state.introduction_to_game();
         state.gold = Saturating(40000);
-        state.debt = Saturating(100);
+        state.loans[Location::London].balance = 100;
         state.game_end = true;
         state
     })?;
@@ -178,7 +228,7 @@ fn end_game_positive() -> UpdateResult<()> {
 |                                                                                                 |
 |                                            39900 gold                                           |
 |                                                                                                 |
-|                                                                                                 |
+|                                       on Normal difficulty                                      |
 |                                                                                                 |
 |                               Your father expected more from you.                               |
 |                                                                                                 |
@@ -186,7 +236,7 @@ fn end_game_positive() -> UpdateResult<()> {
 |                                                                                                 |
 |                                                                                                 |
 |                                                                                                 |
-|                                (q) to quit, (Enter) to play again                               |
+|                  (q) to quit, (Enter) to play again, (s) to see the high scores                 |
 |                                                                                                 |
 |                                                                                                 |
 ---------------------------------------------------------------------------------------------------
@@ -202,7 +252,7 @@ fn end_game_negative() -> UpdateResult<()> {
         let mut state = GameState::new(MockRng::new_with_default_locations().into());
         state.introduction_to_game();
         state.gold = Saturating(100);
-        state.debt = Saturating(40000);
+        state.loans[Location::London].balance = 40000;
         state.game_end = true;
         state
     })?;
@@ -231,7 +281,7 @@ fn end_game_negative() -> UpdateResult<()> {
 |                                                                                                 |
 |                                        39900 gold in debt                                       |
 |                                                                                                 |
-|                                                                                                 |
+|                                       on Normal difficulty                                      |
 |                                                                                                 |
 |                          Obviously, your father is disappointed in you.                         |
 |                                                                                                 |
@@ -239,7 +289,7 @@ fn end_game_negative() -> UpdateResult<()> {
 |                                                                                                 |
 |                                                                                                 |
 |                                                                                                 |
-|                                (q) to quit, (Enter) to play again                               |
+|                  (q) to quit, (Enter) to play again, (s) to see the high scores                 |
 |                                                                                                 |
 |                                                                                                 |
 ---------------------------------------------------------------------------------------------------
@@ -255,11 +305,12 @@ fn end_game_quit() -> UpdateResult<()> {
         let mut state = GameState::new(MockRng::new_with_default_locations().into());
         state.introduction_to_game();
         state.gold = Saturating(100);
-        state.debt = Saturating(40000);
+        state.loans[Location::London].balance = 40000;
         state.game_end = true;
         state
     })?;
     assert!(e.expect("(q) to quit"));
+    assert!(e.nexpect("A new high score!"));
     assert_eq!(
         e.charpress('a')?, // expect nothing
         UpdateSignal::Continue,
@@ -269,13 +320,30 @@ fn end_game_quit() -> UpdateResult<()> {
     Ok(())
 }
 
+#[test]
+fn end_game_new_high_score() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state_with_new_high_score(
+        {
+            let mut state = GameState::new(MockRng::new_with_default_locations().into());
+            state.introduction_to_game();
+            state.gold = Saturating(40000);
+            state.game_end = true;
+            state
+        },
+        2,
+    )?;
+    assert!(e.expect("A new high score! You placed #3."));
+    assert_eq!(e.charpress('s')?, UpdateSignal::ShowHighScores);
+    Ok(())
+}
+
 #[test]
 fn end_game_restart() -> UpdateResult<()> {
     let mut e = TestEngine::from_game_state({
         let mut state = GameState::new(MockRng::new_with_default_locations().into());
         state.introduction_to_game();
         state.gold = Saturating(100);
-        state.debt = Saturating(40000);
+        state.loans[Location::London].balance = 40000;
         state.game_end = true;
         state
     })?;
@@ -290,8 +358,8 @@ fn buy_good() -> UpdateResult<()> {
         let mut state = GameState::new(MockRng::new_with_default_locations().into());
         state.introduction_to_game();
         state.gold = Saturating(1400);
-        state.inventory.cotton = 15;
-        state.locations.london.prices.cotton = 30;
+        state.inventory[Good::Cotton] = 15;
+        state.locations[Location::London].prices[Good::Cotton] = 30;
         state
     })?;
     assert!(e.expect("Gold:    1400"));
@@ -303,16 +371,123 @@ fn buy_good() -> UpdateResult<()> {
     assert!(e.expect("(6) Cotton"));
     e.charpress('6')?;
     assert!(e.expect("How much Cotton do you want?"));
-    assert!(e.expect("You can afford (46)"));
+    // each unit bought raises the price of the next
+    assert!(e.expect("You can afford (43)"));
     e.charpress('1')?;
     assert!(e.expect("How much Cotton do you want? 1"));
-    assert!(e.expect("You can afford (46)"));
+    assert!(e.expect("You can afford (43)"));
     e.charpress('0')?;
     assert!(e.expect("How much Cotton do you want? 10"));
-    assert!(e.expect("You can afford (46)"));
+    assert!(e.expect("You can afford (43)"));
     e.enterpress()?;
     assert!(e.expect("Cotton:   25"));
-    assert!(e.expect("Gold:    1100"));
+    // 31 each on average
+    assert!(e.expect("Gold:    1090"));
+    Ok(())
+}
+
+#[test]
+fn buy_good_beyond_the_number_keys() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        let mut state = GameState::new(MockRng::new_with_default_locations().into());
+        state.introduction_to_game();
+        for good in [Good::Tea, Good::Coffee, Good::Sugar, Good::Tobacco] {
+            state.inventory[good] = 3;
+        }
+        state
+    })?;
+    // only what's in the hold is listed once the catalog outgrows it
+    assert!(e.expect("Tobacco:    3"));
+    assert!(e.nexpect("Rum:    0"));
+    assert!(e.expect("Porcelain:    7"));
+    e.charpress('1')?;
+    assert!(e.expect("(0) Porcelain"));
+    // goods can be picked by their letter as well as their number
+    e.charpress('e')?;
+    assert!(e.expect("How much Porcelain do you want?"));
+    e.charpress('b')?;
+    e.charpress('0')?;
+    assert!(e.expect("How much Porcelain do you want?"));
+    e.charpress('2')?;
+    e.charpress('0')?;
+    e.enterpress()?;
+    assert!(e.expect("Gold:     360"));
+    assert!(e.expect("Tobacco:    3"));
+    assert!(e.expect("Porcelain:   20"));
+    Ok(())
+}
+
+#[test]
+fn trading_moves_prices() -> UpdateResult<()> {
+    let london = LocationInfo {
+        prices: Inventory::from([(Good::Cotton, 100)]),
+        ..default_location_info()
+    };
+    let mut e = TestEngine::from_game_state({
+        let mut state = GameState::new(
+            MockRng::new_with_default_locations()
+                .push_location_info(default_location_info())
+                .push_location_info(london.clone())
+                .push_market_pressure_remaining(0.5)
+                .into(),
+        );
+        state.introduction_to_game();
+        state.gold = Saturating(20000);
+        state.locations[Location::London] = london;
+        state
+    })?;
+    e.charpress('1')?;
+    e.charpress('6')?;
+    e.charpress('1')?;
+    e.charpress('0')?;
+    assert!(e.expect("Price here rises to 102"));
+    e.charpress('0')?;
+    assert!(e.expect("Price here rises to 122"));
+    e.enterpress()?;
+    // 111 each on average
+    assert!(e.expect("Gold:    8900"));
+    assert!(e.expect("Cotton:  122"));
+    // the price is still raised when coming back, but not by as much
+    e.charpress('3')?;
+    e.charpress('6')?;
+    assert!(e.expect("|   Venice    |"));
+    e.charpress('3')?;
+    e.charpress('1')?;
+    assert!(e.expect("|   London    |"));
+    assert!(e.expect("Cotton:  111"));
+    e.charpress('2')?;
+    e.charpress('6')?;
+    e.charpress('5')?;
+    e.charpress('0')?;
+    assert!(e.expect("price falls to 100"));
+    Ok(())
+}
+
+#[test]
+fn buying_and_selling_in_port_never_pays() -> UpdateResult<()> {
+    let mut state = GameState::new(MockRng::new_with_default_locations().into());
+    state.introduction_to_game();
+    state.hold_size = Saturating(10000);
+    for price in [1, 7, 30, 99, 250, 1234, 9000] {
+        for amount in [1, 2, 10, 45, 100, 999] {
+            state.locations[Location::London].prices[Good::Cotton] = price;
+            state.gold = Saturating(20_000_000);
+            let gold = state.gold;
+            for _ in 0..5 {
+                state.begin_buying()?.choose_buy_good(Good::Cotton)?;
+                for digit in amount.to_string().chars().filter_map(|c| c.to_digit(10)) {
+                    state.user_typed_digit(digit)?;
+                }
+                state.commit_buy()?;
+                state.begin_selling()?.choose_sell_good(Good::Cotton)?;
+                for digit in amount.to_string().chars().filter_map(|c| c.to_digit(10)) {
+                    state.user_typed_digit(digit)?;
+                }
+                state.commit_sell()?;
+            }
+            assert!(state.gold <= gold, "{} of cotton at {}", amount, price);
+        }
+    }
     Ok(())
 }
 
@@ -337,14 +512,59 @@ fn buy_good_back() -> UpdateResult<()> {
     Ok(())
 }
 
+#[test]
+fn captains_log() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        let mut state = GameState::new(MockRng::new_with_default_locations().into());
+        state.introduction_to_game();
+        state.gold = Saturating(1400);
+        state.locations[Location::London].prices[Good::Cotton] = 30;
+        state
+    })?;
+    e.charpress('l')?;
+    assert!(e.expect("Nothing has been recorded yet."));
+    e.charpress('b')?;
+    // buy and sell cotton a page's worth of times
+    for _ in 0..11 {
+        e.charpress('1')?;
+        e.charpress('6')?;
+        e.charpress('1')?;
+        e.enterpress()?;
+        e.charpress('2')?;
+        e.charpress('6')?;
+        e.charpress('1')?;
+        e.enterpress()?;
+    }
+    e.charpress('9')?;
+    assert!(e.expect("Captain's Log"));
+    assert!(e.expect(
+        "Mar 1782  London      Sold                Cotton        1     30        +30      1400"
+    ));
+    assert!(e.expect("Entries 3-22 of 22"));
+    e.keypress(KeyCode::Up)?;
+    assert!(e.expect("Entries 2-21 of 22"));
+    // can't scroll past the first entry
+    e.keypress(KeyCode::PageUp)?;
+    assert!(e.expect("Entries 1-20 of 22"));
+    assert!(e.expect(
+        "Mar 1782  London      Bought              Cotton        1     30        -30      1370"
+    ));
+    e.keypress(KeyCode::Down)?;
+    assert!(e.expect("Entries 2-21 of 22"));
+    assert_eq!(e.charpress('e')?, UpdateSignal::ExportLedger);
+    e.charpress('b')?;
+    assert!(e.expect("(1) Buy"));
+    Ok(())
+}
+
 #[test]
 fn sell_good() -> UpdateResult<()> {
     let mut e = TestEngine::from_game_state({
         let mut state = GameState::new(MockRng::new_with_default_locations().into());
         state.introduction_to_game();
         state.gold = Saturating(1400);
-        state.inventory.cotton = 15;
-        state.locations.london.prices.cotton = 30;
+        state.inventory[Good::Cotton] = 15;
+        state.locations[Location::London].prices[Good::Cotton] = 30;
         state
     })?;
     assert!(e.expect("Gold:    1400"));
@@ -366,7 +586,39 @@ fn sell_good() -> UpdateResult<()> {
     assert!(e.expect("want to sell? 10"));
     e.enterpress()?;
     assert!(e.expect("Cotton:    5"));
-    assert!(e.expect("Gold:    1700"));
+    // each unit sold lowers the price of the next, to 29 each on average
+    assert!(e.expect("Gold:    1690"));
+    Ok(())
+}
+
+#[test]
+fn profit_and_loss() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        let mut state = GameState::new(MockRng::new_with_default_locations().into());
+        state.introduction_to_game();
+        state.inventory[Good::Cotton] = 10;
+        state.inventory_cost.add(&Good::Cotton, 200);
+        state.locations[Location::London].prices[Good::Cotton] = 30;
+        state
+    })?;
+    assert!(e.expect("Cotton:   30    +90"));
+    assert!(e.expect("Profit if sold"));
+    e.charpress('2')?;
+    e.charpress('6')?;
+    assert!(e.expect("Bought for 20 each on average"));
+    assert!(e.nexpect("Profit:"));
+    e.charpress('4')?;
+    assert!(e.expect("Profit: +40"));
+    e.enterpress()?;
+    assert!(e.expect("Cotton:   30    +60"));
+    // the cost follows the goods into the stash
+    e.charpress('4')?;
+    e.charpress('6')?;
+    e.charpress('6')?;
+    e.enterpress()?;
+    assert!(e.nexpect("Profit if sold"));
+    assert_eq!(e.game_state().stash_cost.total(&Good::Cotton), 120);
+    assert_eq!(e.game_state().inventory_cost.total(&Good::Cotton), 0);
     Ok(())
 }
 
@@ -415,6 +667,103 @@ fn sail() -> UpdateResult<()> {
     Ok(())
 }
 
+#[test]
+fn price_history() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        let mut state = GameState::new(
+            MockRng::new_with_default_locations()
+                .push_location_info(default_location_info())
+                .into(),
+        );
+        state.introduction_to_game();
+        state
+    })?;
+    e.charpress('3')?;
+    e.charpress('6')?;
+    assert!(e.expect("|   Venice    |"));
+    e.charpress('0')?;
+    assert!(e.expect("Prices of Tea seen in each port"));
+    assert!(e.expect("Savannah    not visited yet"));
+    assert!(e.expect("last 6"));
+    assert!(e.expect("|1782"));
+    e.charpress('2')?;
+    assert!(e.expect("Prices of Coffee seen in each port"));
+    assert!(e.expect("last 5"));
+    e.charpress('b')?;
+    assert!(e.expect("(0) Price history"));
+    Ok(())
+}
+
+#[test]
+fn market_rumors() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        let mut state = GameState::new(
+            MockRng::new_with_default_locations()
+                .push_location_info(default_location_info())
+                .into(),
+        );
+        state.introduction_to_game();
+        state
+    })?;
+    e.charpress('r')?;
+    assert!(e.expect("London     here now"));
+    assert!(e.expect("Venice     never visited"));
+    e.charpress('b')?;
+    e.charpress('3')?;
+    e.charpress('6')?;
+    assert!(e.expect("|   Venice    |"));
+    e.charpress('r')?;
+    assert!(e.expect("London     1 month ago"));
+    assert!(e.expect("Venice     here now"));
+    assert!(e.expect("Lisbon     never visited"));
+    e.charpress('b')?;
+    assert!(e.expect("(r) Market rumors"));
+    Ok(())
+}
+
+#[test]
+fn sail_on_hard_difficulty() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        let mut state = GameState::new(
+            MockRng::new_with_default_locations()
+                .push_location_info(default_location_info())
+                .into(),
+        );
+        state.splash_to_introduction();
+        state
+    })?;
+    e.charpress('h')?;
+    assert!(e.expect("Gold:     250"));
+    assert!(e.expect("Debt:    3000"));
+    e.charpress('3')?;
+    e.charpress('6')?;
+    assert!(e.expect("|   Venice    |"));
+    // debt grows by 15% instead of 10%
+    assert!(e.expect("Debt:    3450"));
+    Ok(())
+}
+
+#[test]
+fn sail_into_end_of_shortened_game() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        let mut state = GameState::new(
+            MockRng::new_with_default_locations()
+                .push_location_info(default_location_info())
+                .into(),
+        );
+        state.introduction_to_game();
+        state.rules.game_length_years = 1;
+        state.date = (1783, Month::February);
+        state.seed = Some(42);
+        state.mode = Mode::Sailing;
+        state
+    })?;
+    e.charpress('6')?;
+    assert!(e.expect("After one year, you went from being"));
+    assert!(e.expect("This voyage was seed 42"));
+    Ok(())
+}
+
 #[test]
 fn sail_back() -> UpdateResult<()> {
     let mut e = TestEngine::from_game_state({
@@ -430,13 +779,35 @@ fn sail_back() -> UpdateResult<()> {
     Ok(())
 }
 
+#[test]
+fn sail_to_a_distant_port() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        let mut state = GameState::new(
+            MockRng::new_with_default_locations()
+                .push_location_info(default_location_info())
+                .into(),
+        );
+        state.introduction_to_game();
+        state.mode = Mode::Sailing;
+        state
+    })?;
+    assert!(e.expect("(5) Cape Town 3 mo"));
+    assert!(e.expect("(6) Venice    1 mo"));
+    e.charpress('c')?;
+    assert!(e.expect("| June       1782 |"));
+    assert!(e.expect("|  Cape Town  |"));
+    // the debt grew for each of the three months at sea
+    assert!(e.expect("Debt:    1996"));
+    Ok(())
+}
+
 #[test]
 fn stash_deposit() -> UpdateResult<()> {
     let mut e = TestEngine::from_game_state({
         let mut state = GameState::new(MockRng::new_with_default_locations().into());
         state.introduction_to_game();
-        state.stash.rum = 5;
-        state.inventory.rum = 20;
+        state.stash[Good::Rum] = 5;
+        state.inventory[Good::Rum] = 20;
         state
     })?;
     assert!(e.expect("Rum:    5"));
@@ -487,8 +858,8 @@ fn stash_withdraw() -> UpdateResult<()> {
     let mut e = TestEngine::from_game_state({
         let mut state = GameState::new(MockRng::new_with_default_locations().into());
         state.introduction_to_game();
-        state.stash.tea = 30;
-        state.inventory.tea = 14;
+        state.stash[Good::Tea] = 30;
+        state.inventory[Good::Tea] = 14;
         state
     })?;
     assert!(e.expect("Tea:   30"));
@@ -539,7 +910,7 @@ fn pay_debt() -> UpdateResult<()> {
     let mut e = TestEngine::from_game_state({
         let mut state = GameState::new(MockRng::new_with_default_locations().into());
         state.introduction_to_game();
-        state.debt = Saturating(500);
+        state.loans[Location::London].balance = 500;
         state.gold = Saturating(1000);
         state
     })?;
@@ -570,7 +941,7 @@ fn pay_debt_back() -> UpdateResult<()> {
     let mut e = TestEngine::from_game_state({
         let mut state = GameState::new(MockRng::new_with_default_locations().into());
         state.introduction_to_game();
-        state.debt = Saturating(500);
+        state.loans[Location::London].balance = 500;
         state.mode = Mode::PayDebt(None);
         state
     })?;
@@ -586,7 +957,7 @@ fn pay_debt_no_debt_left() -> UpdateResult<()> {
     let mut e = TestEngine::from_game_state({
         let mut state = GameState::new(MockRng::new_with_default_locations().into());
         state.introduction_to_game();
-        state.debt = Saturating(0);
+        state.loans[Location::London].balance = 0;
         state
     })?;
     assert!(e.expect("Debt:       0"));
@@ -599,6 +970,242 @@ fn pay_debt_no_debt_left() -> UpdateResult<()> {
     Ok(())
 }
 
+#[test]
+fn borrow_and_repay_abroad() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        let mut state = GameState::new(
+            MockRng::new_with_default_locations()
+                .push_location_info(default_location_info())
+                .into(),
+        );
+        state.introduction_to_game();
+        state
+    })?;
+    e.charpress('3')?;
+    e.charpress('6')?;
+    assert!(e.expect("|   Venice    |"));
+    assert!(e.expect("Debt:    1650"));
+    // what's owed at home can be paid down from here too
+    assert!(e.expect("Pay down debt"));
+    e.charpress('o')?;
+    assert!(e.expect("Up to 5000 at 10% a month,"));
+    assert!(e.expect("to be repaid within 12 months."));
+    // more than the lender will lend does nothing
+    for c in "6000".chars() {
+        e.charpress(c)?;
+    }
+    e.enterpress()?;
+    assert!(e.expect("want to borrow? 6000"));
+    for _ in 0..4 {
+        e.keypress(KeyCode::Backspace)?;
+    }
+    for c in "1000".chars() {
+        e.charpress(c)?;
+    }
+    e.enterpress()?;
+    // less a month of wages
+    assert!(e.expect("Gold:    1464"));
+    assert!(e.expect("Debt:    2650"));
+    // borrowing more doesn't put off the due date
+    e.charpress('o')?;
+    assert!(e.expect("Up to 4000 at 10% a month,"));
+    assert!(e.expect("to be repaid by Apr 1783."));
+    e.charpress('b')?;
+    e.charpress('8')?;
+    assert!(e.expect("You owe 2650 in all."));
+    assert!(e.expect("1000 to Venice by Apr 1783."));
+    // the loan due soonest is paid off first, and the rest goes to the debt at home
+    for c in "1200".chars() {
+        e.charpress(c)?;
+    }
+    e.enterpress()?;
+    assert!(e.expect("Gold:     264"));
+    assert!(e.expect("Debt:    1450"));
+    assert_eq!(e.game_state().loans[Location::Venice].balance, 0);
+    assert_eq!(e.game_state().loans[Location::Venice].due, None);
+    assert_eq!(e.game_state().loans[Location::London].balance, 1450);
+    Ok(())
+}
+
+#[test]
+fn debt_collectors() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        let mut state = GameState::new(
+            MockRng::new_with_default_locations()
+                .push_location_info(default_location_info())
+                .into(),
+        );
+        state.introduction_to_game();
+        state.gold = Saturating(1000);
+        state.loans[Location::Venice] = Loan {
+            balance: 1000,
+            interest_rate: 0.0,
+            due: Some((1782, Month::February)),
+        };
+        state
+    })?;
+    e.charpress('3')?;
+    e.charpress('6')?;
+    assert!(e.expect("Collectors for your Venice lender"));
+    assert!(e.expect("2 months overdue, and they take"));
+    // a quarter of what's left after a month of wages
+    assert!(e.expect("241 gold towards what you owe."));
+    e.charpress('x')?;
+    assert!(e.expect("Gold:     723"));
+    assert_eq!(e.game_state().loans[Location::Venice].balance, 759);
+    Ok(())
+}
+
+#[test]
+fn warehouse() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        let mut state = GameState::new(
+            MockRng::new_with_default_locations()
+                .push_location_info(default_location_info())
+                .into(),
+        );
+        state.introduction_to_game();
+        state.inventory[Good::Rum] = 20;
+        state
+    })?;
+    e.charpress('3')?;
+    e.charpress('6')?;
+    assert!(e.expect("|   Venice    |"));
+    assert!(e.nexpect("Stash deposit"));
+    assert!(e.expect("(u) Rent warehouse"));
+    assert!(e.expect("100 a month"));
+    e.charpress('u')?;
+    // less a month of wages
+    assert!(e.expect("Gold:     364"));
+    assert!(e.expect("Warehouse"));
+    assert!(e.expect("(u) Give up warehouse"));
+    e.charpress('4')?;
+    e.charpress('5')?;
+    e.charpress('2')?;
+    e.charpress('0')?;
+    e.enterpress()?;
+    assert_eq!(
+        e.game_state().warehouses[Location::Venice]
+            .as_ref()
+            .map(|warehouse| warehouse.goods[Good::Rum]),
+        Some(20)
+    );
+    assert!(e.expect("Rum:   20"));
+    // a warehouse with goods in it can't be given up
+    assert!(e.nexpect("Give up warehouse"));
+    Ok(())
+}
+
+#[test]
+fn aging_goods() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        let mut state = GameState::new(MockRng::new_with_default_locations().into());
+        state.introduction_to_game();
+        state.inventory[Good::Tea] = 10;
+        state
+            .inventory_lots
+            .add(&Good::Tea, 10, (1781, Month::November));
+        state.inventory[Good::Rum] = 10;
+        state
+            .inventory_lots
+            .add(&Good::Rum, 10, (1781, Month::November));
+        state.inventory[Good::Sugar] = 10;
+        state
+            .inventory_lots
+            .add(&Good::Sugar, 10, (1781, Month::November));
+        state
+    })?;
+    assert!(e.expect("Tea:   10-"));
+    assert!(e.expect("Rum:   10+"));
+    assert!(e.expect("Sugar:   10 "));
+    e.charpress('2')?;
+    e.charpress('1')?;
+    assert!(e.expect("You have (10), quality 80%"));
+    e.charpress('1')?;
+    e.charpress('0')?;
+    e.enterpress()?;
+    // four months old, the tea sells for 5 rather than 6
+    assert!(e.expect("Gold:     550"));
+    Ok(())
+}
+
+#[test]
+fn contracts() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        let mut state = GameState::new(
+            MockRng::new_with_default_locations()
+                .push_location_info(default_location_info())
+                .into(),
+        );
+        state.introduction_to_game();
+        state.locations[Location::London].contracts = vec![ContractOffer {
+            good: Good::Sugar,
+            amount: 10,
+            destination: Location::Savannah,
+            months: 3,
+            reward: 400,
+        }];
+        state.inventory[Good::Sugar] = 10;
+        state
+    })?;
+    assert!(e.expect("(c) Contracts"));
+    e.charpress('c')?;
+    assert!(e.expect("The merchants of London will pay for these deliveries:"));
+    assert!(e.expect("(1) Deliver 10 Sugar to Savannah by Jun 1782 for 400 gold"));
+    assert!(e.expect("none yet"));
+    e.charpress('1')?;
+    assert!(e.expect("Nothing on offer, come back another month."));
+    assert!(e.expect("10 Sugar to Savannah by Jun 1782 for 400 gold, from London"));
+    e.charpress('b')?;
+    assert!(e.expect("10 Sugar to Savannah by Jun 1782"));
+    e.charpress('3')?;
+    e.charpress('2')?;
+    assert!(e.expect("News of your contracts:"));
+    assert!(e.expect("Delivered 10 Sugar for 400 gold"));
+    e.charpress('x')?;
+    // less two months of wages
+    assert!(e.expect("Gold:     828"));
+    assert_eq!(e.game_state().inventory[Good::Sugar], 0);
+    assert!(!e.expect("10 Sugar to Savannah by Jun 1782"));
+    Ok(())
+}
+
+#[test]
+fn contract_delivered_late() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        let mut state = GameState::new(
+            MockRng::new_with_default_locations()
+                .push_location_info(default_location_info())
+                .into(),
+        );
+        state.introduction_to_game();
+        state.locations[Location::London].contracts = vec![ContractOffer {
+            good: Good::Sugar,
+            amount: 10,
+            destination: Location::Savannah,
+            months: 1,
+            reward: 400,
+        }];
+        state.inventory[Good::Sugar] = 10;
+        state
+    })?;
+    e.charpress('c')?;
+    e.charpress('1')?;
+    e.charpress('b')?;
+    assert!(e.expect("10 Sugar to Savannah by Apr 1782"));
+    // the voyage takes two months, so the goods arrive after the deadline
+    e.charpress('3')?;
+    e.charpress('2')?;
+    assert!(e.expect("News of your contracts:"));
+    assert!(e.expect("Missed 10 Sugar to Savannah, paid 100 gold"));
+    e.charpress('x')?;
+    // less two months of wages and the penalty, and the goods are still in the hold
+    assert!(e.expect("Gold:     328"));
+    assert_eq!(e.game_state().inventory[Good::Sugar], 10);
+    assert!(e.game_state().contracts.is_empty());
+    Ok(())
+}
+
 #[test]
 fn bank_deposit() -> UpdateResult<()> {
     let mut e = TestEngine::from_game_state({
@@ -675,6 +1282,69 @@ fn bank_withdraw() -> UpdateResult<()> {
     Ok(())
 }
 
+#[test]
+fn bank_run_in_any_month_of_a_voyage() -> UpdateResult<()> {
+    let mut state = GameState::new(
+        MockRng::new_with_default_locations()
+            .push_location_info(default_location_info())
+            // a run in the first month of the voyage, after which no more are rolled
+            .push_bank_run(Some(0.5))
+            .into(),
+    );
+    state.introduction_to_game();
+    state.bank = Saturating(1000);
+    assert_eq!(state.voyage_months(&Location::Savannah), 2);
+    state.begin_sailing()?.sail_to(&Location::Savannah)?;
+    assert_eq!(
+        state.mode,
+        Mode::GameEvent(LocationEvent::BankRun {
+            lost: 510,
+            frozen_until: (1782, Month::July),
+        })
+    );
+    // what was left kept earning interest for the rest of the voyage
+    assert_eq!(state.bank, Saturating(520));
+    Ok(())
+}
+
+#[test]
+fn bank_interest_and_run() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        let mut state = GameState::new(
+            MockRng::new_with_default_locations()
+                .push_location_info(default_location_info())
+                .push_bank_run(None)
+                .push_location_info(default_location_info())
+                .push_bank_run(Some(0.5))
+                .into(),
+        );
+        state.introduction_to_game();
+        state.bank = Saturating(1000);
+        state
+    })?;
+    e.charpress('6')?;
+    assert!(e.expect("Deposits earn 2% a month, but there is"));
+    assert!(e.expect("a 2% chance a month of a run on the bank."));
+    e.charpress('b')?;
+    e.charpress('3')?;
+    e.charpress('6')?;
+    assert_eq!(e.game_state().bank, Saturating(1020));
+    e.charpress('3')?;
+    e.charpress('1')?;
+    assert!(e.expect("Word reaches you of a run on your bank!"));
+    assert!(e.expect("520 gold of your deposit is lost, and"));
+    assert!(e.expect("August 1782."));
+    e.charpress('x')?;
+    assert!(e.expect("Bank:     520"));
+    e.charpress('7')?;
+    assert!(e.expect("Withdrawals are frozen until Aug 1782."));
+    e.charpress('1')?;
+    e.enterpress()?;
+    assert!(e.expect("want to withdraw? 1"));
+    assert!(e.expect("Bank:     520"));
+    Ok(())
+}
+
 #[test]
 fn bank_withdraw_back() -> UpdateResult<()> {
     let mut e = TestEngine::from_game_state({
@@ -729,7 +1399,7 @@ fn arrive_at_find_goods_event() -> UpdateResult<()> {
     let mut e = TestEngine::from_game_state({
         let mut state = GameState::new(MockRng::new_with_default_locations().into());
         state.introduction_to_game();
-        state.inventory.coffee = 4;
+        state.inventory[Good::Coffee] = 4;
         state.mode = Mode::GameEvent(LocationEvent::FindGoods(Good::Coffee, 10));
         state
     })?;
@@ -748,7 +1418,7 @@ fn arrive_at_find_goods_event_not_enough_hold() -> UpdateResult<()> {
     let mut e = TestEngine::from_game_state({
         let mut state = GameState::new(MockRng::new_with_default_locations().into());
         state.introduction_to_game();
-        state.inventory.coffee = 4;
+        state.inventory[Good::Coffee] = 4;
         state.hold_size = Saturating(11);
         state.mode = Mode::GameEvent(LocationEvent::FindGoods(Good::Coffee, 10));
         state
@@ -772,56 +1442,166 @@ fn arrive_at_stolen_goods_event_some_stolen() -> UpdateResult<()> {
                 .into(),
         );
         state.introduction_to_game();
-        state.inventory.coffee = 10;
-        state.mode = Mode::GameEvent(LocationEvent::GoodsStolen(None));
+        state.inventory[Good::Coffee] = 10;
+        state.mode = Mode::GameEvent(LocationEvent::GoodsStolen(None));
+        state
+    })?;
+    assert!(e.expect("Prowling harbor thieves stole"));
+    assert!(e.expect("4 Coffee from you!"));
+    assert!(e.expect("Coffee:   10"));
+    e.charpress('a')?;
+    assert!(e.expect("Coffee:    6"));
+    Ok(())
+}
+
+#[test]
+fn arrive_at_stolen_goods_event_nothing_stolen() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        let mut state = GameState::new(MockRng::new_with_default_locations().into());
+        state.introduction_to_game();
+        state.inventory[Good::Coffee] = 0;
+        state.mode = Mode::GameEvent(LocationEvent::GoodsStolen(None));
+        state
+    })?;
+    assert!(e.expect("Thieves were on the prowl, but they"));
+    assert!(e.expect("couldn't find anything to steal"));
+    e.charpress('a')?;
+    assert!(e.expect("(1) Buy"));
+    Ok(())
+}
+
+#[test]
+fn arrive_at_can_buy_cannon_accept() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        let mut state = GameState::new(MockRng::new_with_default_locations().into());
+        state.introduction_to_game();
+        state.gold = Saturating(5100);
+        state.cannons = Saturating(3);
+        state.mode = Mode::GameEvent(LocationEvent::CanBuyCannon);
+        state
+    })?;
+    assert!(e.expect("Gold:    5100"));
+    assert!(e.expect("Cannons: 3"));
+    assert!(e.expect("An enterprising gentleman on the docks"));
+    assert!(e.expect("offers to outfit your ship with an"));
+    assert!(e.expect("additional cannon for 5000 gold."));
+    assert!(e.expect("Accept? y/n"));
+    e.charpress('e')?; // no effect
+    assert!(e.expect("Accept? y/n"));
+    e.charpress('y')?;
+    assert!(e.expect("(1) Buy"));
+    assert!(e.expect("Gold:     100"));
+    assert!(e.expect("Cannons: 4"));
+    Ok(())
+}
+
+#[test]
+fn buy_and_sell_with_duties() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        let mut state = GameState::new(MockRng::new_with_default_locations().into());
+        state.introduction_to_game();
+        state.gold = Saturating(1400);
+        state.inventory[Good::Cotton] = 15;
+        state.locations[Location::London].prices[Good::Cotton] = 30;
+        state.location_config.personalities[Location::London].customs = Customs {
+            import_duties: vec![Duty {
+                good: Good::Cotton,
+                rate: 0.2,
+            }],
+            export_duties: vec![Duty {
+                good: Good::Cotton,
+                rate: 0.1,
+            }],
+            contraband: vec![Good::Tobacco],
+        };
+        state
+    })?;
+    e.charpress('1')?;
+    e.charpress('6')?;
+    // the duty comes out of what there is to spend
+    assert!(e.expect("You can afford (39)"));
+    assert!(e.expect("Export duty 10%: 0 gold"));
+    e.charpress('1')?;
+    e.charpress('0')?;
+    // on the 31 each the cotton costs on average
+    assert!(e.expect("Export duty 10%: 31 gold"));
+    e.enterpress()?;
+    assert!(e.expect("Cotton:   25"));
+    assert!(e.expect("Gold:    1059"));
+    e.charpress('2')?;
+    e.charpress('6')?;
+    e.charpress('1')?;
+    e.charpress('0')?;
+    // buying raised the price to 31, but selling lowers it again for each unit sold
+    assert!(e.expect("Import duty 20%: 60 gold"));
+    e.enterpress()?;
+    assert!(e.expect("Cotton:   15"));
+    assert!(e.expect("Gold:    1299"));
+    e.charpress('1')?;
+    e.charpress('4')?;
+    assert!(e.expect("Tobacco is contraband here!"));
+    Ok(())
+}
+
+#[test]
+fn customs_inspection_finds_nothing() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        let mut state = GameState::new(MockRng::new_with_default_locations().into());
+        state.introduction_to_game();
+        state.location_config.personalities[Location::London]
+            .customs
+            .contraband = vec![Good::Tobacco];
+        state.mode = Mode::GameEvent(LocationEvent::CustomsInspection);
         state
     })?;
-    assert!(e.expect("Prowling harbor thieves stole"));
-    assert!(e.expect("4 Coffee from you!"));
-    assert!(e.expect("Coffee:   10"));
+    assert!(e.expect("Customs officers search your hold"));
+    assert!(e.expect("and find nothing amiss."));
     e.charpress('a')?;
-    assert!(e.expect("Coffee:    6"));
+    assert!(e.expect("(1) Buy"));
     Ok(())
 }
 
 #[test]
-fn arrive_at_stolen_goods_event_nothing_stolen() -> UpdateResult<()> {
+fn customs_inspection_seizes_contraband() -> UpdateResult<()> {
     let mut e = TestEngine::from_game_state({
         let mut state = GameState::new(MockRng::new_with_default_locations().into());
         state.introduction_to_game();
-        state.inventory.coffee = 0;
-        state.mode = Mode::GameEvent(LocationEvent::GoodsStolen(None));
+        state.inventory[Good::Tobacco] = 10;
+        state.locations[Location::London].prices[Good::Tobacco] = 40;
+        state.location_config.personalities[Location::London]
+            .customs
+            .contraband = vec![Good::Tobacco];
+        state.mode = Mode::GameEvent(LocationEvent::CustomsInspection);
         state
     })?;
-    assert!(e.expect("Thieves were on the prowl, but they"));
-    assert!(e.expect("couldn't find anything to steal"));
-    e.charpress('a')?;
+    assert!(e.expect("and find 10 Tobacco, forbidden here."));
+    assert!(e.expect("They'll seize it and fine you 200 gold,"));
+    assert!(e.expect("unless you slip them 120 gold."));
+    assert!(e.expect("Pay the bribe? y/n"));
+    e.charpress('n')?;
     assert!(e.expect("(1) Buy"));
+    assert!(e.expect("Gold:     300"));
+    assert_eq!(e.game_state().inventory[Good::Tobacco], 0);
     Ok(())
 }
 
 #[test]
-fn arrive_at_can_buy_cannon_accept() -> UpdateResult<()> {
+fn customs_inspection_bribe() -> UpdateResult<()> {
     let mut e = TestEngine::from_game_state({
         let mut state = GameState::new(MockRng::new_with_default_locations().into());
         state.introduction_to_game();
-        state.gold = Saturating(5100);
-        state.cannons = Saturating(3);
-        state.mode = Mode::GameEvent(LocationEvent::CanBuyCannon);
+        state.inventory[Good::Tobacco] = 10;
+        state.locations[Location::London].prices[Good::Tobacco] = 40;
+        state.location_config.personalities[Location::London]
+            .customs
+            .contraband = vec![Good::Tobacco];
+        state.mode = Mode::GameEvent(LocationEvent::CustomsInspection);
         state
     })?;
-    assert!(e.expect("Gold:    5100"));
-    assert!(e.expect("Cannons: 3"));
-    assert!(e.expect("An enterprising gentleman on the docks"));
-    assert!(e.expect("offers to outfit your ship with an"));
-    assert!(e.expect("additional cannon for 5000 gold."));
-    assert!(e.expect("Accept? y/n"));
-    e.charpress('e')?; // no effect
-    assert!(e.expect("Accept? y/n"));
     e.charpress('y')?;
     assert!(e.expect("(1) Buy"));
-    assert!(e.expect("Gold:     100"));
-    assert!(e.expect("Cannons: 4"));
+    assert!(e.expect("Gold:     380"));
+    assert_eq!(e.game_state().inventory[Good::Tobacco], 10);
     Ok(())
 }
 
@@ -866,6 +1646,7 @@ fn pirate_encounter_initial() -> UpdateResult<()> {
     let mut e = TestEngine::from_game_state({
         let mut state = GameState::new(
             MockRng::new_with_default_locations()
+                .push_pirate_captain(None)
                 .push_num_pirates_encountered(4)
                 .into(),
         );
@@ -934,9 +1715,9 @@ fn pirate_encounter_initial() -> UpdateResult<()> {
 |                                                                                                 |
 |                                                                                                 |
 |                                                                                                 |
+|                  Will you (r)un, (f)ight, fire a (b)roadside or b(o)ard them ?                  |
 |                                                                                                 |
 |                                                                                                 |
-|                                   Will you (r)un or (f)ight ?                                   |
 |                                                                                                 |
 |                                                                                                 |
 |                                                                                                 |
@@ -960,6 +1741,65 @@ fn pirate_encounter_initial() -> UpdateResult<()> {
     Ok(())
 }
 
+#[test]
+fn pirate_encounter_bribe_and_surrender() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        let mut state = GameState::new(
+            MockRng::new_with_default_locations()
+                .push_gold_bribe_accepted(true)
+                .push_share_of_hold_surrendered(0.45)
+                .into(),
+        );
+        state.introduction_to_game();
+        state.gold = Saturating(2000);
+        state.inventory[Good::Tea] = 10;
+        state.mode = Mode::GameEvent(LocationEvent::PirateEncounter(
+            crate::state::PirateEncounterState::Prompt {
+                info: PirateEncounterInfo::new(2, 5),
+            },
+        ));
+        state
+    })?;
+    assert!(e.expect(
+        "Or (g)ive them 1200 gold, offer them (c)argo, (s)urrender cargo or (d)ump cargo and run ?"
+    ));
+    e.charpress('g')?;
+    assert!(e.expect("The pirates take 1200 gold and sail away."));
+    e.charpress('x')?;
+    assert!(e.expect("Gold:     800"));
+    assert!(e.expect("Tea:   10"));
+    Ok(())
+}
+
+#[test]
+fn pirate_encounter_surrender() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        let mut state = GameState::new(
+            MockRng::new_with_default_locations()
+                .push_share_of_hold_surrendered(0.45)
+                .into(),
+        );
+        state.introduction_to_game();
+        state.inventory[Good::Tea] = 10;
+        state.mode = Mode::GameEvent(LocationEvent::PirateEncounter(
+            crate::state::PirateEncounterState::Prompt {
+                info: PirateEncounterInfo::new(2, 5),
+            },
+        ));
+        state
+    })?;
+    // too little gold to pay them off
+    assert!(e.expect("Or offer them (c)argo, (s)urrender cargo or (d)ump cargo and run ?"));
+    e.charpress('g')?;
+    assert!(e.expect("Or offer them (c)argo"));
+    // the share taken is rounded up
+    e.charpress('s')?;
+    assert!(e.expect("The pirates help themselves to 5 of your goods and leave you be."));
+    e.charpress('x')?;
+    assert!(e.expect("Tea:    5"));
+    Ok(())
+}
+
 #[test]
 fn pirate_encounter_run_success() -> UpdateResult<()> {
     let mut e = TestEngine::from_game_state({
@@ -972,7 +1812,7 @@ fn pirate_encounter_run_success() -> UpdateResult<()> {
         state.cannons = Saturating(2);
         state.mode = Mode::GameEvent(LocationEvent::PirateEncounter(
             crate::state::PirateEncounterState::Prompt {
-                info: PirateEncounterInfo::new(2),
+                info: PirateEncounterInfo::new(2, 5),
             },
         ));
         state
@@ -994,9 +1834,9 @@ fn pirate_encounter_run_success() -> UpdateResult<()> {
 |                                                                                                 |
 |                                                                                                 |
 |                                                                                                 |
+|                  Will you (r)un, (f)ight, fire a (b)roadside or b(o)ard them ?                  |
 |                                                                                                 |
 |                                                                                                 |
-|                                   Will you (r)un or (f)ight ?                                   |
 |                                                                                                 |
 |                                                                                                 |
 |                                                                                                 |
@@ -1076,7 +1916,7 @@ fn pirate_encounter_run_failure() -> UpdateResult<()> {
         state.cannons = Saturating(2);
         state.mode = Mode::GameEvent(LocationEvent::PirateEncounter(
             crate::state::PirateEncounterState::Prompt {
-                info: PirateEncounterInfo::new(2),
+                info: PirateEncounterInfo::new(2, 5),
             },
         ));
         state
@@ -1098,9 +1938,9 @@ fn pirate_encounter_run_failure() -> UpdateResult<()> {
 |                                                                                                 |
 |                                                                                                 |
 |                                                                                                 |
+|                  Will you (r)un, (f)ight, fire a (b)roadside or b(o)ard them ?                  |
 |                                                                                                 |
 |                                                                                                 |
-|                                   Will you (r)un or (f)ight ?                                   |
 |                                                                                                 |
 |                                                                                                 |
 |                                                                                                 |
@@ -1175,7 +2015,7 @@ fn pirate_encounter_pirates_attack_not_destroyed() -> UpdateResult<()> {
         state.cannons = Saturating(2);
         state.mode = Mode::GameEvent(LocationEvent::PirateEncounter(
             crate::state::PirateEncounterState::PiratesAttack {
-                info: PirateEncounterInfo::new(2),
+                info: PirateEncounterInfo::new(2, 5),
                 damage_this_attack: 2,
             },
         ));
@@ -1239,9 +2079,9 @@ fn pirate_encounter_pirates_attack_not_destroyed() -> UpdateResult<()> {
 |                                                                                                 |
 |                                                                                                 |
 |                                                                                                 |
+|                  Will you (r)un, (f)ight, fire a (b)roadside or b(o)ard them ?                  |
 |                                                                                                 |
 |                                                                                                 |
-|                                   Will you (r)un or (f)ight ?                                   |
 |                                                                                                 |
 |                                                                                                 |
 |                                                                                                 |
@@ -1265,12 +2105,294 @@ fn pirate_encounter_pirates_attack_not_destroyed() -> UpdateResult<()> {
     Ok(())
 }
 
+#[test]
+fn hull_damage_lasts_until_mended() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        let mut state = GameState::new(
+            MockRng::new_with_default_locations()
+                .push_run_success(true)
+                .into(),
+        );
+        state.introduction_to_game();
+        state.mode = Mode::GameEvent(LocationEvent::PirateEncounter(
+            crate::state::PirateEncounterState::PiratesAttack {
+                info: PirateEncounterInfo::new(2, 5),
+                damage_this_attack: 3,
+            },
+        ));
+        state
+    })?;
+    e.charpress('x')?;
+    assert!(e.expect("Health 2, Pirates 2, Cannons 1."));
+    e.charpress('r')?;
+    e.charpress('x')?;
+    // the damage outlasts the fight
+    assert!(e.expect("Hull: 2/5"));
+    assert!(e.expect("(m) Mend hull (600)"));
+    // and slows the ship down
+    e.charpress('a')?;
+    assert!(e.expect("(6) Venice    2 mo"));
+    assert!(e.expect("The damaged hull slows every voyage."));
+    e.charpress('b')?;
+    // only as much is mended as can be afforded
+    e.charpress('m')?;
+    assert!(e.expect("Hull: 4/5"));
+    assert!(e.expect("Gold:     100"));
+    assert!(e.expect("(m) Mend hull (200)"));
+    e.charpress('a')?;
+    assert!(e.expect("(6) Venice    1 mo"));
+    Ok(())
+}
+
+#[test]
+fn trade_up_at_a_shipyard() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        let mut state = GameState::new(MockRng::new_with_default_locations().into());
+        state.introduction_to_game();
+        state.location = Location::Amsterdam;
+        state.gold = Saturating(9000);
+        state.cannons = Saturating(5);
+        state.hull_damage = 1;
+        state
+    })?;
+    e.charpress('y')?;
+    assert!(e.expect("The Shipyard of Amsterdam"));
+    // the damage to the sloop comes off its trade-in
+    assert!(e.expect("They'll give 300 gold for your Sloop"));
+    // too dear
+    e.charpress('f')?;
+    assert!(e.expect("The Shipyard of Amsterdam"));
+    e.charpress('r')?;
+    assert!(e.expect("Gold:    1300"));
+    assert!(e.expect("Hold:  250"));
+    assert!(e.expect("Hull: 8/8"));
+    // the cannons move over
+    assert!(e.expect("Cannons: 5"));
+    assert!(e.expect("(y) Shipyard"));
+    Ok(())
+}
+
+#[test]
+fn sign_on_crew() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        let mut state = GameState::new(MockRng::new_with_default_locations().into());
+        state.introduction_to_game();
+        state.crew.sailors = 8;
+        state
+    })?;
+    assert!(e.expect("Crew: 8, content"));
+    e.charpress('n')?;
+    assert!(e.expect("How many sailors do you"));
+    assert!(e.expect("20 gold each, and 3 a month in wages."));
+    assert!(e.expect("Berths for 4 more."));
+    // more than there are berths for does nothing
+    e.charpress('6')?;
+    e.enterpress()?;
+    assert!(e.expect("want to sign on? 6"));
+    e.keypress(KeyCode::Backspace)?;
+    e.charpress('4')?;
+    e.enterpress()?;
+    assert!(e.expect("Gold:     420"));
+    assert!(e.expect("Crew: 12, content"));
+    Ok(())
+}
+
+#[test]
+fn crew_deserts_on_arrival() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        let mut state = GameState::new(
+            MockRng::new_with_default_locations()
+                .push_location_info(default_location_info())
+                .push_deserters(3)
+                .into(),
+        );
+        state.introduction_to_game();
+        state.crew.morale = 30;
+        state
+    })?;
+    e.charpress('3')?;
+    e.charpress('6')?;
+    assert!(e.expect("Your crew has had enough of you!"));
+    assert!(e.expect("3 of them slipped ashore and won't be back."));
+    e.charpress('x')?;
+    // paying their wages cheered those who stayed a little
+    assert!(e.expect("Crew: 9, restless"));
+    Ok(())
+}
+
+#[test]
+fn arrival_events_are_shown_in_turn() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        let mut state = GameState::new(
+            MockRng::new_with_default_locations()
+                .push_location_info(LocationInfo {
+                    event: Some(LocationEvent::CheapGood(Good::Rum)),
+                    ..default_location_info()
+                })
+                .push_deserters(3)
+                .into(),
+        );
+        state.introduction_to_game();
+        state.crew.morale = 30;
+        state
+    })?;
+    e.charpress('3')?;
+    e.charpress('6')?;
+    assert!(e.expect("3 of them slipped ashore and won't be back."));
+    e.charpress('x')?;
+    // the port's own event isn't lost to the desertion
+    assert!(e.expect("a surplus of Rum, lowering"));
+    e.charpress('x')?;
+    assert!(e.expect("Crew: 9, restless"));
+    Ok(())
+}
+
+#[test]
+fn crew_mutinies_on_arrival() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        let mut state = GameState::new(
+            MockRng::new_with_default_locations()
+                .push_location_info(default_location_info())
+                .push_mutiny(true)
+                .into(),
+        );
+        state.introduction_to_game();
+        state.crew.morale = 10;
+        state
+    })?;
+    e.charpress('3')?;
+    e.charpress('6')?;
+    assert!(e.expect("Mutiny! Your crew rose up on the way into port."));
+    assert!(e.expect("6 of them made off with 232 of your gold."));
+    e.charpress('x')?;
+    assert!(e.expect("Gold:     232"));
+    assert!(e.expect("Crew: 6, content"));
+    Ok(())
+}
+
+#[test]
+fn pirates_find_a_short_handed_crew() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        let mut state = GameState::new(
+            MockRng::new_with_default_locations()
+                .push_pirate_captain(None)
+                .push_num_pirates_encountered(2)
+                .into(),
+        );
+        state.introduction_to_game();
+        state.cannons = Saturating(5);
+        state.crew.sailors = 5;
+        state.mode = Mode::GameEvent(LocationEvent::PirateEncounter(
+            crate::state::PirateEncounterState::Initial,
+        ));
+        state
+    })?;
+    e.charpress('x')?;
+    assert!(e.expect("Health 5, Pirates 2, Cannons 5 (2 manned)."));
+    Ok(())
+}
+
+#[test]
+fn pirate_captain_and_bounty() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        let mut state = GameState::new(
+            MockRng::new_with_default_locations()
+                .push_pirate_captain(Some(PirateCaptain::IronMeg))
+                .push_num_pirates_encountered(1)
+                .push_did_kill_a_pirate(true)
+                .push_gold_recovered_from_pirate_encounter(300)
+                .into(),
+        );
+        state.introduction_to_game();
+        // the player has met them twice, and run from them each time
+        *state.pirates.get_mut(PirateCaptain::IronMeg) = PirateRecord {
+            encounters: 2,
+            runs: 2,
+            wins: 0,
+            sunk: false,
+        };
+        state.mode = Mode::GameEvent(LocationEvent::PirateEncounter(
+            crate::state::PirateEncounterState::Initial,
+        ));
+        state
+    })?;
+    e.charpress('x')?;
+    assert_eq!(
+        e.get_current_formatted(),
+        e.expect_full(
+            r###"
+----------------------------------------|=================|----------------------------------------
+|                                       | March      1782 |                                       |
+|                                       |=================|                                       |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                 Health 5, Pirates 1, Cannons 1.                                 |
+|                                                                                                 |
+|           They sail under Iron Meg, a feared captain who has met you 2 times before.            |
+|                          There's a bounty of 1100 gold on their head.                           |
+|                                                                                                 |
+|                  Will you (r)un, (f)ight, fire a (b)roadside or b(o)ard them ?                  |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+|                                                                                                 |
+---------------------------------------------------------------------------------------------------
+"###
+        )
+    );
+    e.charpress('f')?;
+    e.charpress('x')?;
+    assert!(e.expect("The authorities of London pay a bounty of 1100 gold for Iron Meg."));
+    e.charpress('x')?;
+    assert!(e.expect("Gold:    1900"));
+    assert!(e.game_state().pirates.get(PirateCaptain::IronMeg).sunk);
+    Ok(())
+}
+
+#[test]
+fn pirates_find_a_damaged_hull() -> UpdateResult<()> {
+    let mut e = TestEngine::from_game_state({
+        let mut state = GameState::new(
+            MockRng::new_with_default_locations()
+                .push_pirate_captain(None)
+                .push_num_pirates_encountered(2)
+                .into(),
+        );
+        state.introduction_to_game();
+        state.hull_damage = 1;
+        state.mode = Mode::GameEvent(LocationEvent::PirateEncounter(
+            crate::state::PirateEncounterState::Initial,
+        ));
+        state
+    })?;
+    e.charpress('x')?;
+    assert!(e.expect("Health 4, Pirates 2, Cannons 1."));
+    Ok(())
+}
+
 #[test]
 fn pirate_encounter_pirates_attack_is_destroyed() -> UpdateResult<()> {
     let mut e = TestEngine::from_game_state({
         let mut state = GameState::new(MockRng::new_with_default_locations().into());
         state.introduction_to_game();
-        state.inventory.tea = 10;
+        state.inventory[Good::Tea] = 10;
         state.gold = Saturating(500);
         state.cannons = Saturating(2);
         state.mode = Mode::GameEvent(LocationEvent::PirateEncounter(
@@ -1279,6 +2401,8 @@ fn pirate_encounter_pirates_attack_is_destroyed() -> UpdateResult<()> {
                     health: 2,
                     cur_pirates: 2,
                     total_pirates: 2,
+                    captain: None,
+                    boarded: 0,
                 },
                 damage_this_attack: 3,
             },
@@ -1367,7 +2491,7 @@ fn pirate_encounter_pirates_attack_is_destroyed() -> UpdateResult<()> {
         )
     );
     e.charpress('x')?;
-    assert!(e.expect("Tea:    0"));
+    assert_eq!(e.game_state().inventory[Good::Tea], 0);
     assert!(e.expect("Gold:     250"));
     Ok(())
 }
@@ -1385,7 +2509,7 @@ fn pirate_encounter_fight_did_sink_pirate_and_did_not_win() -> UpdateResult<()>
         state.cannons = Saturating(2);
         state.mode = Mode::GameEvent(LocationEvent::PirateEncounter(
             crate::state::PirateEncounterState::Prompt {
-                info: PirateEncounterInfo::new(2),
+                info: PirateEncounterInfo::new(2, 5),
             },
         ));
         state
@@ -1407,9 +2531,9 @@ fn pirate_encounter_fight_did_sink_pirate_and_did_not_win() -> UpdateResult<()>
 |                                                                                                 |
 |                                                                                                 |
 |                                                                                                 |
+|                  Will you (r)un, (f)ight, fire a (b)roadside or b(o)ard them ?                  |
 |                                                                                                 |
 |                                                                                                 |
-|                                   Will you (r)un or (f)ight ?                                   |
 |                                                                                                 |
 |                                                                                                 |
 |                                                                                                 |
@@ -1490,7 +2614,7 @@ fn pirate_encounter_fight_did_sink_pirate_and_did_win() -> UpdateResult<()> {
         state.cannons = Saturating(2);
         state.mode = Mode::GameEvent(LocationEvent::PirateEncounter(
             crate::state::PirateEncounterState::Prompt {
-                info: PirateEncounterInfo::new(1),
+                info: PirateEncounterInfo::new(1, 5),
             },
         ));
         state
@@ -1512,9 +2636,9 @@ fn pirate_encounter_fight_did_sink_pirate_and_did_win() -> UpdateResult<()> {
 |                                                                                                 |
 |                                                                                                 |
 |                                                                                                 |
+|                  Will you (r)un, (f)ight, fire a (b)roadside or b(o)ard them ?                  |
 |                                                                                                 |
 |                                                                                                 |
-|                                   Will you (r)un or (f)ight ?                                   |
 |                                                                                                 |
 |                                                                                                 |
 |                                                                                                 |
@@ -1637,7 +2761,7 @@ fn pirate_encounter_fight_did_not_sink_pirate() -> UpdateResult<()> {
         state.cannons = Saturating(2);
         state.mode = Mode::GameEvent(LocationEvent::PirateEncounter(
             crate::state::PirateEncounterState::Prompt {
-                info: PirateEncounterInfo::new(1),
+                info: PirateEncounterInfo::new(1, 5),
             },
         ));
         state
@@ -1659,9 +2783,9 @@ fn pirate_encounter_fight_did_not_sink_pirate() -> UpdateResult<()> {
 |                                                                                                 |
 |                                                                                                 |
 |                                                                                                 |
+|                  Will you (r)un, (f)ight, fire a (b)roadside or b(o)ard them ?                  |
 |                                                                                                 |
 |                                                                                                 |
-|                                   Will you (r)un or (f)ight ?                                   |
 |                                                                                                 |
 |                                                                                                 |
 |                                                                                                 |
@@ -1728,6 +2852,44 @@ fn pirate_encounter_fight_did_not_sink_pirate() -> UpdateResult<()> {
     Ok(())
 }
 
+#[test]
+fn boarded_pirates_are_not_plundered_twice() -> UpdateResult<()> {
+    let mut state = GameState::new(
+        MockRng::new_with_default_locations()
+            .push_boarding_success(true)
+            .push_gold_recovered_from_pirate_encounter(300)
+            .push_damage_from_pirates(1)
+            .push_boarding_success(true)
+            .push_gold_recovered_from_pirate_encounter(250)
+            .into(),
+    );
+    state.introduction_to_game();
+    state.gold = Saturating(500);
+    state.mode = Mode::GameEvent(LocationEvent::PirateEncounter(
+        crate::state::PirateEncounterState::Prompt {
+            info: PirateEncounterInfo::new(2, 5),
+        },
+    ));
+    state.pirate_board()?;
+    state.proceed_board_result()?;
+    state.proceed_pirates_attack()?;
+    state.pirate_board()?;
+    state.proceed_board_result()?;
+    // both ships were plundered when they were taken, so there's no wreckage left to recover
+    assert_eq!(
+        state.mode,
+        Mode::GameEvent(LocationEvent::PirateEncounter(
+            crate::state::PirateEncounterState::Victory {
+                gold_recovered: 0,
+                bounty: None,
+            }
+        ))
+    );
+    state.proceed_pirate_encounter_victory()?;
+    assert_eq!(state.gold.0, 1050);
+    Ok(())
+}
+
 #[test]
 fn can_buy_hold_space_accept() -> UpdateResult<()> {
     let mut e = TestEngine::from_game_state({
@@ -1815,33 +2977,33 @@ fn no_effect_sunny_day() -> UpdateResult<()> {
 '~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~|=================|~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.
 )                                       | March      1782 |                                       (
 |                                       |=================|                                       |
-(     _____[LLL]______[LLL]____                                     |                             )
-|    /     [LLL]      [LLL]    \                        |          )_)                            |
-)   /___________________________\                      )_)        )___)         |                 (
-|    )=========================(                      )___)       )____)       )_)\               |
-(    '|I .--. I     Tea:    0 I|                      )____)     /)_____)      )__)\              )
-|     |I | +| I  Coffee:    0 I|                     )_____)    /)______)\    )___) \             |
-)     |I_|_+|_I   Sugar:    0 I|                    )______)  //)_______) \\ )_____) \\           (
-|    /_I______I Tobacco:    0 I_\             _____//___|___///_____|______\\\__|_____\\\__=====  |
-(     )========     Rum:    0 =(              \      Tea:    0 Coffee:    0  Sugar:    0  /       )
-|     |I .--. I  Cotton:    0 I|               \ Tobacco:    0    Rum:    0 Cotton:    0 /        |
-)     |I |<>| I               I|                \                                       /____     (
-|     |I |~ | I Bank:       0 I|       --------- \ Gold:     500 Hold:  100 Cannons: 1 //.../---  |
-(     |I |  | I Debt:    1500 I|          ^^^^^ ^^^^^^^^^^^^^^^^^^^^^   ^^^^^^^^^^  ^^^/.../      )
-|     |I_|__|_I_______________I|                ^^^^      ^^^    ^^^^^^^^^    ^^^^^  /..../       |
-)   ###(______)##################                        ^^^      ^^^^             /...../        (
-|    ##(________)   ~"^"^~   ##                                                  /....../         |
+(     _____[LLL]______[LLL]______                                   |                             )
+|    /     [LLL]      [LLL]      \                                 )_)\                           |
+)   /_____________________________\                               )___) \                         (
+|    )===========================(                                )____)  \                       |
+(    '|I .--. I                 I|                               /)_____)   \                     )
+|     |I | +| I                 I|                              /)______)\    \                   |
+)     |I_|_+|_I                 I|                            //)_______) \\    \\                (
+|    /_I______I                 I_\           ______________///_____|______\\\_____\\\_____=====  |
+(     )========                 =(            \                                           /       )
+|     |I .--. I                 I|             \                                         /        |
+)     |I |<>| I                 I|              \                                       /____     (
+|     |I |~ | I  Bank:       0  I|     --------- \ Gold:     500 Hold:  100 Cannons: 1 //.../---  |
+(     |I |  | I  Debt:    1500  I|        ^^^^^ ^^ Hull: 5/5 ^^^^^^^^   ^^^^^^^^^^  ^^^/.../      )
+|     |I_|__|_I_________________I|              ^^ Crew: 12, content ^^^^^    ^^^^^  /..../       |
+)   ###(______)####################                      ^^^      ^^^^             /...../        (
+|    ##(________)   ~"^"^~     ##                                                /....../         |
 ('~~.~~(_________)~'~~.~~'~~.~~'~~.~~'~~.~<------------->~.~~'~~.~~'~~.~~'~~.~~/......../~~'~~.~~')
 |      (__________)                       |   London    |                    /........./          |
 )                                         <------------->                                         (
 |                                                                                                 |
-(         As your ship glides into the harbor        Captain, the prices of goods here are:       )
-|         you take a moment and feel the                            Tea:    6                     |
-)         comforting warmth of the sun                           Coffee:    5                     (
-|         on your face.                                           Sugar:    4                     |
-(                                                               Tobacco:    3                     )
-|         (press any key to continue)                               Rum:    2                     |
-)                                                                Cotton:    1                     (
+(         As your ship glides into the harbor       Captain, the prices of goods here are:        )
+|         you take a moment and feel the                  Tea:    6           Cotton:    1        |
+)         comforting warmth of the sun                 Coffee:    5           Spices:    5        (
+|         on your face.                                 Sugar:    4             Silk:    6        |
+(                                                     Tobacco:    3           Indigo:    2        )
+|         (press any key to continue)                     Rum:    2        Porcelain:    7        |
+)                                                                                                 (
 |                                                                                                 |
 (                                                                                                 )
 .~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'
@@ -1868,33 +3030,33 @@ fn no_effect_storm() -> UpdateResult<()> {
 '~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~|=================|~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.
 )                                       | March      1782 |                                       (
 |                                       |=================|                                       |
-(     _____[LLL]______[LLL]____                                     |                             )
-|    /     [LLL]      [LLL]    \                        |          )_)                            |
-)   /___________________________\                      )_)        )___)         |                 (
-|    )=========================(                      )___)       )____)       )_)\               |
-(    '|I .--. I     Tea:    0 I|                      )____)     /)_____)      )__)\              )
-|     |I | +| I  Coffee:    0 I|                     )_____)    /)______)\    )___) \             |
-)     |I_|_+|_I   Sugar:    0 I|                    )______)  //)_______) \\ )_____) \\           (
-|    /_I______I Tobacco:    0 I_\             _____//___|___///_____|______\\\__|_____\\\__=====  |
-(     )========     Rum:    0 =(              \      Tea:    0 Coffee:    0  Sugar:    0  /       )
-|     |I .--. I  Cotton:    0 I|               \ Tobacco:    0    Rum:    0 Cotton:    0 /        |
-)     |I |<>| I               I|                \                                       /____     (
-|     |I |~ | I Bank:       0 I|       --------- \ Gold:     500 Hold:  100 Cannons: 1 //.../---  |
-(     |I |  | I Debt:    1500 I|          ^^^^^ ^^^^^^^^^^^^^^^^^^^^^   ^^^^^^^^^^  ^^^/.../      )
-|     |I_|__|_I_______________I|                ^^^^      ^^^    ^^^^^^^^^    ^^^^^  /..../       |
-)   ###(______)##################                        ^^^      ^^^^             /...../        (
-|    ##(________)   ~"^"^~   ##                                                  /....../         |
+(     _____[LLL]______[LLL]______                                   |                             )
+|    /     [LLL]      [LLL]      \                                 )_)\                           |
+)   /_____________________________\                               )___) \                         (
+|    )===========================(                                )____)  \                       |
+(    '|I .--. I                 I|                               /)_____)   \                     )
+|     |I | +| I                 I|                              /)______)\    \                   |
+)     |I_|_+|_I                 I|                            //)_______) \\    \\                (
+|    /_I______I                 I_\           ______________///_____|______\\\_____\\\_____=====  |
+(     )========                 =(            \                                           /       )
+|     |I .--. I                 I|             \                                         /        |
+)     |I |<>| I                 I|              \                                       /____     (
+|     |I |~ | I  Bank:       0  I|     --------- \ Gold:     500 Hold:  100 Cannons: 1 //.../---  |
+(     |I |  | I  Debt:    1500  I|        ^^^^^ ^^ Hull: 5/5 ^^^^^^^^   ^^^^^^^^^^  ^^^/.../      )
+|     |I_|__|_I_________________I|              ^^ Crew: 12, content ^^^^^    ^^^^^  /..../       |
+)   ###(______)####################                      ^^^      ^^^^             /...../        (
+|    ##(________)   ~"^"^~     ##                                                /....../         |
 ('~~.~~(_________)~'~~.~~'~~.~~'~~.~~'~~.~<------------->~.~~'~~.~~'~~.~~'~~.~~/......../~~'~~.~~')
 |      (__________)                       |   London    |                    /........./          |
 )                                         <------------->                                         (
 |                                                                                                 |
-(         You stand on the docks and look            Captain, the prices of goods here are:       )
-|         off to the horizon.                                       Tea:    6                     |
-)         You see an ominous storm forming.                      Coffee:    5                     (
-|                                                                 Sugar:    4                     |
-(                                                               Tobacco:    3                     )
-|         (press any key to continue)                               Rum:    2                     |
-)                                                                Cotton:    1                     (
+(         You stand on the docks and look           Captain, the prices of goods here are:        )
+|         off to the horizon.                             Tea:    6           Cotton:    1        |
+)         You see an ominous storm forming.            Coffee:    5           Spices:    5        (
+|                                                       Sugar:    4             Silk:    6        |
+(                                                     Tobacco:    3           Indigo:    2        )
+|         (press any key to continue)                     Rum:    2        Porcelain:    7        |
+)                                                                                                 (
 |                                                                                                 |
 (                                                                                                 )
 .~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'~.~'
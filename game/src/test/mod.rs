@@ -1,5 +1,5 @@
 mod integration_cases;
 mod render_component;
-mod rng;
+pub mod rng;
 pub mod test_engine;
 pub use render_component::*;
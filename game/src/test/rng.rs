@@ -1,8 +1,9 @@
 use std::collections::VecDeque;
 
 use crate::state::{
-    EventWeights, Good, Inventory, Location, LocationConfig, LocationInfo, LocationMap,
-    LocationPersonality, MerchantRng, PriceRanges,
+    ContractTerms, Customs, EventWeights, Good, Inventory, Lender, Location, LocationConfig,
+    LocationInfo, LocationMap, LocationPersonality, MerchantRng, PirateCaptain, PriceRanges, Rules,
+    WarehouseMishap,
 };
 
 pub struct MockRng {
@@ -10,14 +11,27 @@ pub struct MockRng {
     damage_from_pirates: VecDeque<u8>,
     did_kill_a_pirate: VecDeque<bool>,
     run_success: VecDeque<bool>,
+    ships_sunk_by_broadside: VecDeque<u8>,
+    boarding_success: VecDeque<bool>,
+    gold_bribe_accepted: VecDeque<bool>,
+    cargo_bribe_accepted: VecDeque<bool>,
+    share_of_hold_surrendered: VecDeque<f64>,
+    run_success_after_dumping: VecDeque<bool>,
     num_pirates_encountered: VecDeque<u8>,
+    pirate_captain: VecDeque<Option<PirateCaptain>>,
+    deserters: VecDeque<u32>,
+    mutiny: VecDeque<bool>,
     good_stolen: VecDeque<(Good, u32)>,
     location_info: VecDeque<LocationInfo>,
     location_config: VecDeque<LocationConfig>,
+    market_pressure_remaining: VecDeque<f64>,
+    bank_run: VecDeque<Option<f64>>,
+    warehouse_mishap: VecDeque<Option<(WarehouseMishap, Good, u32)>>,
+    seed: VecDeque<u64>,
 }
 
 impl MerchantRng for MockRng {
-    fn gen_gold_recovered_from_pirate_encounter(&mut self, _: u8) -> u32 {
+    fn gen_gold_recovered_from_pirate_encounter(&mut self, _: u8, _: (u32, u32)) -> u32 {
         self.gold_recovered_from_pirate_encounter
             .pop_front()
             .expect("MockRng not seeded with enough gold_recovered_from_pirate_encounter")
@@ -35,18 +49,72 @@ impl MerchantRng for MockRng {
             .expect("MockRng not seeded with enough did_kill_a_pirate")
     }
 
-    fn gen_run_success(&mut self, _: u8) -> bool {
+    fn gen_run_success(&mut self, _: u8, _: f64) -> bool {
         self.run_success
             .pop_front()
             .expect("MockRng not seeded with enough run_success")
     }
 
-    fn gen_num_pirates_encountered(&mut self) -> u8 {
+    fn gen_ships_sunk_by_broadside(&mut self, _: u8, _: u8) -> u8 {
+        self.ships_sunk_by_broadside
+            .pop_front()
+            .expect("MockRng not seeded with enough ships_sunk_by_broadside")
+    }
+
+    fn gen_boarding_success(&mut self, _: u8, _: f64) -> bool {
+        self.boarding_success
+            .pop_front()
+            .expect("MockRng not seeded with enough boarding_success")
+    }
+
+    fn gen_gold_bribe_accepted(&mut self, _: u8) -> bool {
+        self.gold_bribe_accepted
+            .pop_front()
+            .expect("MockRng not seeded with enough gold_bribe_accepted")
+    }
+
+    fn gen_cargo_bribe_accepted(&mut self, _: u8) -> bool {
+        self.cargo_bribe_accepted
+            .pop_front()
+            .expect("MockRng not seeded with enough cargo_bribe_accepted")
+    }
+
+    fn gen_share_of_hold_surrendered(&mut self, _: u8) -> f64 {
+        self.share_of_hold_surrendered
+            .pop_front()
+            .expect("MockRng not seeded with enough share_of_hold_surrendered")
+    }
+
+    fn gen_run_success_after_dumping(&mut self, _: u8, _: f64) -> bool {
+        self.run_success_after_dumping
+            .pop_front()
+            .expect("MockRng not seeded with enough run_success_after_dumping")
+    }
+
+    fn gen_num_pirates_encountered(&mut self, _: (u8, u8)) -> u8 {
         self.num_pirates_encountered
             .pop_front()
             .expect("MockRng not seeded with enough num_pirates_encountered")
     }
 
+    fn gen_pirate_captain(&mut self, _: &[PirateCaptain], _: f64) -> Option<PirateCaptain> {
+        self.pirate_captain
+            .pop_front()
+            .expect("MockRng not seeded with enough pirate_captain")
+    }
+
+    fn gen_deserters(&mut self, _: u32, _: f64) -> u32 {
+        self.deserters
+            .pop_front()
+            .expect("MockRng not seeded with enough deserters")
+    }
+
+    fn gen_mutiny(&mut self, _: f64) -> bool {
+        self.mutiny
+            .pop_front()
+            .expect("MockRng not seeded with enough mutiny")
+    }
+
     fn gen_good_stolen(&mut self, _: &[(Good, u32)]) -> (Good, u32) {
         self.good_stolen
             .pop_front()
@@ -56,6 +124,7 @@ impl MerchantRng for MockRng {
     fn gen_location_info(
         &mut self,
         _: bool,
+        _: &Location,
         _: &crate::state::LocationPersonality,
         _: i32,
     ) -> LocationInfo {
@@ -64,11 +133,40 @@ impl MerchantRng for MockRng {
             .expect("MockRng not seeded with enough location_info")
     }
 
-    fn gen_location_config(&mut self, _: u32) -> LocationConfig {
+    fn gen_location_config(&mut self, _: &Rules) -> LocationConfig {
         self.location_config
             .pop_front()
             .expect("MockRng not seeded with enough location_config")
     }
+
+    fn gen_market_pressure_remaining(&mut self, _: u32, _: f64) -> f64 {
+        self.market_pressure_remaining
+            .pop_front()
+            .expect("MockRng not seeded with enough market_pressure_remaining")
+    }
+
+    fn gen_bank_run(&mut self, _: f64, _: f64) -> Option<f64> {
+        self.bank_run
+            .pop_front()
+            .expect("MockRng not seeded with enough bank_run")
+    }
+
+    fn gen_warehouse_mishap(
+        &mut self,
+        _: &[(Good, u32)],
+        _: f64,
+        _: f64,
+    ) -> Option<(WarehouseMishap, Good, u32)> {
+        self.warehouse_mishap
+            .pop_front()
+            .expect("MockRng not seeded with enough warehouse_mishap")
+    }
+
+    fn gen_seed(&mut self) -> u64 {
+        self.seed
+            .pop_front()
+            .expect("MockRng not seeded with enough seed")
+    }
 }
 
 impl Default for MockRng {
@@ -84,19 +182,30 @@ impl MockRng {
             damage_from_pirates: VecDeque::new(),
             did_kill_a_pirate: VecDeque::new(),
             run_success: VecDeque::new(),
+            ships_sunk_by_broadside: VecDeque::new(),
+            boarding_success: VecDeque::new(),
+            gold_bribe_accepted: VecDeque::new(),
+            cargo_bribe_accepted: VecDeque::new(),
+            share_of_hold_surrendered: VecDeque::new(),
+            run_success_after_dumping: VecDeque::new(),
             num_pirates_encountered: VecDeque::new(),
+            pirate_captain: VecDeque::new(),
+            deserters: VecDeque::new(),
+            mutiny: VecDeque::new(),
             good_stolen: VecDeque::new(),
             location_info: VecDeque::new(),
             location_config: VecDeque::new(),
+            market_pressure_remaining: VecDeque::new(),
+            bank_run: VecDeque::new(),
+            warehouse_mishap: VecDeque::new(),
+            seed: VecDeque::new(),
         }
     }
 
     pub fn new_with_default_locations() -> Self {
-        let overall_price_ranges = PriceRanges::from_start_price_and_spreads(
-            500,
-            [5.0, 3.0, 2.0, 1.5, 1.0, 0.75],
-            [5.0, 4.2, 3.4, 2.6, 1.8],
-        );
+        let rules = Rules::default();
+        let overall_price_ranges =
+            PriceRanges::from_tiers_and_spreads(500, &rules.price_tiers, &rules.price_spreads);
         let basic_event_weights = EventWeights {
             no_event: 6,
             cheap_good: 1,
@@ -107,33 +216,22 @@ impl MockRng {
             pirate_encounter: 1,
             can_buy_more_hold_space: 1,
             no_effect: 1,
+            customs_inspection: 0,
         };
-        let personalities = LocationMap {
-            london: LocationPersonality {
-                price_ranges: overall_price_ranges.clone(),
-                event_weights: basic_event_weights.clone(),
-            },
-            savannah: LocationPersonality {
-                price_ranges: overall_price_ranges.clone(),
-                event_weights: basic_event_weights.clone(),
-            },
-            lisbon: LocationPersonality {
-                price_ranges: overall_price_ranges.clone(),
-                event_weights: basic_event_weights.clone(),
-            },
-            amsterdam: LocationPersonality {
-                price_ranges: overall_price_ranges.clone(),
-                event_weights: basic_event_weights.clone(),
-            },
-            capetown: LocationPersonality {
-                price_ranges: overall_price_ranges.clone(),
-                event_weights: basic_event_weights.clone(),
-            },
-            venice: LocationPersonality {
-                price_ranges: overall_price_ranges.clone(),
-                event_weights: basic_event_weights.clone(),
-            },
+        let lender = Lender {
+            interest_rate: 0.1,
+            cap: 5000,
+            term_months: 12,
         };
+        let personalities = LocationMap::from_fn(|_| LocationPersonality {
+            price_ranges: overall_price_ranges.clone(),
+            event_weights: basic_event_weights.clone(),
+            lender: lender.clone(),
+            contracts: ContractTerms::default(),
+            cheap_goods: vec![],
+            expensive_goods: vec![],
+            customs: Customs::default(),
+        });
         Self::new()
             .push_location_config(LocationConfig {
                 home_port: Location::London,
@@ -167,12 +265,60 @@ impl MockRng {
         self
     }
 
+    pub fn push_ships_sunk_by_broadside(mut self, ships_sunk_by_broadside: u8) -> Self {
+        self.ships_sunk_by_broadside
+            .push_back(ships_sunk_by_broadside);
+        self
+    }
+
+    pub fn push_boarding_success(mut self, boarding_success: bool) -> Self {
+        self.boarding_success.push_back(boarding_success);
+        self
+    }
+
+    pub fn push_gold_bribe_accepted(mut self, gold_bribe_accepted: bool) -> Self {
+        self.gold_bribe_accepted.push_back(gold_bribe_accepted);
+        self
+    }
+
+    pub fn push_cargo_bribe_accepted(mut self, cargo_bribe_accepted: bool) -> Self {
+        self.cargo_bribe_accepted.push_back(cargo_bribe_accepted);
+        self
+    }
+
+    pub fn push_share_of_hold_surrendered(mut self, share_of_hold_surrendered: f64) -> Self {
+        self.share_of_hold_surrendered
+            .push_back(share_of_hold_surrendered);
+        self
+    }
+
+    pub fn push_run_success_after_dumping(mut self, run_success_after_dumping: bool) -> Self {
+        self.run_success_after_dumping
+            .push_back(run_success_after_dumping);
+        self
+    }
+
     pub fn push_num_pirates_encountered(mut self, num_pirates_encountered: u8) -> Self {
         self.num_pirates_encountered
             .push_back(num_pirates_encountered);
         self
     }
 
+    pub fn push_pirate_captain(mut self, pirate_captain: Option<PirateCaptain>) -> Self {
+        self.pirate_captain.push_back(pirate_captain);
+        self
+    }
+
+    pub fn push_deserters(mut self, deserters: u32) -> Self {
+        self.deserters.push_back(deserters);
+        self
+    }
+
+    pub fn push_mutiny(mut self, mutiny: bool) -> Self {
+        self.mutiny.push_back(mutiny);
+        self
+    }
+
     pub fn push_good_stolen(mut self, good_stolen: (Good, u32)) -> Self {
         self.good_stolen.push_back(good_stolen);
         self
@@ -195,6 +341,25 @@ impl MockRng {
         self.location_config.push_back(location_config);
         self
     }
+
+    pub fn push_market_pressure_remaining(mut self, market_pressure_remaining: f64) -> Self {
+        self.market_pressure_remaining
+            .push_back(market_pressure_remaining);
+        self
+    }
+
+    pub fn push_bank_run(mut self, bank_run: Option<f64>) -> Self {
+        self.bank_run.push_back(bank_run);
+        self
+    }
+
+    pub fn push_warehouse_mishap(
+        mut self,
+        warehouse_mishap: Option<(WarehouseMishap, Good, u32)>,
+    ) -> Self {
+        self.warehouse_mishap.push_back(warehouse_mishap);
+        self
+    }
 }
 
 impl From<MockRng> for Box<dyn MerchantRng> {
@@ -205,18 +370,25 @@ impl From<MockRng> for Box<dyn MerchantRng> {
 
 pub fn default_location_info() -> LocationInfo {
     LocationInfo {
-        prices: Inventory {
-            tea: 6,
-            coffee: 5,
-            sugar: 4,
-            tobacco: 3,
-            rum: 2,
-            cotton: 1,
-        },
+        prices: Inventory::from([
+            (Good::Tea, 6),
+            (Good::Coffee, 5),
+            (Good::Sugar, 4),
+            (Good::Tobacco, 3),
+            (Good::Rum, 2),
+            (Good::Cotton, 1),
+            (Good::Spices, 5),
+            (Good::Silk, 6),
+            (Good::Indigo, 2),
+            (Good::Porcelain, 7),
+        ]),
         event: None,
+        contracts: vec![],
     }
 }
 
 pub fn default_location_infos() -> Vec<LocationInfo> {
-    (0..6).map(|_| default_location_info()).collect()
+    (0..Location::COUNT)
+        .map(|_| default_location_info())
+        .collect()
 }
@@ -3,7 +3,7 @@ use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use std::{cell::RefCell, str};
 
 use crate::{
-    engine::{Engine, UpdateResult, UpdateSignal},
+    engine::{global_signal, Engine, UpdateResult, UpdateSignal},
     state::GameState,
 };
 use raw_format_ansi::raw_format_ansi;
@@ -11,21 +11,53 @@ use raw_format_ansi::raw_format_ansi;
 pub struct TestEngine {
     writer_ref: RefCell<CapturedWrite>,
     game_state: GameState,
+    saved_game_available: bool,
+    new_high_score: Option<usize>,
 }
 
 impl TestEngine {
+    pub fn from_game_state(game_state: GameState) -> UpdateResult<Self> {
+        Self::from_game_state_with_saved_game(game_state, false)
+    }
+
+    pub fn from_game_state_with_saved_game(
+        game_state: GameState,
+        saved_game_available: bool,
+    ) -> UpdateResult<Self> {
+        Self::new(game_state, saved_game_available, None)
+    }
+
+    pub fn from_game_state_with_new_high_score(
+        game_state: GameState,
+        new_high_score: usize,
+    ) -> UpdateResult<Self> {
+        Self::new(game_state, false, Some(new_high_score))
+    }
+
     #[allow(unused_must_use)]
-    pub fn from_game_state(mut game_state: GameState) -> UpdateResult<Self> {
+    fn new(
+        mut game_state: GameState,
+        saved_game_available: bool,
+        new_high_score: Option<usize>,
+    ) -> UpdateResult<Self> {
         let writer = CapturedWrite::new();
         let writer_box: RefCell<CapturedWrite> = RefCell::from(writer);
         let mut engine = Engine::new(&writer_box);
+        engine.saved_game_available = saved_game_available;
+        engine.new_high_score = new_high_score;
         engine.draw_scene(&mut game_state)?;
         Ok(Self {
             writer_ref: writer_box,
             game_state,
+            saved_game_available,
+            new_high_score,
         })
     }
 
+    pub fn game_state(&self) -> &GameState {
+        &self.game_state
+    }
+
     pub fn get_current_formatted(&self) -> String {
         let buffer = self.writer_ref.borrow().buffer.clone();
         raw_format_ansi(&buffer)
@@ -62,19 +94,29 @@ impl TestEngine {
     }
 
     #[allow(unused_must_use)]
-    pub fn keypress(&mut self, key_code: KeyCode) -> UpdateResult<UpdateSignal> {
+    pub fn key_event(&mut self, event: KeyEvent) -> UpdateResult<UpdateSignal> {
+        if let Some(signal) = global_signal(&event, &self.game_state) {
+            return Ok(signal);
+        }
         self.writer_ref.borrow_mut().reset();
         let mut engine = Engine::new(&self.writer_ref);
+        engine.saved_game_available = self.saved_game_available;
+        engine.new_high_score = self.new_high_score;
         let update = engine.draw_scene(&mut self.game_state)?;
-        let signal = update(
-            KeyEvent::new(key_code, KeyModifiers::empty()),
-            &mut self.game_state,
-        )?;
+        let signal = update(event, &mut self.game_state)?;
         self.writer_ref.borrow_mut().reset();
         engine.draw_scene(&mut self.game_state)?;
         Ok(signal)
     }
 
+    pub fn keypress(&mut self, key_code: KeyCode) -> UpdateResult<UpdateSignal> {
+        self.key_event(KeyEvent::new(key_code, KeyModifiers::empty()))
+    }
+
+    pub fn ctrlpress(&mut self, char: char) -> UpdateResult<UpdateSignal> {
+        self.key_event(KeyEvent::new(KeyCode::Char(char), KeyModifiers::CONTROL))
+    }
+
     pub fn charpress(&mut self, char: char) -> UpdateResult<UpdateSignal> {
         self.keypress(KeyCode::Char(char))
     }
@@ -136,14 +136,12 @@ pub fn raw_format_ansi(s: &str) -> String {
                 line.push(' ');
             }
             // append actual text
-            let mut index = col;
-            for text_char in text.chars() {
+            for (index, text_char) in (col..).zip(text.chars()) {
                 if line.len() > index {
                     let _ = std::mem::replace(&mut line[index], text_char);
                 } else {
                     line.push(text_char);
                 }
-                index += 1;
                 cursor_pos.1 += 1;
             }
         } else {